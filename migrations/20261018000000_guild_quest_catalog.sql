@@ -0,0 +1,20 @@
+-- Guild-specific quests and aliases.
+-- Rows with guild_id = NULL form the global catalog shared by every server;
+-- rows with a guild_id extend or override it for that server only.
+
+ALTER TABLE quests ADD COLUMN IF NOT EXISTS guild_id BIGINT;
+ALTER TABLE quests_alias ADD COLUMN IF NOT EXISTS guild_id BIGINT;
+
+-- target_id and alias are now unique per scope instead of globally
+ALTER TABLE quests DROP CONSTRAINT IF EXISTS quests_target_id_key;
+ALTER TABLE quests_alias DROP CONSTRAINT IF EXISTS quests_alias_alias_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS quests_global_target_id_key
+    ON quests (target_id) WHERE guild_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS quests_guild_target_id_key
+    ON quests (guild_id, target_id) WHERE guild_id IS NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS quests_alias_global_alias_key
+    ON quests_alias (alias) WHERE guild_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS quests_alias_guild_alias_key
+    ON quests_alias (guild_id, alias) WHERE guild_id IS NOT NULL;
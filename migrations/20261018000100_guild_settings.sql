@@ -0,0 +1,13 @@
+-- Per-guild settings. NULL columns fall back to the bot's defaults.
+CREATE TABLE IF NOT EXISTS guild_settings (
+    id SERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL UNIQUE,
+    recruitment_channel_id BIGINT,
+    default_start_time VARCHAR(5),
+    admin_role_name VARCHAR(100),
+    locale VARCHAR(10),
+    timezone VARCHAR(6),
+    reminder_offsets VARCHAR(100),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
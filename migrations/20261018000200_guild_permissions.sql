@@ -0,0 +1,14 @@
+-- Capabilities granted to roles or users per guild.
+-- Grants are stored by ID so that renaming a role keeps its permissions.
+CREATE TABLE IF NOT EXISTS guild_permissions (
+    id SERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    capability VARCHAR(50) NOT NULL,
+    role_id BIGINT,
+    user_id BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((role_id IS NULL) <> (user_id IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS guild_permissions_guild_id_idx ON guild_permissions (guild_id);
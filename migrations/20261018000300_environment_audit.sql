@@ -0,0 +1,16 @@
+-- Secret environment values are masked in /environ output.
+ALTER TABLE environments ADD COLUMN IF NOT EXISTS is_secret BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Every change made with /environ set|delete. Secret values are stored masked.
+CREATE TABLE IF NOT EXISTS environment_audit_logs (
+    id SERIAL PRIMARY KEY,
+    key VARCHAR(255) NOT NULL,
+    action VARCHAR(10) NOT NULL,
+    old_value TEXT,
+    new_value TEXT,
+    guild_id BIGINT,
+    user_id BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS environment_audit_logs_key_idx ON environment_audit_logs (key);
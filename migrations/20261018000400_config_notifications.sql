@@ -0,0 +1,18 @@
+-- Notify the bot when environments or message_texts change so that it reloads them.
+-- The payload is the name of the changed table.
+CREATE OR REPLACE FUNCTION notify_gbf_bot_config_changed() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('gbf_bot_config_changed', TG_TABLE_NAME);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS environments_config_changed ON environments;
+CREATE TRIGGER environments_config_changed
+    AFTER INSERT OR UPDATE OR DELETE OR TRUNCATE ON environments
+    FOR EACH STATEMENT EXECUTE FUNCTION notify_gbf_bot_config_changed();
+
+DROP TRIGGER IF EXISTS message_texts_config_changed ON message_texts;
+CREATE TRIGGER message_texts_config_changed
+    AFTER INSERT OR UPDATE OR DELETE OR TRUNCATE ON message_texts
+    FOR EACH STATEMENT EXECUTE FUNCTION notify_gbf_bot_config_changed();
@@ -0,0 +1,15 @@
+-- The completion message (MSG00032) is now a template that also renders the participant mentions.
+-- Prepend the mentions to existing texts so that completion notices keep mentioning the participants.
+UPDATE message_texts
+SET message_jp = '${participants}' || E'\n' || message_jp,
+    updated_at = NOW()
+WHERE message_id = 'MSG00032'
+  AND message_jp NOT LIKE '%${participants}%';
+
+UPDATE message_texts
+SET message_en = '${participants}' || E'\n' || message_en,
+    updated_at = NOW()
+WHERE message_id = 'MSG00032'
+  AND message_en IS NOT NULL
+  AND message_en <> ''
+  AND message_en NOT LIKE '%${participants}%';
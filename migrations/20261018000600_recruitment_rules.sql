@@ -0,0 +1,20 @@
+-- Recruitments posted automatically every week, e.g. every Saturday 21:00.
+-- weekday is 0 (Monday) to 6 (Sunday) and start_time is HH:MM in the guild's timezone.
+-- last_posted_for is the event time of the latest post, so that each week is posted only once.
+CREATE TABLE IF NOT EXISTS recruitment_rules (
+    id SERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    quest_alias VARCHAR(100) NOT NULL,
+    battle_type_id INTEGER NOT NULL DEFAULT 0,
+    weekday SMALLINT NOT NULL CHECK (weekday BETWEEN 0 AND 6),
+    start_time VARCHAR(5) NOT NULL,
+    lead_time_minutes INTEGER NOT NULL CHECK (lead_time_minutes >= 0),
+    paused BOOLEAN NOT NULL DEFAULT FALSE,
+    last_posted_for TIMESTAMPTZ,
+    created_by BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS recruitment_rules_guild_id_idx ON recruitment_rules (guild_id);
@@ -0,0 +1,23 @@
+-- Named recruitment presets. Templates belong to the member who saved them;
+-- published templates (is_public) can be used by everyone in the guild.
+CREATE TABLE IF NOT EXISTS recruitment_templates (
+    id SERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    owner_id BIGINT NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    quest_alias VARCHAR(100) NOT NULL,
+    battle_type_id INTEGER NOT NULL DEFAULT 0,
+    capacity INTEGER NOT NULL DEFAULT 6 CHECK (capacity BETWEEN 1 AND 30),
+    notes TEXT,
+    channel_id BIGINT,
+    is_public BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (guild_id, owner_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS recruitment_templates_guild_id_idx ON recruitment_templates (guild_id);
+
+-- Capacity and notes of each recruitment, so that templates other than a full party of 6 complete correctly.
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS capacity INTEGER NOT NULL DEFAULT 6;
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS notes TEXT;
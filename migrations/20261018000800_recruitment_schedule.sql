@@ -0,0 +1,7 @@
+-- Progress of the reminders and the start call of each recruitment.
+-- reminded_offset_minutes is the offset of the last reminder sent (reminders go from the largest offset down);
+-- both are cleared when the event time of the recruitment is changed, so that they are sent again for the new time.
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS reminded_offset_minutes INTEGER;
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS started_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS battle_recruitments_pending_start_idx ON battle_recruitments (expiry_date) WHERE started_at IS NULL;
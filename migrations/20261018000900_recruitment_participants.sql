@@ -0,0 +1,13 @@
+-- Members who joined each recruitment, one row per element they reacted with.
+-- Kept in sync with the reactions on the recruitment message, so that members can list what they joined.
+CREATE TABLE IF NOT EXISTS battle_recruitment_participants (
+    id SERIAL PRIMARY KEY,
+    recruitment_id INTEGER NOT NULL REFERENCES battle_recruitments (id) ON DELETE CASCADE,
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    element_id INTEGER NOT NULL,
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (recruitment_id, user_id, element_id)
+);
+
+CREATE INDEX IF NOT EXISTS battle_recruitment_participants_user_idx ON battle_recruitment_participants (guild_id, user_id);
@@ -0,0 +1,5 @@
+-- The member who created each recruitment, for the actions only the creator (or a member with
+-- cancel_others_recruitments) may take. Recruitments posted before this column existed have no creator.
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS created_by BIGINT;
+
+CREATE INDEX IF NOT EXISTS battle_recruitments_created_by_idx ON battle_recruitments (guild_id, created_by);
@@ -0,0 +1,11 @@
+-- Battle IDs (参戦ID) shared with the participants of each recruitment, newest last.
+CREATE TABLE IF NOT EXISTS battle_recruitment_raid_ids (
+    id SERIAL PRIMARY KEY,
+    recruitment_id INTEGER NOT NULL REFERENCES battle_recruitments (id) ON DELETE CASCADE,
+    guild_id BIGINT NOT NULL,
+    raid_id VARCHAR(8) NOT NULL,
+    posted_by BIGINT NOT NULL,
+    posted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS battle_recruitment_raid_ids_recruitment_idx ON battle_recruitment_raid_ids (recruitment_id, posted_at);
@@ -0,0 +1,5 @@
+-- Optional discussion thread per recruitment.
+-- guild_settings.recruitment_threads turns them on for a server; battle_recruitments.thread_id is the thread
+-- created on the recruitment message, where reminders, start calls and other notices are posted.
+ALTER TABLE guild_settings ADD COLUMN IF NOT EXISTS recruitment_threads BOOLEAN;
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS thread_id BIGINT;
@@ -0,0 +1,5 @@
+-- Optional Discord scheduled event per recruitment.
+-- guild_settings.scheduled_events turns them on for a server; battle_recruitments.scheduled_event_id is the
+-- event mirroring the recruitment, kept in sync when it is edited, started or cancelled.
+ALTER TABLE guild_settings ADD COLUMN IF NOT EXISTS scheduled_events BOOLEAN;
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS scheduled_event_id BIGINT;
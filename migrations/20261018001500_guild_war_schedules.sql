@@ -0,0 +1,20 @@
+-- Guild War (Unite and Fight) schedule of each guild, one row per round.
+-- round is preliminaries, interlude or finals_1 to finals_4; importing a schedule replaces every round of the guild.
+-- The reminders at the start and end of each round are posted to channel_id, mentioning role_id when set;
+-- start_notified_at / end_notified_at record them so that each is posted only once.
+CREATE TABLE IF NOT EXISTS guild_war_schedules (
+    id SERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    round VARCHAR(16) NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL CHECK (ends_at > starts_at),
+    channel_id BIGINT NOT NULL,
+    role_id BIGINT,
+    start_notified_at TIMESTAMPTZ,
+    end_notified_at TIMESTAMPTZ,
+    created_by BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (guild_id, round)
+);
+
+CREATE INDEX IF NOT EXISTS guild_war_schedules_pending_end_idx ON guild_war_schedules (ends_at) WHERE end_notified_at IS NULL;
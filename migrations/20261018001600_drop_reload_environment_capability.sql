@@ -0,0 +1,3 @@
+-- /environ_load reloads the settings of every guild, so it is limited to the bot owners
+-- instead of a per-guild capability. Remove the grants of the dropped capability.
+DELETE FROM guild_permissions WHERE capability = 'reload_environment';
@@ -0,0 +1,6 @@
+-- Rules posted 0 minutes before the start were never posted, since the scheduler checks once a minute
+-- and the start time had already passed. Raise them to the new minimum of 5 minutes.
+UPDATE recruitment_rules
+SET lead_time_minutes = 5,
+    updated_at = NOW()
+WHERE lead_time_minutes < 5;
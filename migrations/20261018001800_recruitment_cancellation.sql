@@ -0,0 +1,3 @@
+-- Cancelled recruitments are kept so that their messages and events can still be resolved,
+-- but reminders, start calls and listings skip them.
+ALTER TABLE battle_recruitments ADD COLUMN IF NOT EXISTS cancelled_at TIMESTAMPTZ;
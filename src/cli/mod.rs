@@ -0,0 +1,24 @@
+mod quest;
+
+use crate::types::PoiseError;
+
+const USAGE: &str = "\
+Usage:
+  gbf_discord_bot_rs                                   Start the bot
+  gbf_discord_bot_rs quests export <json|csv> [FILE]   Export quests and aliases (stdout when FILE is omitted)
+  gbf_discord_bot_rs quests import <FILE>              Import quests and aliases (format is detected from the extension)";
+
+/// コマンドライン引数で指定されたサブコマンドを実行する
+pub async fn run(args: &[String]) -> Result<(), PoiseError> {
+    let args: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+    match args.as_slice() {
+        ["quests", "export", format] => quest::export(format, None).await,
+        ["quests", "export", format, path] => quest::export(format, Some(path)).await,
+        ["quests", "import", path] => quest::import(path).await,
+        ["help"] | ["--help"] | ["-h"] => {
+            println!("{}", USAGE);
+            Ok(())
+        },
+        _ => Err(format!("Unknown arguments: {}\n\n{}", args.join(" "), USAGE).into()),
+    }
+}
@@ -0,0 +1,49 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::repository::Database;
+use crate::services::quest::QuestService;
+use crate::services::quest::catalog::{self, CatalogFormat};
+use crate::types::PoiseError;
+
+/// 全サーバー共通のクエストマスタを書き出す（出力先未指定時は標準出力）
+pub(super) async fn export(format: &str, path: Option<&str>) -> Result<(), PoiseError> {
+    let format = CatalogFormat::from_name(format)
+        .ok_or_else(|| format!("Unknown format: {} (json or csv)", format))?;
+
+    let service = connect().await?;
+    let records = service.export_catalog(None).await?;
+    let content = catalog::serialize(&records, format)?;
+
+    match path {
+        Some(path) => {
+            tokio::fs::write(path, content).await?;
+            eprintln!("Exported {} quests to {}", records.len(), path);
+        },
+        None => print!("{}", content),
+    }
+    Ok(())
+}
+
+/// ファイルから全サーバー共通のクエストマスタへ取り込む
+pub(super) async fn import(path: &str) -> Result<(), PoiseError> {
+    let format = Path::new(path)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(CatalogFormat::from_filename)
+        .ok_or_else(|| format!("Cannot detect the format of {} (use .json or .csv)", path))?;
+
+    let content = tokio::fs::read_to_string(path).await?;
+    let records = catalog::deserialize(&content, format)?;
+
+    let service = connect().await?;
+    let summary = service.import_catalog(None, &records).await?;
+    eprintln!("Imported {}: {}", path, summary);
+    Ok(())
+}
+
+async fn connect() -> Result<QuestService, PoiseError> {
+    let db = Database::new().await
+        .map_err(|e| format!("データベース接続エラー: {}", e))?;
+    Ok(QuestService::new(Arc::new(db)))
+}
@@ -21,6 +21,9 @@ pub async fn event_handler(
             println!("reaction removes user is {}", removed_reaction.user(&ctx.http).await?.name);
             handlers::reaction_remove::on_reaction_remove(ctx, removed_reaction).await?;
         },
+        poise::serenity_prelude::FullEvent::Message { new_message } => {
+            handlers::message::on_message(ctx, new_message).await?;
+        },
         _ => {
         }
     }
@@ -0,0 +1,14 @@
+use log::error;
+use poise::serenity_prelude::Message;
+use crate::facades::raid_id;
+
+pub async fn on_message(
+    ctx: &poise::serenity_prelude::Context,
+    message: &Message,
+) -> Result<(), String> {
+    // Relay battle IDs posted in reply to a recruitment
+    if let Err(e) = raid_id::relay(ctx, message).await {
+        error!("Raid ID relay failed: {}", e);
+    }
+    Ok(())
+}
@@ -1,3 +1,4 @@
 pub mod reaction_add;
 pub mod reaction_remove;
+pub mod message;
 pub mod ready;
\ No newline at end of file
@@ -1,6 +1,7 @@
-use log::info;
+use log::{info, error};
 use poise::serenity_prelude::Reaction;
-use crate::facades::battle_recruitment::member_update;
+use crate::facades::battle_recruitment::{member_update, sync_participants};
+use crate::types::BattleType;
 
 pub async fn on_reaction_add(
     ctx: &poise::serenity_prelude::Context,
@@ -13,6 +14,16 @@ pub async fn on_reaction_add(
     let channel_id = reaction.channel_id.get();
     let message_id = reaction.message_id.get();
     
+    // The bot's own reactions are not participants
+    let joined = reaction.user_id
+        .filter(|user_id| *user_id != ctx.cache.current_user().id)
+        .filter(|_| BattleType::from_reaction(&reaction.emoji).is_some());
+
+    // Record the participants before updating the recruitment message
+    if let Err(e) = sync_participants(ctx, guild_id, channel_id, message_id, joined).await {
+        error!("Participant sync failed: {}", e);
+    }
+
     // Call member_update with the new signature
     match member_update(ctx, guild_id, channel_id, message_id).await {
         Ok(_) => {
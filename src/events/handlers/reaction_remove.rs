@@ -1,6 +1,6 @@
-use log::info;
+use log::{info, error};
 use poise::serenity_prelude::Reaction;
-use crate::facades::battle_recruitment::member_update;
+use crate::facades::battle_recruitment::{member_update, sync_participants};
 
 pub async fn on_reaction_remove(
     ctx: &poise::serenity_prelude::Context,
@@ -14,6 +14,11 @@ pub async fn on_reaction_remove(
     let channel_id = reaction.channel_id.get();
     let message_id = reaction.message_id.get();
     
+    // Record the participants before updating the recruitment message
+    if let Err(e) = sync_participants(ctx, guild_id, channel_id, message_id, None).await {
+        error!("Participant sync failed: {}", e);
+    }
+
     // Call member_update with the new signature
     match member_update(ctx, guild_id, channel_id, message_id).await {
         Ok(_) => {
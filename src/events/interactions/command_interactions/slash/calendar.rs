@@ -0,0 +1,25 @@
+use crate::facades::calendar;
+use crate::types::{CalendarScope, PoiseContext, PoiseError};
+
+/// Export upcoming recruitments as a calendar file (.ics)
+#[poise::command(
+    slash_command,
+    name_localized("ja", "カレンダー"),
+    description_localized("ja", "開始前の募集をカレンダーのファイル（.ics）で書き出します"),
+    guild_only,
+    ephemeral
+)]
+pub async fn calendar(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Every recruitment in the server, or only the ones you created or joined"]
+    #[description_localized("ja", "サーバー全体の募集か、作成または参加した募集のみか")]
+    scope: CalendarScope,
+
+    #[description = "Issue a new feed URL and disable the old one"]
+    #[description_localized("ja", "配信URLを作り直し、以前のURLを無効にする")]
+    reset_feed: Option<bool>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    calendar::export(&ctx, scope, reset_feed.unwrap_or(false)).await
+}
@@ -0,0 +1,97 @@
+use crate::types::{PoiseContext, PoiseError};
+use crate::facades::environment;
+use crate::services::permission::checks;
+
+/// Manage the bot's environment values
+#[poise::command(
+    slash_command,
+    name_localized("ja", "設定値"),
+    description_localized("ja", "Botの設定値を管理します"),
+    subcommands("environ_list", "environ_get", "environ_set", "environ_delete"),
+    subcommand_required,
+    guild_only,
+    check = "checks::bot_owner"
+)]
+pub async fn environ(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// List environment values
+#[poise::command(
+    slash_command,
+    rename = "list",
+    name_localized("ja", "一覧"),
+    description_localized("ja", "登録されている設定値を一覧表示します"),
+    ephemeral
+)]
+pub async fn environ_list(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    environment::list(&ctx).await
+}
+
+/// Show an environment value and its recent changes
+#[poise::command(
+    slash_command,
+    rename = "get",
+    name_localized("ja", "表示"),
+    description_localized("ja", "設定値と最近の変更履歴を表示します"),
+    ephemeral
+)]
+pub async fn environ_get(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Key"]
+    #[description_localized("ja", "キー")]
+    key: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    environment::get(&ctx, &key).await
+}
+
+/// Set an environment value
+#[poise::command(
+    slash_command,
+    rename = "set",
+    name_localized("ja", "変更"),
+    description_localized("ja", "設定値を登録・変更します"),
+    ephemeral
+)]
+pub async fn environ_set(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Key"]
+    #[description_localized("ja", "キー")]
+    key: String,
+
+    #[description = "Value"]
+    #[description_localized("ja", "値")]
+    value: String,
+
+    #[description = "Hide the value in command output (keeps the current flag when omitted)"]
+    #[description_localized("ja", "値を表示しない（省略時は現在の指定のまま）")]
+    secret: Option<bool>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    environment::set(&ctx, &key, &value, secret).await
+}
+
+/// Delete an environment value
+#[poise::command(
+    slash_command,
+    rename = "delete",
+    name_localized("ja", "削除"),
+    description_localized("ja", "設定値を削除します"),
+    ephemeral
+)]
+pub async fn environ_delete(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Key"]
+    #[description_localized("ja", "キー")]
+    key: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    environment::delete(&ctx, &key).await
+}
@@ -1,11 +1,14 @@
 use crate::types::{PoiseContext, PoiseError};
 use crate::facades::environment;
+use crate::services::permission::checks;
 
 #[poise::command(
     slash_command,
     name_localized("ja", "設定値リロード"),
     description_localized("ja", "Botの設定値をサーバーから読み込みます"),
-    ephemeral
+    ephemeral,
+    guild_only,
+    check = "checks::bot_owner"
 )]
 pub async fn environ_load(
     ctx: PoiseContext<'_>,
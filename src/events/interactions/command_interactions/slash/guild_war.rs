@@ -0,0 +1,79 @@
+use poise::serenity_prelude as serenity;
+use crate::types::{PoiseContext, PoiseError};
+use crate::facades::guild_war;
+use crate::services::permission::checks;
+
+/// Manage the Guild War schedule
+#[poise::command(
+    slash_command,
+    name_localized("ja", "古戦場"),
+    description_localized("ja", "古戦場の日程を管理し、各区切りの開始・終了をお知らせします"),
+    subcommands("guild_war_import", "guild_war_show", "guild_war_clear"),
+    subcommand_required,
+    guild_only
+)]
+pub async fn guild_war(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// Import the Guild War schedule from a JSON file, replacing the current one
+#[poise::command(
+    slash_command,
+    rename = "import",
+    name_localized("ja", "取り込み"),
+    description_localized("ja", "JSONファイルから古戦場の日程を取り込みます（今の日程は置き換えます）"),
+    check = "checks::bot_control",
+    ephemeral
+)]
+pub async fn guild_war_import(
+    ctx: PoiseContext<'_>,
+
+    #[description = "JSON file with the round, start and end of each round"]
+    #[description_localized("ja", "区切りごとに round・start・end を書いたJSONファイル")]
+    file: serenity::Attachment,
+
+    #[description = "Channel for the round notices (recruitment channel setting when omitted)"]
+    #[description_localized("ja", "区切りのお知らせを投稿するチャンネル（省略時はサーバー設定の募集チャンネル）")]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+
+    #[description = "Role to mention in the round notices"]
+    #[description_localized("ja", "区切りのお知らせでメンションするロール")]
+    role: Option<serenity::Role>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    let channel_id = channel.map(|channel| channel.id);
+    let role_id = role.map(|role| role.id);
+    guild_war::import(&ctx, &file, channel_id, role_id).await
+}
+
+/// Show the Guild War schedule
+#[poise::command(
+    slash_command,
+    rename = "show",
+    name_localized("ja", "日程"),
+    description_localized("ja", "古戦場の日程を表示します"),
+    ephemeral
+)]
+pub async fn guild_war_show(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    guild_war::show(&ctx).await
+}
+
+/// Delete the Guild War schedule and stop its notices
+#[poise::command(
+    slash_command,
+    rename = "clear",
+    name_localized("ja", "削除"),
+    description_localized("ja", "古戦場の日程を削除し、お知らせを止めます"),
+    check = "checks::bot_control",
+    ephemeral
+)]
+pub async fn guild_war_clear(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    guild_war::clear(&ctx).await
+}
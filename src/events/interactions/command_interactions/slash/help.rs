@@ -22,14 +22,129 @@ pub async fn help(
         .field(
             "/recruit",
             "Create a battle_recruitment recruitment with reactions for different elements.\n\
-            Usage: `/recruit quest:<quest_name> [battle_type:<type>] [event_date:<date>]`",
+            Usage: `/recruit quest:<quest_name> [battle_type:<type>] [event_date:<date>]` or `/recruit template:<name> [event_date:<date>]`",
             false
         )
         .field(
             "/environ_load",
             "Reload environment variables from the database.\n\
             Usage: `/environ_load`\n\
-            Note: Requires the 'gbf_bot_control' role.",
+            Note: Only the bot owners can use this, since it reloads the settings of every server.",
+            false
+        )
+        .field(
+            "/environ",
+            "List, show, set or delete environment values stored in the database. Secret values are masked and every change is audited.\n\
+            Usage: `/environ list|get|set|delete`\n\
+            Note: Only the bot owners can use this. DISCORD_TOKEN and DATABASE_URL cannot be changed.",
+            false
+        )
+        .field(
+            "/quest",
+            "Manage this server's quests, or import/export them as JSON or CSV.\n\
+            Usage: `/quest add|edit|remove|list|import|export`\n\
+            Note: Requires the 'manage_quests' capability.",
+            false
+        )
+        .field(
+            "/alias",
+            "Manage this server's quest aliases. Aliases must be unique.\n\
+            Usage: `/alias add|remove|list`\n\
+            Note: Requires the 'manage_quests' capability.",
+            false
+        )
+        .field(
+            "/settings",
+            "Show or change this server's settings: recruitment channel, default start time, admin role, language, timezone and reminders.\n\
+            Usage: `/settings show|set|reset`\n\
+            Note: `set` and `reset` require the bot control role.",
+            false
+        )
+        .field(
+            "/permission",
+            "Grant or revoke capabilities (manage_quests, cancel_others_recruitments) for roles or users.\n\
+            Usage: `/permission grant|revoke|list`\n\
+            Note: Requires the bot control role ('gbf_bot_control' unless changed with `/settings`).",
+            false
+        )
+        .field(
+            "/message_text",
+            "Override this server's bot messages (recruitment templates, MSG00032, ...) in Japanese and English. `set` shows a preview with sample values before saving.\n\
+            Usage: `/message_text list|show|set|reset`\n\
+            Note: Requires the bot control role.",
+            false
+        )
+        .field(
+            "/recruit_edit",
+            "Change the start time, battle type, capacity or notes of a posted recruitment. Participants are notified and reminders follow the new time.\n\
+            Usage: `/recruit_edit message:<link or ID> [event_date] [battle_type] [capacity] [notes]`\n\
+            Note: Only the recruitment's creator, or members with the cancel_others_recruitments capability.",
+            false
+        )
+        .field(
+            "/recruit_start",
+            "Start a recruitment now and call its participants. The call at the planned time is no longer sent.\n\
+            Usage: `/recruit_start message:<link or ID>`\n\
+            Note: Only the recruitment's creator, or members with the cancel_others_recruitments capability.",
+            false
+        )
+        .field(
+            "/recruit_cancel",
+            "Cancel a recruitment before it starts and notify its participants. Its reminders and start call are no longer sent.\n\
+            Usage: `/recruit_cancel message:<link or ID>`\n\
+            Note: Only the recruitment's creator, or members with the cancel_others_recruitments capability.",
+            false
+        )
+        .field(
+            "/recruit_transfer",
+            "Hand a recruitment over to one of its participants.\n\
+            Usage: `/recruit_transfer message:<link or ID> member:<member>`\n\
+            Note: Only the recruitment's creator, or members with the cancel_others_recruitments capability.",
+            false
+        )
+        .field(
+            "/recruit_list",
+            "List this server's recruitments by start time, with participant counts and links. Use the buttons to change pages.\n\
+            Usage: `/recruit_list [channel] [status] [days]`",
+            false
+        )
+        .field(
+            "/raid_id",
+            "Share a battle ID with the participants of your recruitment. Replying to a recruitment message with a battle ID also shares it.\n\
+            Usage: `/raid_id id:<battle ID> [message] [dm]`",
+            false
+        )
+        .field(
+            "/my_recruitments",
+            "Show the recruitments you created or joined with your elements, split into upcoming and recently started ones.\n\
+            Usage: `/my_recruitments`",
+            false
+        )
+        .field(
+            "/calendar",
+            "Export upcoming recruitments as an .ics file for your calendar app, for the whole server or only the ones you created or joined.\n\
+            Usage: `/calendar scope:<guild|joined> [reset_feed]`",
+            false
+        )
+        .field(
+            "/guild_war",
+            "Show the Guild War schedule, and announce the start and end of each round. `/recruit event_date:本戦1日目 22時` uses the schedule.\n\
+            Usage: `/guild_war import|show|clear`\n\
+            Note: `import` and `clear` require the bot control role.",
+            false
+        )
+        .field(
+            "/recruit_template",
+            "Save named presets (quest, battle type, capacity, notes, channel) and start them with `/recruit template:<name>`.\n\
+            Usage: `/recruit_template save|list|delete|publish|unpublish`\n\
+            Note: `publish` and `unpublish` require the bot control role.",
+            false
+        )
+        .field(
+            "/recruit_rule",
+            "Post a recruitment automatically every week (e.g. every Saturday 21:00), some time before the start.\n\
+            Usage: `/recruit_rule add|list|pause|resume|delete`\n\
+            Note: Requires the bot control role.",
             false
         )
         .field(
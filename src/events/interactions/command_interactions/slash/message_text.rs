@@ -0,0 +1,97 @@
+use crate::types::{PoiseContext, PoiseError};
+use crate::facades::message_text;
+use crate::services::permission::checks;
+
+/// Manage this server's bot messages
+#[poise::command(
+    slash_command,
+    name_localized("ja", "メッセージ"),
+    description_localized("ja", "このサーバーでBotが送信するメッセージを変更します"),
+    subcommands("message_text_list", "message_text_show", "message_text_set", "message_text_reset"),
+    subcommand_required,
+    guild_only,
+    check = "checks::bot_control"
+)]
+pub async fn message_text(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// List the recruitment templates and this server's custom messages
+#[poise::command(
+    slash_command,
+    rename = "list",
+    name_localized("ja", "一覧"),
+    description_localized("ja", "募集のテンプレートと独自に設定したメッセージを一覧表示します"),
+    ephemeral
+)]
+pub async fn message_text_list(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    message_text::list(&ctx).await
+}
+
+/// Show a message in Japanese and English
+#[poise::command(
+    slash_command,
+    rename = "show",
+    name_localized("ja", "表示"),
+    description_localized("ja", "現在のメッセージを日本語と英語で表示します"),
+    ephemeral
+)]
+pub async fn message_text_show(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Message key or ID (e.g. battle_recruitment.template.body, MSG00032)"]
+    #[description_localized("ja", "メッセージのキーまたはID（例: battle_recruitment.template.body, MSG00032）")]
+    message_id: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    message_text::show(&ctx, &message_id).await
+}
+
+/// Preview and save a message for this server
+#[poise::command(
+    slash_command,
+    rename = "set",
+    name_localized("ja", "変更"),
+    description_localized("ja", "見本の値でプレビューしてからメッセージを保存します"),
+    ephemeral
+)]
+pub async fn message_text_set(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Message key or ID (e.g. battle_recruitment.template.body, MSG00032)"]
+    #[description_localized("ja", "メッセージのキーまたはID（例: battle_recruitment.template.body, MSG00032）")]
+    message_id: String,
+
+    #[description = "Japanese message (\\n for a line break)"]
+    #[description_localized("ja", "日本語のメッセージ（\\n で改行）")]
+    japanese: Option<String>,
+
+    #[description = "English message (\\n for a line break)"]
+    #[description_localized("ja", "英語のメッセージ（\\n で改行）")]
+    english: Option<String>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    message_text::set(&ctx, &message_id, japanese.as_deref(), english.as_deref()).await
+}
+
+/// Go back to the bundled message
+#[poise::command(
+    slash_command,
+    rename = "reset",
+    name_localized("ja", "リセット"),
+    description_localized("ja", "独自の設定を削除し、同梱のメッセージに戻します"),
+    ephemeral
+)]
+pub async fn message_text_reset(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Message key or ID (e.g. battle_recruitment.template.body, MSG00032)"]
+    #[description_localized("ja", "メッセージのキーまたはID（例: battle_recruitment.template.body, MSG00032）")]
+    message_id: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    message_text::reset(&ctx, &message_id).await
+}
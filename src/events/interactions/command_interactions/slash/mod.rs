@@ -1,3 +1,18 @@
 pub mod recruit;
 pub mod environ_load;
-pub mod help;
\ No newline at end of file
+pub mod environ;
+pub mod message_text;
+pub mod recruit_rule;
+pub mod recruit_template;
+pub mod recruit_edit;
+pub mod recruit_start;
+pub mod recruit_cancel;
+pub mod recruit_transfer;
+pub mod recruit_list;
+pub mod my_recruitments;
+pub mod raid_id;
+pub mod calendar;
+pub mod guild_war;
+pub mod help;
+pub mod quest;pub mod settings;
+pub mod permission;
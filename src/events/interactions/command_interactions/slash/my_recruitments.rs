@@ -0,0 +1,17 @@
+use crate::facades::my_recruitments;
+use crate::types::{PoiseContext, PoiseError};
+
+/// Show the recruitments you created or joined, upcoming and past
+#[poise::command(
+    slash_command,
+    name_localized("ja", "参加中の募集"),
+    description_localized("ja", "作成または参加した募集を開始前と開始済みに分けて表示します"),
+    guild_only,
+    ephemeral
+)]
+pub async fn my_recruitments(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    my_recruitments::list(&ctx).await
+}
@@ -0,0 +1,85 @@
+use poise::serenity_prelude::{Role, User};
+use crate::types::{Capability, PoiseContext, PoiseError};
+use crate::facades::permission;
+
+/// Manage bot permissions for roles and users
+#[poise::command(
+    slash_command,
+    name_localized("ja", "権限"),
+    description_localized("ja", "ロールやユーザーに付与するBotの権限を管理します"),
+    subcommands("permission_grant", "permission_revoke", "permission_list"),
+    subcommand_required,
+    guild_only
+)]
+pub async fn permission(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// Grant a capability to a role or a user
+#[poise::command(
+    slash_command,
+    rename = "grant",
+    name_localized("ja", "付与"),
+    description_localized("ja", "ロールまたはユーザーに権限を付与します"),
+    ephemeral
+)]
+pub async fn permission_grant(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Capability"]
+    #[description_localized("ja", "権限")]
+    capability: Capability,
+
+    #[description = "Role to grant the capability to"]
+    #[description_localized("ja", "権限を付与するロール")]
+    role: Option<Role>,
+
+    #[description = "User to grant the capability to"]
+    #[description_localized("ja", "権限を付与するユーザー")]
+    user: Option<User>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    permission::grant(&ctx, capability, role, user).await
+}
+
+/// Revoke a capability from a role or a user
+#[poise::command(
+    slash_command,
+    rename = "revoke",
+    name_localized("ja", "取り消し"),
+    description_localized("ja", "ロールまたはユーザーの権限を取り消します"),
+    ephemeral
+)]
+pub async fn permission_revoke(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Capability"]
+    #[description_localized("ja", "権限")]
+    capability: Capability,
+
+    #[description = "Role to revoke the capability from"]
+    #[description_localized("ja", "権限を取り消すロール")]
+    role: Option<Role>,
+
+    #[description = "User to revoke the capability from"]
+    #[description_localized("ja", "権限を取り消すユーザー")]
+    user: Option<User>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    permission::revoke(&ctx, capability, role, user).await
+}
+
+/// List granted capabilities
+#[poise::command(
+    slash_command,
+    rename = "list",
+    name_localized("ja", "一覧"),
+    description_localized("ja", "付与されている権限の一覧を表示します"),
+    ephemeral
+)]
+pub async fn permission_list(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    permission::list(&ctx).await
+}
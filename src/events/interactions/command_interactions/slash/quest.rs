@@ -0,0 +1,220 @@
+use poise::serenity_prelude::Attachment;
+use crate::types::{PoiseContext, PoiseError};
+use crate::facades::quest;
+use crate::services::permission::checks;
+use crate::services::quest::catalog::CatalogFormat;
+
+/// Manage quests
+#[poise::command(
+    slash_command,
+    name_localized("ja", "クエスト"),
+    description_localized("ja", "クエストマスタを管理します"),
+    subcommands("quest_add", "quest_edit", "quest_remove", "quest_list", "quest_import", "quest_export"),
+    subcommand_required,
+    guild_only,
+    check = "checks::manage_quests"
+)]
+pub async fn quest(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// Add a quest
+#[poise::command(
+    slash_command,
+    rename = "add",
+    name_localized("ja", "追加"),
+    description_localized("ja", "クエストを追加します"),
+    ephemeral
+)]
+pub async fn quest_add(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Quest target ID"]
+    #[description_localized("ja", "クエストのtarget_id")]
+    target_id: i32,
+
+    #[description = "Quest name"]
+    #[description_localized("ja", "クエスト名")]
+    quest_name: String,
+
+    #[description = "Default battle type (0-7)"]
+    #[description_localized("ja", "デフォルトの戦闘スタイル(0〜7)")]
+    default_battle_type: i32,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::add(&ctx, target_id, &quest_name, default_battle_type).await
+}
+
+/// Edit a quest
+#[poise::command(
+    slash_command,
+    rename = "edit",
+    name_localized("ja", "編集"),
+    description_localized("ja", "クエストを編集します"),
+    ephemeral
+)]
+pub async fn quest_edit(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Quest target ID"]
+    #[description_localized("ja", "クエストのtarget_id")]
+    target_id: i32,
+
+    #[description = "New quest name"]
+    #[description_localized("ja", "新しいクエスト名")]
+    quest_name: Option<String>,
+
+    #[description = "New default battle type (0-7)"]
+    #[description_localized("ja", "新しいデフォルトの戦闘スタイル(0〜7)")]
+    default_battle_type: Option<i32>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::edit(&ctx, target_id, quest_name.as_deref(), default_battle_type).await
+}
+
+/// Remove a quest and its aliases
+#[poise::command(
+    slash_command,
+    rename = "remove",
+    name_localized("ja", "削除"),
+    description_localized("ja", "クエストを別名ごと削除します"),
+    ephemeral
+)]
+pub async fn quest_remove(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Quest target ID"]
+    #[description_localized("ja", "クエストのtarget_id")]
+    target_id: i32,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::remove(&ctx, target_id).await
+}
+
+/// List quests
+#[poise::command(
+    slash_command,
+    rename = "list",
+    name_localized("ja", "一覧"),
+    description_localized("ja", "クエスト一覧を表示します"),
+    ephemeral
+)]
+pub async fn quest_list(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::list(&ctx).await
+}
+
+/// Import quests and aliases from a JSON or CSV file
+#[poise::command(
+    slash_command,
+    rename = "import",
+    name_localized("ja", "取り込み"),
+    description_localized("ja", "JSONまたはCSVファイルからクエストと別名を取り込みます"),
+    ephemeral
+)]
+pub async fn quest_import(
+    ctx: PoiseContext<'_>,
+
+    #[description = "JSON or CSV file (upserted by target_id)"]
+    #[description_localized("ja", "JSONまたはCSVファイル（target_idで追加・更新）")]
+    file: Attachment,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::import(&ctx, &file).await
+}
+
+/// Export quests and aliases as a JSON or CSV file
+#[poise::command(
+    slash_command,
+    rename = "export",
+    name_localized("ja", "書き出し"),
+    description_localized("ja", "クエストと別名をJSONまたはCSVファイルに書き出します"),
+    ephemeral
+)]
+pub async fn quest_export(
+    ctx: PoiseContext<'_>,
+
+    #[description = "File format"]
+    #[description_localized("ja", "ファイル形式")]
+    format: CatalogFormat,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::export(&ctx, format).await
+}
+
+/// Manage quest aliases
+#[poise::command(
+    slash_command,
+    name_localized("ja", "別名"),
+    description_localized("ja", "クエスト別名を管理します"),
+    subcommands("alias_add", "alias_remove", "alias_list"),
+    subcommand_required,
+    guild_only,
+    check = "checks::manage_quests"
+)]
+pub async fn alias(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// Add a quest alias
+#[poise::command(
+    slash_command,
+    rename = "add",
+    name_localized("ja", "追加"),
+    description_localized("ja", "クエスト別名を追加します"),
+    ephemeral
+)]
+pub async fn alias_add(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Quest target ID"]
+    #[description_localized("ja", "クエストのtarget_id")]
+    target_id: i32,
+
+    #[description = "Alias"]
+    #[description_localized("ja", "別名")]
+    alias: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::alias_add(&ctx, target_id, &alias).await
+}
+
+/// Remove a quest alias
+#[poise::command(
+    slash_command,
+    rename = "remove",
+    name_localized("ja", "削除"),
+    description_localized("ja", "クエスト別名を削除します"),
+    ephemeral
+)]
+pub async fn alias_remove(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Alias"]
+    #[description_localized("ja", "別名")]
+    alias: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::alias_remove(&ctx, &alias).await
+}
+
+/// List quest aliases
+#[poise::command(
+    slash_command,
+    rename = "list",
+    name_localized("ja", "一覧"),
+    description_localized("ja", "クエスト別名の一覧を表示します"),
+    ephemeral
+)]
+pub async fn alias_list(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Quest target ID (all aliases when omitted)"]
+    #[description_localized("ja", "クエストのtarget_id（省略時は全件）")]
+    target_id: Option<i32>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    quest::alias_list(&ctx, target_id).await
+}
@@ -0,0 +1,29 @@
+use crate::facades::raid_id;
+use crate::types::{PoiseContext, PoiseError};
+
+/// Share a battle ID with the participants of your recruitment
+#[poise::command(
+    slash_command,
+    name_localized("ja", "参戦id"),
+    description_localized("ja", "参戦IDを募集の参加者に共有します"),
+    guild_only,
+    ephemeral
+)]
+pub async fn raid_id(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Battle ID (8 characters), or text containing it"]
+    #[description_localized("ja", "参戦ID（8桁）またはそれを含む文章")]
+    id: String,
+
+    #[description = "Link or ID of the recruitment message (your nearest one in this channel when omitted)"]
+    #[description_localized("ja", "募集メッセージのリンクまたはID（省略時はこのチャンネルの直近の募集）")]
+    message: Option<String>,
+
+    #[description = "Send it by DM instead of mentioning the participants"]
+    #[description_localized("ja", "メンションの代わりにDMで送る")]
+    dm: Option<bool>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    raid_id::share(&ctx, &id, message.as_deref(), dm.unwrap_or(false)).await
+}
@@ -1,8 +1,9 @@
 use futures::Stream;
-use sea_orm::TransactionTrait;
-use crate::facades::battle_recruitment;
+use crate::facades::{battle_recruitment, guild_war};
+use crate::repository::Database;
+use crate::services::message::Messages;
 use crate::types::{BattleType, PoiseContext, PoiseError};
-use crate::utils::database::DatabaseServiceExt;
+use crate::utils::text_normalizer::normalize_alias;
 // use crate::services::battle_recruitment::_recruitment::RecruitmentService;
 
 #[poise::command(
@@ -13,14 +14,19 @@ use crate::utils::database::DatabaseServiceExt;
 pub async fn handle_recruit_command(
     ctx: PoiseContext<'_>,
 
-    #[description = "quest name or alias"]
-    #[description_localized("ja", "クエスト名またはクエスト別名")]
+    #[description = "quest name or alias (overrides the template's quest)"]
+    #[description_localized("ja", "クエスト名またはクエスト別名（テンプレートのクエストより優先）")]
     #[autocomplete = "quest_auto_complete"]
-    quest: String,
+    quest: Option<String>,
 
-    #[description = "Quest departure date and time"]
-    #[description_localized("ja", "クエスト出発日時")]
-    event_date: String,
+    #[description = "Quest departure date and time (server default start time when omitted)"]
+    #[description_localized("ja", "クエスト出発日時（省略時はサーバー設定の開始時刻）")]
+    event_date: Option<String>,
+
+    #[description = "Saved recruitment template"]
+    #[description_localized("ja", "保存した募集テンプレート")]
+    #[autocomplete = "template_auto_complete"]
+    template: Option<String>,
 
     // Temporarily removing BattleType parameter until traits are implemented
     // #[description = "Quest Combat Style"]
@@ -29,40 +35,88 @@ pub async fn handle_recruit_command(
 
 ) -> Result<(), PoiseError> {
     ctx.defer().await?;
-
-    let db = &ctx.data().db;
+    let messages = Messages::for_context(&ctx).await;
 
     // Use default battle_recruitment type for now
     let battle_type = BattleType::Default;
 
-    // let _event_datetime = RecruitmentService::parse_event_date(&event_date).await?;
+    // 日時が省略された場合はサーバー設定の開始時刻を使う
+    let event_datetime = match event_date.as_deref().map(str::trim).filter(|date| !date.is_empty()) {
+        Some(event_date) => match guild_war::parse_event_date(&ctx, &messages, event_date).await {
+            Ok(event_datetime) => Some(event_datetime),
+            Err(e) => {
+                ctx.say(messages.get_with_params("battle_recruitment.create_failed", &[("error", &e)])).await?;
+                return Err(e.into());
+            }
+        },
+        None => None,
+    };
 
-    // Call the updated battle_recruitment::new function
-    match battle_recruitment::new(&ctx, &quest, battle_type).await {
+    let quest = quest.as_deref().map(str::trim).filter(|quest| !quest.is_empty());
+    let template = template.as_deref().map(str::trim).filter(|template| !template.is_empty());
+    let result = match (template, quest) {
+        (Some(template), quest) => battle_recruitment::new_from_template(&ctx, &messages, template, quest, event_datetime).await,
+        (None, Some(quest)) => battle_recruitment::new(&ctx, &messages, quest, battle_type, event_datetime).await,
+        (None, None) => Err(messages.get("battle_recruitment.quest_required")),
+    };
+    match result {
         Ok(_) => {
-            ctx.say("募集が正常に作成されました。").await?;
+            ctx.say(messages.get("battle_recruitment.created")).await?;
             Ok(())
         },
         Err(e) => {
-            ctx.say(format!("募集作成に失敗しました: {}", e)).await?;
+            ctx.say(messages.get_with_params("battle_recruitment.create_failed", &[("error", &e)])).await?;
             Err(e.into())
         }
     }
 }
 
-async fn quest_auto_complete<'a>(
-    _ctx: PoiseContext<'_>,
+/// クエスト名と別名から候補を返す（ひらがな・カタカナ、全角・半角を区別しない）
+pub(crate) async fn quest_auto_complete<'a>(
+    ctx: PoiseContext<'_>,
+    partial: &'a str,
+) -> impl Stream<Item = String> + 'a {
+    let Ok(db) = Database::new().await else {
+        return futures::stream::iter(Vec::new());
+    };
+    let guild_id = ctx.guild_id().map(|guild_id| guild_id.get() as i64);
+    let quests = db.quest.get_all(guild_id).await.unwrap_or_default();
+    let aliases = db.quest.get_aliases(guild_id).await.unwrap_or_default();
+
+    let partial = normalize_alias(partial);
+    let mut candidates: Vec<String> = aliases.into_iter()
+        .map(|a| a.alias)
+        .chain(quests.into_iter().map(|q| q.quest_name))
+        .filter(|name| normalize_alias(name).contains(&partial))
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+    // Discordのオートコンプリートは25件まで
+    candidates.truncate(25);
+
+    futures::stream::iter(candidates)
+}
+
+/// 自分の募集テンプレートと公開されたテンプレートの名前から候補を返す
+pub(crate) async fn template_auto_complete<'a>(
+    ctx: PoiseContext<'_>,
     partial: &'a str,
 ) -> impl Stream<Item = String> + 'a {
-    // Use a static list to avoid borrowing local variables
-    const QUEST_LIST: &[&str] = &["Amanda", "Bob", "Christian", "Danny", "Ester", "Falk"];
-    
-    // Pre-filter the list synchronously to avoid lifetime issues
-    let filtered_items: Vec<String> = QUEST_LIST
-        .iter()
-        .filter(|name| name.starts_with(partial))
-        .map(|name| name.to_string())
+    let (Some(guild_id), Ok(db)) = (ctx.guild_id(), Database::new().await) else {
+        return futures::stream::iter(Vec::new());
+    };
+    let templates = db.recruitment_template.get_visible(guild_id.get() as i64, ctx.author().id.get() as i64).await
+        .unwrap_or_default();
+
+    let partial = normalize_alias(partial);
+    let mut candidates: Vec<String> = templates.into_iter()
+        .map(|t| t.name)
+        .filter(|name| normalize_alias(name).contains(&partial))
         .collect();
-    
-    futures::stream::iter(filtered_items)
+    candidates.sort();
+    candidates.dedup();
+    // Discordのオートコンプリートは25件まで
+    candidates.truncate(25);
+
+    futures::stream::iter(candidates)
 }
@@ -0,0 +1,30 @@
+use crate::facades::battle_recruitment;
+use crate::services::message::Messages;
+use crate::types::{PoiseContext, PoiseError};
+
+// 募集者と、他人の募集のキャンセル権限を持つメンバーのみキャンセルできる
+/// Cancel a recruitment before it starts and notify its participants
+#[poise::command(
+    slash_command,
+    name_localized("ja", "募集キャンセル"),
+    description_localized("ja", "開始前の募集をキャンセルし、参加者に知らせます"),
+    guild_only,
+    ephemeral
+)]
+pub async fn recruit_cancel(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Link or ID of the recruitment message"]
+    #[description_localized("ja", "募集メッセージのリンクまたはID")]
+    message: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    let messages = Messages::for_context(&ctx).await;
+
+    let content = match battle_recruitment::cancel(&ctx, &messages, &message).await {
+        Ok(()) => messages.get("battle_recruitment.cancelled_now"),
+        Err(e) => e,
+    };
+    ctx.say(content).await?;
+    Ok(())
+}
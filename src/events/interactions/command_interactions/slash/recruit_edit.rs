@@ -0,0 +1,60 @@
+use crate::facades::{battle_recruitment, guild_war};
+use crate::services::message::Messages;
+use crate::types::{BattleType, PoiseContext, PoiseError};
+
+// 募集者と、他人の募集のキャンセル権限を持つメンバーのみ変更できる
+/// Change the time or details of a posted recruitment
+#[poise::command(
+    slash_command,
+    name_localized("ja", "募集変更"),
+    description_localized("ja", "投稿済みの募集の開催日時や内容を変更し、参加者に通知します"),
+    guild_only,
+    ephemeral
+)]
+pub async fn recruit_edit(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Link or ID of the recruitment message"]
+    #[description_localized("ja", "募集メッセージのリンクまたはID")]
+    message: String,
+
+    #[description = "New quest departure date and time"]
+    #[description_localized("ja", "新しいクエスト出発日時")]
+    event_date: Option<String>,
+
+    #[description = "New battle type"]
+    #[description_localized("ja", "新しい戦闘スタイル")]
+    battle_type: Option<BattleType>,
+
+    #[description = "New number of members"]
+    #[description_localized("ja", "新しい募集人数")]
+    #[min = 1]
+    #[max = 30]
+    capacity: Option<u32>,
+
+    #[description = "New notes (\"-\" to remove them)"]
+    #[description_localized("ja", "新しい補足（「-」で削除）")]
+    notes: Option<String>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    let messages = Messages::for_context(&ctx).await;
+
+    let event_datetime = match event_date.as_deref().map(str::trim).filter(|date| !date.is_empty()) {
+        Some(event_date) => match guild_war::parse_event_date(&ctx, &messages, event_date).await {
+            Ok(event_datetime) => Some(event_datetime),
+            Err(e) => {
+                ctx.say(e).await?;
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+
+    let capacity = capacity.map(|capacity| capacity as usize);
+    let content = match battle_recruitment::edit(&ctx, &messages, &message, event_datetime, battle_type, capacity, notes.as_deref()).await {
+        Ok(()) => messages.get("battle_recruitment.edited"),
+        Err(e) => e,
+    };
+    ctx.say(content).await?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use poise::serenity_prelude as serenity;
+use crate::facades::recruitment_list;
+use crate::types::{PoiseContext, PoiseError, RecruitmentStatus};
+
+/// List this server's recruitments with links to their messages
+#[poise::command(
+    slash_command,
+    name_localized("ja", "募集一覧"),
+    description_localized("ja", "サーバーの募集を開催日時の順に一覧表示します"),
+    guild_only,
+    ephemeral
+)]
+pub async fn recruit_list(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Only recruitments in this channel"]
+    #[description_localized("ja", "このチャンネルの募集のみ")]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+
+    #[description = "Status (open and full when omitted)"]
+    #[description_localized("ja", "状態（省略時は募集中と満員）")]
+    status: Option<RecruitmentStatus>,
+
+    #[description = "Days ahead (all when omitted), or days back for started ones (7 when omitted)"]
+    #[description_localized("ja", "今後の日数（省略時はすべて）。開始済みは遡る日数（省略時は7日）")]
+    #[min = 1]
+    #[max = 60]
+    days: Option<u32>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_list::list(&ctx, channel.map(|channel| channel.id), status, days).await
+}
@@ -0,0 +1,130 @@
+use poise::serenity_prelude as serenity;
+use crate::types::{DayOfWeek, PoiseContext, PoiseError};
+use crate::facades::recruitment_rule;
+use crate::services::permission::checks;
+use super::recruit::quest_auto_complete;
+
+/// Manage recurring recruitments
+#[poise::command(
+    slash_command,
+    name_localized("ja", "定期募集"),
+    description_localized("ja", "毎週決まった曜日・時刻の募集を自動で投稿します"),
+    subcommands("recruit_rule_add", "recruit_rule_list", "recruit_rule_pause", "recruit_rule_resume", "recruit_rule_delete"),
+    subcommand_required,
+    guild_only,
+    check = "checks::bot_control"
+)]
+pub async fn recruit_rule(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// Post a recruitment every week
+#[poise::command(
+    slash_command,
+    rename = "add",
+    name_localized("ja", "追加"),
+    description_localized("ja", "毎週の募集を追加します"),
+    ephemeral
+)]
+pub async fn recruit_rule_add(
+    ctx: PoiseContext<'_>,
+
+    #[description = "quest name or alias"]
+    #[description_localized("ja", "クエスト名またはクエスト別名")]
+    #[autocomplete = "quest_auto_complete"]
+    quest: String,
+
+    #[description = "Day of the week"]
+    #[description_localized("ja", "曜日")]
+    weekday: DayOfWeek,
+
+    #[description = "Start time HH:MM (server default start time when omitted)"]
+    #[description_localized("ja", "開始時刻 HH:MM（省略時はサーバー設定の開始時刻）")]
+    start_time: Option<String>,
+
+    #[description = "Channel to post in (recruitment channel setting when omitted)"]
+    #[description_localized("ja", "投稿するチャンネル（省略時はサーバー設定の募集チャンネル）")]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+
+    #[description = "Minutes before the start time to post, at least 5 (1 day when omitted)"]
+    #[description_localized("ja", "開始時刻の何分前に投稿するか（5分以上、省略時は1日前）")]
+    #[min = 5]
+    lead_time: Option<i64>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    let channel_id = channel.map(|channel| channel.id);
+    recruitment_rule::add(&ctx, &quest, weekday, start_time.as_deref(), channel_id, lead_time).await
+}
+
+/// List the recurring recruitments
+#[poise::command(
+    slash_command,
+    rename = "list",
+    name_localized("ja", "一覧"),
+    description_localized("ja", "定期募集と次回の開始日時を一覧表示します"),
+    ephemeral
+)]
+pub async fn recruit_rule_list(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_rule::list(&ctx).await
+}
+
+/// Stop posting a recurring recruitment
+#[poise::command(
+    slash_command,
+    rename = "pause",
+    name_localized("ja", "停止"),
+    description_localized("ja", "定期募集の投稿を停止します"),
+    ephemeral
+)]
+pub async fn recruit_rule_pause(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Rule ID shown in the list"]
+    #[description_localized("ja", "一覧に表示されるID")]
+    id: i32,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_rule::set_paused(&ctx, id, true).await
+}
+
+/// Resume posting a recurring recruitment
+#[poise::command(
+    slash_command,
+    rename = "resume",
+    name_localized("ja", "再開"),
+    description_localized("ja", "停止中の定期募集を再開します"),
+    ephemeral
+)]
+pub async fn recruit_rule_resume(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Rule ID shown in the list"]
+    #[description_localized("ja", "一覧に表示されるID")]
+    id: i32,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_rule::set_paused(&ctx, id, false).await
+}
+
+/// Delete a recurring recruitment
+#[poise::command(
+    slash_command,
+    rename = "delete",
+    name_localized("ja", "削除"),
+    description_localized("ja", "定期募集を削除します"),
+    ephemeral
+)]
+pub async fn recruit_rule_delete(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Rule ID shown in the list"]
+    #[description_localized("ja", "一覧に表示されるID")]
+    id: i32,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_rule::delete(&ctx, id).await
+}
@@ -0,0 +1,30 @@
+use crate::facades::battle_recruitment;
+use crate::services::message::Messages;
+use crate::types::{PoiseContext, PoiseError};
+
+// 募集者と、他人の募集のキャンセル権限を持つメンバーのみ開始できる
+/// Start a recruitment now and call its participants
+#[poise::command(
+    slash_command,
+    name_localized("ja", "募集開始"),
+    description_localized("ja", "開始時刻を待たずに募集を開始し、参加者を呼び出します"),
+    guild_only,
+    ephemeral
+)]
+pub async fn recruit_start(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Link or ID of the recruitment message"]
+    #[description_localized("ja", "募集メッセージのリンクまたはID")]
+    message: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    let messages = Messages::for_context(&ctx).await;
+
+    let content = match battle_recruitment::start_now(&ctx, &messages, &message).await {
+        Ok(()) => messages.get("battle_recruitment.started_now"),
+        Err(e) => e,
+    };
+    ctx.say(content).await?;
+    Ok(())
+}
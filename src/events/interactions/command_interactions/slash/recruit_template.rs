@@ -0,0 +1,140 @@
+use poise::serenity_prelude as serenity;
+use crate::types::{BattleType, PoiseContext, PoiseError};
+use crate::facades::recruitment_template;
+use crate::services::permission::checks;
+use super::recruit::{quest_auto_complete, template_auto_complete};
+
+/// Save recruitment presets and start recruitments with `/recruit template:`
+#[poise::command(
+    slash_command,
+    name_localized("ja", "募集テンプレート"),
+    description_localized("ja", "募集の設定を名前を付けて保存し、/recruit の template で使えるようにします"),
+    subcommands("recruit_template_save", "recruit_template_list", "recruit_template_delete", "recruit_template_publish", "recruit_template_unpublish"),
+    subcommand_required,
+    guild_only
+)]
+pub async fn recruit_template(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// Save a recruitment template (replaces your template with the same name)
+#[poise::command(
+    slash_command,
+    rename = "save",
+    name_localized("ja", "保存"),
+    description_localized("ja", "募集テンプレートを保存します（同じ名前の自分のテンプレートは上書き）"),
+    ephemeral
+)]
+pub async fn recruit_template_save(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Template name"]
+    #[description_localized("ja", "テンプレート名")]
+    name: String,
+
+    #[description = "quest name or alias"]
+    #[description_localized("ja", "クエスト名またはクエスト別名")]
+    #[autocomplete = "quest_auto_complete"]
+    quest: String,
+
+    #[description = "Battle type (default when omitted)"]
+    #[description_localized("ja", "戦闘スタイル（省略時はデフォルト）")]
+    battle_type: Option<BattleType>,
+
+    #[description = "Number of members (6 when omitted)"]
+    #[description_localized("ja", "募集人数（省略時は6人）")]
+    #[min = 1]
+    #[max = 30]
+    capacity: Option<i64>,
+
+    #[description = "Notes added to the recruitment message"]
+    #[description_localized("ja", "募集メッセージに添える補足")]
+    notes: Option<String>,
+
+    #[description = "Channel to post in (recruitment channel setting when omitted)"]
+    #[description_localized("ja", "投稿するチャンネル（省略時はサーバー設定の募集チャンネル）")]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    let battle_type = battle_type.unwrap_or(BattleType::Default);
+    let channel_id = channel.map(|channel| channel.id);
+    recruitment_template::save(&ctx, &name, &quest, battle_type, capacity, notes.as_deref(), channel_id).await
+}
+
+/// List your templates and the published ones
+#[poise::command(
+    slash_command,
+    rename = "list",
+    name_localized("ja", "一覧"),
+    description_localized("ja", "自分のテンプレートと公開されたテンプレートを一覧表示します"),
+    ephemeral
+)]
+pub async fn recruit_template_list(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_template::list(&ctx).await
+}
+
+/// Delete one of your templates
+#[poise::command(
+    slash_command,
+    rename = "delete",
+    name_localized("ja", "削除"),
+    description_localized("ja", "自分のテンプレートを削除します"),
+    ephemeral
+)]
+pub async fn recruit_template_delete(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Template name"]
+    #[description_localized("ja", "テンプレート名")]
+    #[autocomplete = "template_auto_complete"]
+    name: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_template::delete(&ctx, &name).await
+}
+
+/// Publish one of your templates to the whole server
+#[poise::command(
+    slash_command,
+    rename = "publish",
+    name_localized("ja", "公開"),
+    description_localized("ja", "自分のテンプレートをサーバー全体に公開します"),
+    check = "checks::bot_control",
+    ephemeral
+)]
+pub async fn recruit_template_publish(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Template name"]
+    #[description_localized("ja", "テンプレート名")]
+    #[autocomplete = "template_auto_complete"]
+    name: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_template::publish(&ctx, &name).await
+}
+
+/// Stop publishing a template
+#[poise::command(
+    slash_command,
+    rename = "unpublish",
+    name_localized("ja", "公開取り消し"),
+    description_localized("ja", "公開されたテンプレートを非公開に戻します"),
+    check = "checks::bot_control",
+    ephemeral
+)]
+pub async fn recruit_template_unpublish(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Template name"]
+    #[description_localized("ja", "テンプレート名")]
+    #[autocomplete = "template_auto_complete"]
+    name: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    recruitment_template::unpublish(&ctx, &name).await
+}
@@ -0,0 +1,35 @@
+use poise::serenity_prelude as serenity;
+use crate::facades::battle_recruitment;
+use crate::services::message::Messages;
+use crate::types::{PoiseContext, PoiseError};
+
+// 募集者と、他人の募集のキャンセル権限を持つメンバーのみ変更できる
+/// Hand a recruitment over to one of its participants
+#[poise::command(
+    slash_command,
+    name_localized("ja", "募集者変更"),
+    description_localized("ja", "募集の募集者を参加者の1人に変更します"),
+    guild_only,
+    ephemeral
+)]
+pub async fn recruit_transfer(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Link or ID of the recruitment message"]
+    #[description_localized("ja", "募集メッセージのリンクまたはID")]
+    message: String,
+
+    #[description = "New creator (must have joined the recruitment)"]
+    #[description_localized("ja", "新しい募集者（募集の参加者）")]
+    member: serenity::User,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    let messages = Messages::for_context(&ctx).await;
+
+    let content = match battle_recruitment::transfer(&ctx, &messages, &message, member.id).await {
+        Ok(()) => messages.get_with_params("battle_recruitment.transferred", &[("member", &format!("<@{}>", member.id.get()))]),
+        Err(e) => e,
+    };
+    ctx.say(content).await?;
+    Ok(())
+}
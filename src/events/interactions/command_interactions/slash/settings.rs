@@ -0,0 +1,73 @@
+use crate::types::{PoiseContext, PoiseError};
+use crate::facades::settings;
+use crate::services::settings::SettingKey;
+
+/// Manage server settings
+#[poise::command(
+    slash_command,
+    name_localized("ja", "設定"),
+    description_localized("ja", "サーバーごとの設定を管理します"),
+    subcommands("settings_show", "settings_set", "settings_reset"),
+    subcommand_required,
+    guild_only
+)]
+pub async fn settings(_: PoiseContext<'_>) -> Result<(), PoiseError> {
+    Ok(())
+}
+
+/// Show server settings
+#[poise::command(
+    slash_command,
+    rename = "show",
+    name_localized("ja", "表示"),
+    description_localized("ja", "サーバー設定を表示します"),
+    ephemeral
+)]
+pub async fn settings_show(
+    ctx: PoiseContext<'_>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    settings::show(&ctx).await
+}
+
+/// Change a server setting
+#[poise::command(
+    slash_command,
+    rename = "set",
+    name_localized("ja", "変更"),
+    description_localized("ja", "サーバー設定を変更します"),
+    ephemeral
+)]
+pub async fn settings_set(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Setting to change"]
+    #[description_localized("ja", "変更する項目")]
+    key: SettingKey,
+
+    #[description = "New value (#channel, HH:MM, role name, ja/en, +09:00, minutes such as 60,15)"]
+    #[description_localized("ja", "新しい値（#チャンネル、HH:MM、ロール名、ja/en、+09:00、60,15 のような分）")]
+    value: String,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    settings::set(&ctx, key, &value).await
+}
+
+/// Reset server settings to their defaults
+#[poise::command(
+    slash_command,
+    rename = "reset",
+    name_localized("ja", "リセット"),
+    description_localized("ja", "サーバー設定を既定値に戻します"),
+    ephemeral
+)]
+pub async fn settings_reset(
+    ctx: PoiseContext<'_>,
+
+    #[description = "Setting to reset (all settings when omitted)"]
+    #[description_localized("ja", "既定値に戻す項目（省略時はすべて）")]
+    key: Option<SettingKey>,
+) -> Result<(), PoiseError> {
+    ctx.defer_ephemeral().await?;
+    settings::reset(&ctx, key).await
+}
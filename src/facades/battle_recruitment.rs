@@ -1,22 +1,119 @@
 use crate::types::{PoiseContext, BattleType};
 use crate::services::battle_recruitment::recruitment::{
     NewRecruitmentService, UpdateRecruitmentService, ParticipantsService, 
-    CancelRecruitmentService, StartRecruitmentService
+    CancelRecruitmentService, StartRecruitmentService, JoinedRecruitmentService, ScheduledEventService
 };
 use crate::repository::Database;
+use crate::repository::battle_recruitment_repository::NewBattleRecruitment;
+use crate::services::settings::SettingsService;
+use crate::services::recruitment_template::RecruitmentTemplateService;
+use crate::services::message::Messages;
+use crate::services::battle_recruitment::params::{CreateParameter, EditParameter};
+use crate::services::battle_recruitment::template::RecruitmentVariables;
+use crate::services::battle_recruitment::DEFAULT_CAPACITY;
 use crate::utils::database::DatabaseServiceExt;
+use crate::utils::discord_helper::parse_message_link;
+use crate::services::permission::can_manage_recruitment;
+use crate::models::battle_recruitment::BattleRecruitment;
+use poise::serenity_prelude::{ChannelId, UserId};
 use std::sync::Arc;
 use tracing::{info, warn, error};
-use chrono::Local;
+use chrono::{DateTime, FixedOffset};
 
 /// 新しい募集を開始する
-pub(crate) async fn new(ctx: &PoiseContext<'_>, quest_alias: &str, battle_type: BattleType) -> Result<(), String> {
+/// エラーは `messages`（実行したユーザーの言語）で返す
+pub(crate) async fn new(ctx: &PoiseContext<'_>, messages: &Messages, quest_alias: &str, battle_type: BattleType, event_date: Option<DateTime<FixedOffset>>) -> Result<(), String> {
     info!("battle_recruitment::new - 新しい募集を開始します");
-    
-    // 他の関連操作...
-    // repository.set_recruitment_end_message(recruitment.id, some_message_id).await?;
 
-    Ok(())
+    let db = connect(messages).await?;
+    let recruitment = Recruitment {
+        quest_alias,
+        battle_type,
+        capacity: DEFAULT_CAPACITY,
+        notes: None,
+        channel_id: None,
+    };
+    create(ctx, messages, db, recruitment, event_date).await
+}
+
+/// 募集テンプレートから新しい募集を開始する
+/// `quest_alias` を指定した場合はテンプレートのクエストの代わりに使う
+pub(crate) async fn new_from_template(ctx: &PoiseContext<'_>, messages: &Messages, template_name: &str, quest_alias: Option<&str>, event_date: Option<DateTime<FixedOffset>>) -> Result<(), String> {
+    info!("battle_recruitment::new_from_template - テンプレートから募集を開始します");
+
+    let guild_id = ctx.guild_id()
+        .ok_or_else(|| messages.get("errors.guild_only"))?;
+    let db = connect(messages).await?;
+
+    let template = RecruitmentTemplateService::new(db.clone())
+        .find(messages, guild_id.get() as i64, ctx.author().id.get() as i64, template_name).await?;
+    let recruitment = Recruitment {
+        quest_alias: quest_alias.unwrap_or(&template.quest_alias),
+        battle_type: template.battle_type(),
+        capacity: template.capacity as usize,
+        notes: template.notes.as_deref(),
+        channel_id: template.channel_id.map(|channel_id| channel_id as u64),
+    };
+    create(ctx, messages, db, recruitment, event_date).await
+}
+
+/// 開始する募集の内容
+struct Recruitment<'a> {
+    quest_alias: &'a str,
+    battle_type: BattleType,
+    capacity: usize,
+    notes: Option<&'a str>,
+    /// 省略時はサーバー設定の募集チャンネル、なければ実行したチャンネル
+    channel_id: Option<u64>,
+}
+
+async fn create(ctx: &PoiseContext<'_>, messages: &Messages, db: Arc<Database>, recruitment: Recruitment<'_>, event_date: Option<DateTime<FixedOffset>>) -> Result<(), String> {
+    let guild_id = ctx.guild_id()
+        .ok_or_else(|| messages.get("errors.guild_only"))?;
+
+    // サーバー設定から募集チャンネルと既定の開催日時を決定
+    let settings = SettingsService::new(db.clone()).get(guild_id.get() as i64).await
+        .map_err(|_| messages.get("battle_recruitment.database_error"))?;
+    let channel_id = recruitment.channel_id
+        .or(settings.recruitment_channel_id.map(|channel_id| channel_id as u64))
+        .unwrap_or_else(|| ctx.channel_id().get());
+    let event_date = event_date.unwrap_or_else(|| settings.default_event_date());
+
+    // NewRecruitmentServiceのインスタンス作成
+    let service = NewRecruitmentService::new(db);
+
+    // 募集メッセージ作成処理を実行
+    let params = CreateParameter {
+        guild_id: guild_id.get(),
+        channel_id,
+        quest_alias: recruitment.quest_alias,
+        battle_type: recruitment.battle_type,
+        event_date: Some(event_date),
+        capacity: recruitment.capacity,
+        notes: recruitment.notes,
+        created_by: ctx.author().id.get(),
+    };
+    match service.create_recruitment(ctx.serenity_context(), messages, params).await {
+        Ok(message) => {
+            info!("新しい募集が正常に作成されました: message_id={}", message.id);
+            Ok(())
+        },
+        Err(e) => {
+            error!("募集作成エラー: {}", e);
+            Err(e)
+        }
+    }
+}
+
+/// データベース接続
+async fn connect(messages: &Messages) -> Result<Arc<Database>, String> {
+    match Database::new().await {
+        Ok(database) => Ok(Arc::new(database)),
+        Err(e) => {
+            error!("データベース接続エラー: {}", e);
+            Err(messages.get("errors.database_connection"))
+        }
+    }
 }
 
 /// Builder パターンを使った例（execute_in_transactionを使用）
@@ -32,14 +129,19 @@ pub(crate) async fn new_with_builder(ctx: &PoiseContext<'_>, quest_alias: &str,
             .map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("リポジトリ作成エラー: {}", e))) as crate::types::PoiseError)?;
 
         // 募集を作成
-        let recruitment = repository.battle_recruitment.create(
-            ctx.guild_id().unwrap().get() as i64,
-            ctx.channel_id().get() as i64,
-            12345, // TODO: 実際のメッセージIDを使用
-            1,     // TODO: 実際のtarget_idを使用
-            battle_type as i32,
-            chrono::Utc::now() + chrono::Duration::hours(1),
-        ).await
+        let recruitment = repository.battle_recruitment.create(NewBattleRecruitment {
+            guild_id: ctx.guild_id().unwrap().get() as i64,
+            channel_id: ctx.channel_id().get() as i64,
+            message_id: 12345, // TODO: 実際のメッセージIDを使用
+            target_id: 1,      // TODO: 実際のtarget_idを使用
+            battle_type_id: battle_type as i32,
+            expiry_date: chrono::Utc::now() + chrono::Duration::hours(1),
+            capacity: DEFAULT_CAPACITY as i32,
+            notes: None,
+            created_by: Some(ctx.author().id.get() as i64),
+            thread_id: None,
+            scheduled_event_id: None,
+        }).await
         .map_err(|e| Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("募集作成エラー: {}", e))) as crate::types::PoiseError)?;
 
         // 他の関連操作...
@@ -82,6 +184,35 @@ pub(crate) async fn information_update(ctx: &poise::serenity_prelude::Context, g
     }
 }
 
+/// 募集メッセージのリアクションから参加者の記録を更新する
+/// 募集ではないメッセージのリアクションは何もしない
+/// `joined` を指定した場合は、参加したメンバーを募集スレッドに追加する
+pub(crate) async fn sync_participants(ctx: &poise::serenity_prelude::Context, guild_id: u64, channel_id: u64, message_id: u64, joined: Option<UserId>) -> Result<(), String> {
+    if guild_id == 0 {
+        return Ok(());
+    }
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            error!("データベース接続エラー: {}", e);
+            return Err(format!("Database connection error: {}", e));
+        }
+    };
+
+    let Some(recruitment) = JoinedRecruitmentService::new(db).sync(ctx, guild_id, channel_id, message_id).await? else {
+        return Ok(());
+    };
+
+    // 参加したメンバーを募集スレッドに追加
+    if let (Some(thread_id), Some(user_id)) = (recruitment.thread_id, joined) {
+        ChannelId::new(thread_id as u64).add_thread_member(&ctx.http, user_id).await
+            .map_err(|e| format!("Failed to add a member to the recruitment thread: {}", e))?;
+    }
+    Ok(())
+}
+
 /// 参加者を更新する
 pub(crate) async fn member_update(ctx: &poise::serenity_prelude::Context, guild_id: u64, channel_id: u64, message_id: u64) -> Result<(), String> {
     info!("battle_recruitment::member_update - 参加者を更新します");
@@ -126,50 +257,53 @@ pub(crate) async fn member_update(ctx: &poise::serenity_prelude::Context, guild_
     }
 }
 
-/// 募集をキャンセルする
-pub(crate) async fn cancel(ctx: &poise::serenity_prelude::Context, guild_id: u64, channel_id: u64, message_id: u64) -> Result<(), String> {
+/// 開始前の募集をキャンセルし、募集メッセージをキャンセル済みの表示に変えて参加者に知らせる
+/// 予定されていたリマインドと開始時刻の呼び出しは送信しなくなる
+/// エラーは `messages`（実行したユーザーの言語）で返す
+pub(crate) async fn cancel(ctx: &PoiseContext<'_>, messages: &Messages, message_link: &str) -> Result<(), String> {
     info!("battle_recruitment::cancel - 募集をキャンセルします");
-    
-    // CancelRecruitmentServiceのインスタンス作成
-    let service = CancelRecruitmentService::new();
-    
-    // DBから募集情報を取得
-    if let Err(e) = service.get_recruitment_from_db(guild_id, channel_id, message_id).await {
-        error!("DB募集情報取得エラー: {}", e);
-        return Err(e);
+
+    let db = connect(messages).await?;
+    let recruitment = managed_recruitment(ctx, messages, &db, message_link).await?;
+    if recruitment.started_at.is_some() {
+        return Err(messages.get("battle_recruitment.already_started"));
     }
-    
-    // リアクションから参加者一覧取得
-    let participants = match service.get_participants_from_reactions(message_id).await {
+
+    let ctx = ctx.serenity_context();
+    let post_messages = messages.in_guild_language();
+    let service = CancelRecruitmentService::new(db.clone());
+
+    // 参加者は募集メッセージを書き換える前に取得する
+    let participants = match service.get_participants_from_reactions(ctx, &recruitment).await {
         Ok(participants) => participants,
         Err(e) => {
             error!("参加者取得エラー: {}", e);
-            return Err(e);
-        }
-    };
-    
-    // 募集メッセージをキャンセル済みメッセージに変えるためのメッセージ作成
-    let cancelled_message = match service.create_cancelled_message("元の募集内容").await {
-        Ok(message) => message,
-        Err(e) => {
-            error!("キャンセル済みメッセージ作成エラー: {}", e);
-            return Err(e);
+            return Err(messages.get("battle_recruitment.update_failed"));
         }
     };
-    
+
+    // 先にキャンセル済みにして、リマインドや開始の呼び出しが送信されないようにする
+    if !service.mark_recruitment_as_cancelled(messages, recruitment.id).await? {
+        info!("募集は既に開始またはキャンセルされています: id={}", recruitment.id);
+        return Err(messages.get("battle_recruitment.already_started"));
+    }
+
+    // 募集メッセージが削除されていても、キャンセルの通知は送る
+    if let Err(e) = service.update_cancelled_message(ctx, &post_messages, &recruitment).await {
+        warn!("キャンセル済みメッセージ更新エラー: {}", e);
+    }
+    ScheduledEventService::new(db.clone()).cancel(ctx, &recruitment).await;
+
     // キャンセル通知メッセージ作成（参加者にメンションを含む）
-    let notification_message = match service.create_cancel_notification(&participants).await {
-        Ok(message) => message,
-        Err(e) => {
-            error!("キャンセル通知メッセージ作成エラー: {}", e);
-            return Err(e);
-        }
-    };
-    
-    // 元の募集メッセージに返信する形でメッセージを送信
-    match service.send_cancel_reply(channel_id, message_id, &notification_message).await {
+    let quest_name = service.get_quest_name(&recruitment).await;
+    let timezone = SettingsService::new(db.clone()).timezone(recruitment.guild_id).await;
+    let variables = RecruitmentVariables::for_recruitment(&recruitment, &quest_name, &participants, timezone);
+    let notification_message = service.create_cancel_notification(&post_messages, &variables).await?;
+
+    // 募集スレッド、または元の募集メッセージに返信する形でメッセージを送信
+    match service.send_cancel_reply(ctx, messages, &recruitment, notification_message).await {
         Ok(_) => {
-            info!("募集キャンセル処理が完了しました: message_id={}", message_id);
+            info!("募集キャンセル処理が完了しました: id={}", recruitment.id);
             Ok(())
         },
         Err(e) => {
@@ -179,52 +313,152 @@ pub(crate) async fn cancel(ctx: &poise::serenity_prelude::Context, guild_id: u64
     }
 }
 
-/// 開始時間になった
-pub(crate) async fn start(ctx: &poise::serenity_prelude::Context, guild_id: u64, channel_id: u64, message_id: u64, recruitment_id: i64) -> Result<(), String> {
+/// 開始時間になった募集、または募集者が開始した募集の参加者を呼び出す
+/// 他のプロセスが先に開始した場合は何もしない
+pub(crate) async fn start(ctx: &poise::serenity_prelude::Context, db: Arc<Database>, guild_id: u64, channel_id: u64, message_id: u64) -> Result<(), String> {
     info!("battle_recruitment::start - 募集を開始します");
-    
+
     // StartRecruitmentServiceのインスタンス作成
-    let service = StartRecruitmentService::new();
-    
+    let messages = Messages::for_guild(&db, guild_id as i64).await;
+    let service = StartRecruitmentService::new(db.clone());
+
     // DBから募集情報を取得
-    if let Err(e) = service.get_recruitment_from_db(guild_id, channel_id, message_id).await {
-        error!("DB募集情報取得エラー: {}", e);
-        return Err(e);
+    let recruitment = match service.get_recruitment_from_db(&messages, guild_id, channel_id, message_id).await {
+        Ok(recruitment) => recruitment,
+        Err(e) => {
+            error!("DB募集情報取得エラー: {}", e);
+            return Err(e);
+        }
+    };
+
+    // 先に開始済みにして、呼び出しが二重に送信されないようにする
+    if !service.mark_recruitment_as_started(&messages, recruitment.id).await? {
+        info!("募集は既に開始済みです: message_id={}", message_id);
+        return Ok(());
     }
-    
+    ScheduledEventService::new(db.clone()).start(ctx, &recruitment).await;
+
     // リアクションから参加者一覧取得
-    let participants = match service.get_participants_from_reactions(message_id).await {
+    let participants = match service.get_participants_from_reactions(ctx, channel_id, message_id).await {
         Ok(participants) => participants,
         Err(e) => {
             error!("参加者取得エラー: {}", e);
             return Err(e);
         }
     };
-    
+
     // 開始メッセージを作成（参加者へのメンション含む）
-    let start_message = match service.create_start_message("サンプルクエスト", &participants).await {
-        Ok(message) => message,
+    let quest_name = service.get_quest_name(&recruitment).await;
+    let timezone = SettingsService::new(db.clone()).timezone(recruitment.guild_id).await;
+    let variables = RecruitmentVariables::for_recruitment(&recruitment, &quest_name, &participants, timezone);
+    let start_message = service.create_start_message(&messages, &variables).await?;
+
+    // 募集スレッド、または元の募集メッセージに返信する形でメッセージを送信
+    match service.send_start_reply(ctx, &messages, &recruitment, start_message).await {
+        Ok(_) => {
+            info!("募集開始処理が完了しました: message_id={}", message_id);
+            Ok(())
+        },
         Err(e) => {
-            error!("開始メッセージ作成エラー: {}", e);
-            return Err(e);
+            error!("開始返信送信エラー: {}", e);
+            Err(e)
         }
+    }
+}
+
+/// 投稿済みの募集の内容を変更する
+/// 募集はメッセージのリンクか、実行したチャンネルのメッセージIDで指定する
+/// エラーは `messages`（実行したユーザーの言語）で返す
+pub(crate) async fn edit(ctx: &PoiseContext<'_>, messages: &Messages, message_link: &str, event_date: Option<DateTime<FixedOffset>>, battle_type: Option<BattleType>, capacity: Option<usize>, notes: Option<&str>) -> Result<(), String> {
+    info!("battle_recruitment::edit - 募集内容を変更します");
+
+    let db = connect(messages).await?;
+    let recruitment = managed_recruitment(ctx, messages, &db, message_link).await?;
+    if recruitment.started_at.is_some() {
+        return Err(messages.get("battle_recruitment.already_started"));
+    }
+
+    let params = EditParameter {
+        guild_id: recruitment.guild_id as u64,
+        channel_id: recruitment.channel_id as u64,
+        message_id: recruitment.message_id as u64,
+        event_date,
+        battle_type,
+        capacity,
+        notes,
     };
-    
-    // 元の募集メッセージに返信する形でメッセージを送信
-    if let Err(e) = service.send_start_reply(channel_id, message_id, &start_message).await {
-        error!("開始返信送信エラー: {}", e);
-        return Err(e);
+    match UpdateRecruitmentService::new(db).edit_recruitment(ctx.serenity_context(), messages, params).await {
+        Ok(recruitment) => {
+            info!("募集内容が正常に変更されました: id={}", recruitment.id);
+            Ok(())
+        },
+        Err(e) => {
+            error!("募集内容変更エラー: {}", e);
+            Err(e)
+        }
     }
-    
-    // 募集を開始済み状態に更新
-    match service.mark_recruitment_as_started(recruitment_id).await {
-        Ok(_) => {
-            info!("募集開始処理が完了しました: message_id={}", message_id);
+}
+
+/// 開始時刻を待たずに募集を開始し、参加者を呼び出す
+/// 予定されていた開始時刻の呼び出しは送信しなくなる
+/// エラーは `messages`（実行したユーザーの言語）で返す
+pub(crate) async fn start_now(ctx: &PoiseContext<'_>, messages: &Messages, message_link: &str) -> Result<(), String> {
+    info!("battle_recruitment::start_now - 募集をすぐに開始します");
+
+    let db = connect(messages).await?;
+    let recruitment = managed_recruitment(ctx, messages, &db, message_link).await?;
+    if recruitment.started_at.is_some() {
+        return Err(messages.get("battle_recruitment.already_started"));
+    }
+
+    start(ctx.serenity_context(), db, recruitment.guild_id as u64, recruitment.channel_id as u64, recruitment.message_id as u64).await
+}
+
+/// 募集者を参加者の1人に変更する
+/// エラーは `messages`（実行したユーザーの言語）で返す
+pub(crate) async fn transfer(ctx: &PoiseContext<'_>, messages: &Messages, message_link: &str, new_owner: UserId) -> Result<(), String> {
+    info!("battle_recruitment::transfer - 募集者を変更します");
+
+    let db = connect(messages).await?;
+    let recruitment = managed_recruitment(ctx, messages, &db, message_link).await?;
+
+    match UpdateRecruitmentService::new(db).transfer_recruitment(ctx.serenity_context(), messages, &recruitment, new_owner).await {
+        Ok(recruitment) => {
+            info!("募集者が正常に変更されました: id={}", recruitment.id);
             Ok(())
         },
         Err(e) => {
-            error!("募集開始済み状態更新エラー: {}", e);
+            error!("募集者変更エラー: {}", e);
             Err(e)
         }
     }
-}
\ No newline at end of file
+}
+
+/// リンクで指定された募集を取得する
+/// 操作できるのは募集者と、他人の募集のキャンセル権限を持つメンバーのみ（キャンセルした募集は操作できない）
+async fn managed_recruitment(ctx: &PoiseContext<'_>, messages: &Messages, db: &Database, message_link: &str) -> Result<BattleRecruitment, String> {
+    let guild_id = ctx.guild_id()
+        .ok_or_else(|| messages.get("errors.guild_only"))?;
+    let (channel_id, message_id) = parse_message_link(message_link, ctx.channel_id())
+        .ok_or_else(|| messages.get("battle_recruitment.invalid_message"))?;
+
+    let recruitment = match db.battle_recruitment.get_by_message(guild_id.get() as i64, channel_id.get() as i64, message_id.get() as i64).await {
+        Ok(Some(recruitment)) => recruitment,
+        Ok(None) => return Err(messages.get("battle_recruitment.not_found")),
+        Err(e) => {
+            error!("DB募集情報取得エラー: {:?}", e);
+            return Err(messages.get("battle_recruitment.database_error"));
+        }
+    };
+
+    let member = ctx.author_member().await
+        .ok_or_else(|| messages.get("errors.guild_only"))?;
+    if let Err(reason) = can_manage_recruitment(ctx, &member, &recruitment).await {
+        warn!("募集の操作を拒否しました: {}", reason);
+        return Err(messages.get("battle_recruitment.not_creator"));
+    }
+    if recruitment.cancelled_at.is_some() {
+        return Err(messages.get("battle_recruitment.already_cancelled"));
+    }
+    Ok(recruitment)
+}
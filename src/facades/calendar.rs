@@ -0,0 +1,67 @@
+use crate::services::calendar::CalendarService;
+use crate::services::calendar::calendar_service::CALENDAR_DAYS;
+use crate::services::calendar::feed::feed_path;
+use crate::services::environment::init::current_config;
+use crate::services::message::Messages;
+use crate::repository::Database;
+use crate::types::{CalendarScope, PoiseContext, PoiseError};
+use poise::serenity_prelude::CreateAttachment;
+use std::sync::Arc;
+use chrono::Utc;
+
+/// 開始前の募集を .ics ファイルで返す
+/// 配信URLを提供している場合は、カレンダーアプリに登録する配信URLも案内する
+pub(crate) async fn export(ctx: &PoiseContext<'_>, scope: CalendarScope, reset_feed: bool) -> Result<(), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some(guild_id) = ctx.guild_id() else {
+        return reply(ctx, messages.get("errors.guild_only")).await;
+    };
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            tracing::error!("データベース接続エラー: {}", e);
+            return reply(ctx, messages.get("errors.database_connection")).await;
+        }
+    };
+
+    let service = CalendarService::new(db);
+    let guild_id = guild_id.get() as i64;
+    let user_id = ctx.author().id.get() as i64;
+    let (calendar, count) = match service.render(&messages, guild_id, user_id, scope, Utc::now()).await {
+        Ok(rendered) => rendered,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let mut content = messages.get_with_params("calendar.exported", &[
+        ("count", &count.to_string()),
+        ("days", &CALENDAR_DAYS.to_string()),
+    ]);
+    if let Some(base_url) = current_config().and_then(|config| config.calendar_feed_url.clone()) {
+        match service.feed_token(&messages, guild_id, user_id, scope, reset_feed).await {
+            Ok(token) => {
+                let url = format!("{}{}", base_url, feed_path(&token));
+                content.push('\n');
+                content.push_str(&messages.get_with_params("calendar.feed", &[("url", &url)]));
+            },
+            Err(e) => return reply(ctx, e).await,
+        }
+    }
+
+    let filename = format!("recruitments-{}.ics", scope.key());
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .attachment(CreateAttachment::bytes(calendar.into_bytes(), filename))
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Serves the calendar feed URLs handed out by `/calendar`.
+//!
+//! A minimal HTTP/1.1 server on `CALENDAR_FEED_ADDR` answers `GET /calendar/<token>.ics`
+//! with the current calendar of the member and scope the token was issued for, as long
+//! as the member is still in the server (checked through the Discord API). Every
+//! request is answered on its own connection, which is then closed; calendar apps poll
+//! the feed only every few hours, so nothing more is needed. At most
+//! [`MAX_CONNECTIONS`] requests are handled at once; further connections wait to be
+//! accepted. Put it behind a reverse
+//! proxy for HTTPS, and point `CALENDAR_FEED_URL` at the public address.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use poise::serenity_prelude::Http;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Semaphore;
+use tracing::{error, info, warn};
+
+use crate::repository::Database;
+use crate::services::calendar::CalendarService;
+use crate::services::calendar::feed::parse_request_line;
+
+/// リクエストの読み込みを待つ時間
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 同時に処理するリクエストの上限
+const MAX_CONNECTIONS: usize = 32;
+
+/// カレンダーの配信URLを提供するHTTPサーバーをバックグラウンドで開始する
+/// `http` はメンバーがサーバーに残っているかの確認に使う
+#[allow(dead_code)] // main.rs からのみ起動する（ライブラリでは使われない）
+pub(crate) fn spawn(addr: SocketAddr, http: Arc<Http>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let listener = match TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!("Calendar feed server could not listen on {}: {}", addr, e);
+                return;
+            }
+        };
+        info!("Calendar feed server listening on {}", addr);
+
+        let connections = Arc::new(Semaphore::new(MAX_CONNECTIONS));
+        let mut db: Option<Arc<Database>> = None;
+        loop {
+            // 上限に達している間は新しい接続を受け付けない（許可は処理が終わるまで保持する）
+            let Ok(permit) = connections.clone().acquire_owned().await else {
+                return;
+            };
+            let (stream, peer) = match listener.accept().await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    warn!("Calendar feed connection failed: {}", e);
+                    continue;
+                }
+            };
+
+            // 接続できなかった場合は次のリクエストで接続し直す
+            let database = match &db {
+                Some(database) => database.clone(),
+                None => match Database::new().await {
+                    Ok(database) => db.insert(Arc::new(database)).clone(),
+                    Err(e) => {
+                        error!("Calendar feed database connection error: {}", e);
+                        let _ = respond(stream, "503 Service Unavailable", "text/plain", "Service Unavailable").await;
+                        continue;
+                    }
+                },
+            };
+
+            let http = http.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle(stream, database, &http).await {
+                    warn!("Calendar feed request from {} failed: {}", peer, e);
+                }
+                drop(permit);
+            });
+        }
+    })
+}
+
+async fn handle(mut stream: TcpStream, db: Arc<Database>, http: &Http) -> Result<(), String> {
+    // 先頭行だけを読めばよい
+    let mut request_line = String::new();
+    let mut reader = BufReader::new(&mut stream);
+    match tokio::time::timeout(READ_TIMEOUT, reader.read_line(&mut request_line)).await {
+        Ok(Ok(_)) => {},
+        Ok(Err(e)) => return Err(format!("Failed to read the request: {}", e)),
+        Err(_) => return Err("Timed out reading the request".to_string()),
+    }
+
+    let Some(token) = parse_request_line(&request_line) else {
+        return respond(stream, "404 Not Found", "text/plain", "Not Found").await;
+    };
+    match CalendarService::new(db).render_feed(http, token, Utc::now()).await {
+        Ok(Some(calendar)) => respond(stream, "200 OK", "text/calendar; charset=utf-8", &calendar).await,
+        Ok(None) => respond(stream, "404 Not Found", "text/plain", "Not Found").await,
+        Err(e) => {
+            error!("Calendar feed error: {}", e);
+            respond(stream, "500 Internal Server Error", "text/plain", "Internal Server Error").await
+        }
+    }
+}
+
+async fn respond(mut stream: TcpStream, status: &str, content_type: &str, body: &str) -> Result<(), String> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{}",
+        status, content_type, body.len(), body,
+    );
+    stream.write_all(response.as_bytes()).await
+        .map_err(|e| format!("Failed to send the response: {}", e))?;
+    stream.shutdown().await
+        .map_err(|e| format!("Failed to close the connection: {}", e))
+}
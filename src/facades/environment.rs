@@ -1,20 +1,15 @@
-use crate::services::permission::has_bot_control_permission;
-use crate::services::environment::service::load_environment_from_database;
+use crate::services::environment::service::{load_environment_from_database, Actor, EnvironmentService};
+use crate::services::message::message_text_cache;
 use crate::repository::Database;
 use crate::types::{PoiseContext, PoiseError};
+use poise::serenity_prelude::CreateEmbed;
 use std::sync::Arc;
+use super::{truncate_lines, EMBED_DESCRIPTION_LIMIT};
 
+/// 環境変数をデータベースから読み込み直す
+/// 全サーバーの設定を読み込み直すため、Botのオーナーのみ（コマンドの check で確認済み）
 pub(crate) async fn load(ctx: &PoiseContext<'_>) -> Result<(), PoiseError>{
 
-    // コマンド実行者の情報取得
-    let member = ctx.author_member().await.unwrap();
-
-    // 権限チェック
-    let has_permission_result = has_bot_control_permission(ctx, &member).await;
-    if let Err(permission_error) = has_permission_result {
-        return Err(permission_error.into());
-    }
-
     // データベース接続
     let db = match Database::new().await {
         Ok(database) => Arc::new(database),
@@ -28,8 +23,11 @@ pub(crate) async fn load(ctx: &PoiseContext<'_>) -> Result<(), PoiseError>{
         }
     };
 
+    // サーバー独自のメッセージも次回の参照時に読み込み直す（設定値の検証結果にかかわらず）
+    message_text_cache::invalidate();
+
     // 環境変数読み込み処理（データベースから読み込み）
-    match load_environment_from_database(db).await.map_err(|e| format!("環境変数読み込みエラー: {}", e)) {
+    match load_environment_from_database(db).await.map_err(|e| format!("環境変数読み込みエラー（以前の設定を維持しています）: {}", e)) {
         Ok(_) => {
             // 完了したことをメッセージで表示
             ctx.send(poise::CreateReply::default()
@@ -48,3 +46,118 @@ pub(crate) async fn load(ctx: &PoiseContext<'_>) -> Result<(), PoiseError>{
     }
 }
 
+
+/// `environments` テーブルの値の一覧を表示する
+pub(crate) async fn list(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let (service, _) = prepare(ctx).await?;
+
+    let environments = match service.list().await {
+        Ok(environments) => environments,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let description = if environments.is_empty() {
+        "登録されている値はありません。".to_string()
+    } else {
+        let lines: Vec<String> = environments.iter()
+            .map(|environment| format!("`{}` = `{}`", environment.key, environment.value))
+            .collect();
+        truncate_lines(&lines, EMBED_DESCRIPTION_LIMIT)
+    };
+
+    let embed = CreateEmbed::new()
+        .title("設定値一覧")
+        .description(description)
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// 値と最近の変更履歴を表示する
+pub(crate) async fn get(ctx: &PoiseContext<'_>, key: &str) -> Result<(), PoiseError> {
+    let (service, _) = prepare(ctx).await?;
+
+    let (environment, history) = match service.get(key).await {
+        Ok(Some(found)) => found,
+        Ok(None) => return reply(ctx, format!("`{}` は登録されていません。", key)).await,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let history = if history.is_empty() {
+        "なし".to_string()
+    } else {
+        history.iter()
+            .map(|log| format!("<t:{}:f> <@{}> {}", log.created_at.timestamp(), log.user_id, log.action))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let embed = CreateEmbed::new()
+        .title(&environment.key)
+        .field("値", format!("`{}`", environment.value), false)
+        .field("秘密", if environment.is_secret { "はい" } else { "いいえ" }, true)
+        .field("更新日時", format!("<t:{}:f>", environment.updated_at.timestamp()), true)
+        .field("変更履歴", history, false)
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// 値を登録・変更する
+pub(crate) async fn set(ctx: &PoiseContext<'_>, key: &str, value: &str, secret: Option<bool>) -> Result<(), PoiseError> {
+    let (service, actor) = prepare(ctx).await?;
+
+    let content = match service.set(key, value, secret, actor).await {
+        Ok(environment) => format!("`{}` を `{}` に設定しました。", environment.key, environment.value),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 値を削除する
+pub(crate) async fn delete(ctx: &PoiseContext<'_>, key: &str) -> Result<(), PoiseError> {
+    let (service, actor) = prepare(ctx).await?;
+
+    let content = match service.delete(key, actor).await {
+        Ok(true) => format!("`{}` を削除しました。", key),
+        Ok(false) => format!("`{}` は登録されていません。", key),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// サービスと操作者を用意する
+/// 権限はコマンドの check（Bot管理）で確認済み
+async fn prepare(ctx: &PoiseContext<'_>) -> Result<(EnvironmentService, Actor), PoiseError> {
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            let error_msg = format!("データベース接続エラー: {}", e);
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    let actor = Actor {
+        guild_id: ctx.guild_id().map(|guild_id| guild_id.get() as i64),
+        user_id: ctx.author().id.get() as i64,
+    };
+    Ok((EnvironmentService::new(db), actor))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use crate::models::guild_war_schedule::GuildWarSchedule;
+use crate::services::guild_war::{round_name, GuildWarService};
+use crate::services::guild_war::guild_war_service::ImportTarget;
+use crate::services::message::Messages;
+use crate::services::settings::SettingsService;
+use crate::repository::Database;
+use crate::types::{PoiseContext, PoiseError};
+use crate::utils::date_parser;
+use poise::serenity_prelude::{Attachment, ChannelId, CreateEmbed, RoleId};
+use std::sync::Arc;
+use chrono::{DateTime, FixedOffset, Utc};
+
+/// 古戦場の日程をJSONファイルから取り込み、サーバーの日程を置き換える
+/// お知らせの投稿先を省略した場合はサーバー設定の募集チャンネル、なければ実行したチャンネル
+pub(crate) async fn import(ctx: &PoiseContext<'_>, file: &Attachment, channel_id: Option<ChannelId>, role_id: Option<RoleId>) -> Result<(), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some((service, db, guild_id)) = prepare(ctx, &messages).await? else {
+        return Ok(());
+    };
+
+    let content = match file.download().await {
+        Ok(bytes) => match String::from_utf8(bytes) {
+            Ok(content) => content,
+            Err(_) => return reply(ctx, messages.get("guild_war.not_utf8")).await,
+        },
+        Err(e) => return reply(ctx, messages.get_with_params("guild_war.download_failed", &[("error", &e.to_string())])).await,
+    };
+
+    let channel_id = match channel_id {
+        Some(channel_id) => channel_id.get() as i64,
+        None => match SettingsService::new(db).get(guild_id).await {
+            Ok(settings) => settings.recruitment_channel_id.unwrap_or(ctx.channel_id().get() as i64),
+            Err(e) => return reply(ctx, e).await,
+        },
+    };
+    let target = ImportTarget {
+        guild_id,
+        channel_id,
+        role_id: role_id.map(|role_id| role_id.get() as i64),
+        created_by: ctx.author().id.get() as i64,
+    };
+
+    let content = match service.import(&messages, target, &content).await {
+        Ok(rounds) => messages.get_with_params("guild_war.imported", &[
+            ("count", &rounds.len().to_string()),
+            ("channel", &format!("<#{}>", channel_id)),
+        ]),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// サーバーの古戦場の日程を表示する
+pub(crate) async fn show(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some((service, _, guild_id)) = prepare(ctx, &messages).await? else {
+        return Ok(());
+    };
+
+    let rounds = match service.list(&messages, guild_id).await {
+        Ok(rounds) => rounds,
+        Err(e) => return reply(ctx, e).await,
+    };
+    let Some(first) = rounds.first() else {
+        return reply(ctx, messages.get("guild_war.empty")).await;
+    };
+
+    let now = Utc::now();
+    let mut lines: Vec<String> = rounds.iter().map(|round| line(&messages, round, now)).collect();
+    lines.push(String::new());
+    lines.push(match first.role_id {
+        Some(role_id) => messages.get_with_params("guild_war.notices_with_role", &[
+            ("channel", &format!("<#{}>", first.channel_id)),
+            ("role", &format!("<@&{}>", role_id)),
+        ]),
+        None => messages.get_with_params("guild_war.notices", &[("channel", &format!("<#{}>", first.channel_id))]),
+    });
+
+    let embed = CreateEmbed::new()
+        .title(messages.get("guild_war.title"))
+        .description(lines.join("\n"))
+        .color(0x0099ff);
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// サーバーの古戦場の日程を削除する
+pub(crate) async fn clear(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some((service, _, guild_id)) = prepare(ctx, &messages).await? else {
+        return Ok(());
+    };
+
+    let content = match service.clear(&messages, guild_id).await {
+        Ok(0) => messages.get("guild_war.empty"),
+        Ok(_) => messages.get("guild_war.cleared"),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 募集の日時を解釈する（サーバーのタイムゾーン）
+/// 「本戦1日目 22時」のように古戦場の区切りで始まる場合はサーバーの日程から求め、それ以外は通常の日時として解釈する
+pub(crate) async fn parse_event_date(ctx: &PoiseContext<'_>, messages: &Messages, event_date: &str) -> Result<DateTime<FixedOffset>, String> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Err(messages.get("errors.guild_only"));
+    };
+    let db = Arc::new(Database::new().await
+        .map_err(|e| {
+            tracing::error!("データベース接続エラー: {}", e);
+            messages.get("errors.database_connection")
+        })?);
+    let timezone = SettingsService::new(db.clone()).get(guild_id.get() as i64).await
+        .map_err(|_| messages.get("battle_recruitment.database_error"))?
+        .timezone();
+
+    let date = match date_parser::parse_guild_war_date(event_date) {
+        Some(date) => date?,
+        None => return date_parser::parse_event_date(event_date, &timezone).await,
+    };
+    GuildWarService::new(db).event_date(messages, guild_id.get() as i64, date).await
+        .map(|date| date.with_timezone(&timezone))
+}
+
+/// 区切り1件の表示（区切り名、期間、開催中か）
+fn line(messages: &Messages, round: &GuildWarSchedule, now: DateTime<Utc>) -> String {
+    let name = round.round().map(|r| round_name(messages, r)).unwrap_or_else(|| round.round.clone());
+    let mut line = messages.get_with_params("guild_war.round_line", &[
+        ("round", &name),
+        ("start", &round.starts_at.timestamp().to_string()),
+        ("end", &round.ends_at.timestamp().to_string()),
+    ]);
+    if round.starts_at <= now && now < round.ends_at {
+        line.push_str(&messages.get("guild_war.in_progress"));
+    }
+    line
+}
+
+/// サービスを生成する（サーバー外やデータベースに接続できない場合は返信して None）
+async fn prepare(ctx: &PoiseContext<'_>, messages: &Messages) -> Result<Option<(GuildWarService, Arc<Database>, i64)>, PoiseError> {
+    let Some(guild_id) = ctx.guild_id() else {
+        reply(ctx, messages.get("errors.guild_only")).await?;
+        return Ok(None);
+    };
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            tracing::error!("データベース接続エラー: {}", e);
+            reply(ctx, messages.get("errors.database_connection")).await?;
+            return Ok(None);
+        }
+    };
+    Ok(Some((GuildWarService::new(db.clone()), db, guild_id.get() as i64)))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use crate::services::message::message_text_service::{MessageTextDraft, MessageTextEntry, MessageTextService};
+use crate::services::message::Language;
+use crate::services::battle_recruitment::template::VARIABLES;
+use crate::repository::Database;
+use crate::types::{PoiseContext, PoiseError};
+use poise::serenity_prelude::{ButtonStyle, ComponentInteractionCollector, CreateActionRow, CreateButton, CreateEmbed};
+use std::sync::Arc;
+use std::time::Duration;
+use super::{truncate_lines, EMBED_DESCRIPTION_LIMIT};
+
+/// 埋め込みのフィールドの上限（Discordの制限は1024文字）
+const EMBED_FIELD_LIMIT: usize = 1000;
+
+/// 保存の確認を待つ時間
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// 変更できるテンプレートと、サーバー独自に設定したメッセージの一覧を表示する
+pub(crate) async fn list(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx).await?;
+
+    let entries = match service.list(guild_id).await {
+        Ok(entries) => entries,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let lines: Vec<String> = entries.iter()
+        .map(|entry| {
+            let state = if entry.guild_text.is_some() { "✅ 独自設定" } else { "同梱" };
+            format!("{} `{}`{}", state, entry.key, legacy_label(entry))
+        })
+        .collect();
+
+    let embed = CreateEmbed::new()
+        .title("メッセージ一覧")
+        .description(truncate_lines(&lines, EMBED_DESCRIPTION_LIMIT))
+        .footer(poise::serenity_prelude::CreateEmbedFooter::new("同梱のメッセージのキーも /message_text set で変更できます"))
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// 現在のメッセージを表示する
+pub(crate) async fn show(ctx: &PoiseContext<'_>, message_id: &str) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx).await?;
+
+    let entry = match service.get(guild_id, message_id).await {
+        Ok(entry) => entry,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let state = match &entry.guild_text {
+        Some(text) => format!("独自設定（<t:{}:f> 更新）", text.updated_at.timestamp()),
+        None => "同梱のメッセージ".to_string(),
+    };
+
+    let mut embed = CreateEmbed::new()
+        .title(format!("{}{}", entry.key, legacy_label(&entry)))
+        .field("状態", state, false)
+        .field("日本語", code_block(&entry.current(Language::Japanese).unwrap_or_default()), false)
+        .field("英語", code_block(&entry.current(Language::English).unwrap_or_default()), false)
+        .color(0x0099ff);
+    if entry.is_template() {
+        embed = embed.field("使用できる変数", variables(), false);
+    }
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// プレビューを表示し、確認後に保存する
+pub(crate) async fn set(ctx: &PoiseContext<'_>, message_id: &str, message_jp: Option<&str>, message_en: Option<&str>) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx).await?;
+
+    let draft = match service.draft(guild_id, message_id, message_jp, message_en).await {
+        Ok(draft) => draft,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let save_id = format!("{}:save", ctx.id());
+    let cancel_id = format!("{}:cancel", ctx.id());
+    let buttons = CreateActionRow::Buttons(vec![
+        CreateButton::new(&save_id).label("保存").style(ButtonStyle::Success),
+        CreateButton::new(&cancel_id).label("キャンセル").style(ButtonStyle::Secondary),
+    ]);
+
+    let handle = ctx.send(poise::CreateReply::default()
+        .content(format!("`{}` のプレビューです（変数は見本の値）。保存しますか？", draft.key))
+        .embed(preview_embed(&draft))
+        .components(vec![buttons])
+        .ephemeral(true)
+    ).await?;
+    let message = handle.message().await?;
+
+    let interaction = ComponentInteractionCollector::new(ctx.serenity_context())
+        .author_id(ctx.author().id)
+        .message_id(message.id)
+        .custom_ids(vec![save_id.clone(), cancel_id])
+        .timeout(CONFIRM_TIMEOUT)
+        .await;
+
+    let content = match interaction {
+        Some(interaction) => {
+            interaction.defer(ctx.serenity_context()).await?;
+            if interaction.data.custom_id == save_id {
+                match service.save(guild_id, &draft).await {
+                    Ok(_) => format!("`{}` を保存しました。", draft.key),
+                    Err(e) => e,
+                }
+            } else {
+                "保存せずに終了しました。".to_string()
+            }
+        },
+        None => "時間切れのため保存しませんでした。".to_string(),
+    };
+
+    handle.edit(*ctx, poise::CreateReply::default()
+        .content(content)
+        .embed(preview_embed(&draft))
+        .components(Vec::new())
+    ).await?;
+    Ok(())
+}
+
+/// サーバー独自の設定を削除し、同梱のメッセージに戻す
+pub(crate) async fn reset(ctx: &PoiseContext<'_>, message_id: &str) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx).await?;
+
+    let content = match service.reset(guild_id, message_id).await {
+        Ok(true) => format!("`{}` を同梱のメッセージに戻しました。", message_id),
+        Ok(false) => format!("`{}` は独自に設定されていません。", message_id),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// サービスを生成する
+/// 権限はコマンドの check（Bot管理）で確認済み
+async fn prepare(ctx: &PoiseContext<'_>) -> Result<(MessageTextService, i64), PoiseError> {
+    let guild_id = ctx.guild_id()
+        .ok_or_else(|| PoiseError::from("このコマンドはサーバー内でのみ使用できます。"))?;
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            let error_msg = format!("データベース接続エラー: {}", e);
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    Ok((MessageTextService::new(db), guild_id.get() as i64))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+fn preview_embed(draft: &MessageTextDraft) -> CreateEmbed {
+    let english = match draft.message_en {
+        Some(_) => "英語",
+        None => "英語（未設定のため同梱のメッセージ）",
+    };
+    CreateEmbed::new()
+        .title(&draft.key)
+        .field("日本語", truncate(&draft.preview(Language::Japanese)), false)
+        .field(english, truncate(&draft.preview(Language::English)), false)
+        .color(0xffaa00)
+}
+
+fn legacy_label(entry: &MessageTextEntry) -> String {
+    entry.legacy_id
+        .map(|legacy_id| format!("（{}）", legacy_id))
+        .unwrap_or_default()
+}
+
+fn variables() -> String {
+    VARIABLES.iter()
+        .map(|name| format!("`${{{}}}`", name))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn code_block(message: &str) -> String {
+    format!("```\n{}\n```", truncate(message))
+}
+
+fn truncate(message: &str) -> String {
+    if message.is_empty() {
+        return "（なし）".to_string();
+    }
+    if message.chars().count() <= EMBED_FIELD_LIMIT {
+        return message.to_string();
+    }
+    let truncated: String = message.chars().take(EMBED_FIELD_LIMIT).collect();
+    format!("{}…", truncated)
+}
@@ -2,3 +2,38 @@ pub(crate) mod battle_recruitment;
 pub(crate) mod settings;
 pub(crate) mod scheduler;
 pub(crate) mod environment;
+pub(crate) mod quest;
+pub(crate) mod permission;
+pub(crate) mod message_text;
+pub(crate) mod recruitment_rule;
+pub(crate) mod recruitment_template;
+pub(crate) mod recruitment_list;
+pub(crate) mod my_recruitments;
+pub(crate) mod raid_id;
+pub(crate) mod calendar;
+pub(crate) mod calendar_feed;
+pub(crate) mod guild_war;
+
+/// 埋め込みの説明文の上限（Discordの制限は4096文字）
+const EMBED_DESCRIPTION_LIMIT: usize = 4000;
+
+/// メッセージ本文のエラー一覧の上限（Discordの制限は2000文字）
+const MESSAGE_CONTENT_LIMIT: usize = 1800;
+
+/// 上限文字数に収まるように行を連結し、溢れた件数を末尾に記載する
+fn truncate_lines(lines: &[String], limit: usize) -> String {
+    let mut result = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let remaining = lines.len() - index;
+        let suffix = format!("\n…ほか{}件", remaining);
+        if result.chars().count() + line.chars().count() + 1 + suffix.chars().count() > limit {
+            result.push_str(&suffix);
+            break;
+        }
+        if !result.is_empty() {
+            result.push('\n');
+        }
+        result.push_str(line);
+    }
+    result
+}
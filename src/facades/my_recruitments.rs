@@ -0,0 +1,84 @@
+use crate::services::battle_recruitment::recruitment::JoinedRecruitmentService;
+use crate::services::battle_recruitment::recruitment::joined::JoinedRecruitment;
+use crate::services::message::Messages;
+use crate::repository::Database;
+use crate::types::{PoiseContext, PoiseError};
+use poise::serenity_prelude::CreateEmbed;
+use std::sync::Arc;
+use super::truncate_lines;
+
+/// 埋め込みのフィールドの上限（Discordの制限は1024文字）
+const EMBED_FIELD_LIMIT: usize = 1000;
+
+/// 実行したユーザーが作成または参加した募集を、開始前と開始済みに分けて表示する
+pub(crate) async fn list(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some(guild_id) = ctx.guild_id() else {
+        return reply(ctx, messages.get("errors.guild_only")).await;
+    };
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            tracing::error!("データベース接続エラー: {}", e);
+            return reply(ctx, messages.get("errors.database_connection")).await;
+        }
+    };
+
+    let service = JoinedRecruitmentService::new(db);
+    let joined = match service.list(&messages, guild_id.get() as i64, ctx.author().id.get() as i64).await {
+        Ok(joined) => joined,
+        Err(e) => return reply(ctx, e).await,
+    };
+    if joined.is_empty() {
+        return reply(ctx, messages.get("my_recruitments.empty")).await;
+    }
+
+    let field = |items: &[JoinedRecruitment]| {
+        if items.is_empty() {
+            return messages.get("my_recruitments.none");
+        }
+        let lines: Vec<String> = items.iter().map(|item| line(&messages, item)).collect();
+        truncate_lines(&lines, EMBED_FIELD_LIMIT)
+    };
+    let embed = CreateEmbed::new()
+        .title(messages.get("my_recruitments.title"))
+        .field(messages.get("my_recruitments.upcoming"), field(&joined.upcoming), false)
+        .field(messages.get("my_recruitments.past"), field(&joined.past), false)
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// 募集1件の表示（クエスト名、開催日時、状態、募集者か、参加した属性、募集メッセージへのリンク）
+fn line(messages: &Messages, item: &JoinedRecruitment) -> String {
+    let recruitment = &item.recruitment;
+    let mut details = vec![messages.get(recruitment.status().message_key())];
+    if item.created {
+        details.push(messages.get("battle_recruitment.creator"));
+    }
+    if !item.elements.is_empty() {
+        let elements: Vec<&str> = item.elements.iter().map(|element| element.name()).collect();
+        details.push(elements.join("・"));
+    }
+    format!(
+        "**{}** <t:{}:f>\n　{} [{}](https://discord.com/channels/{}/{}/{})",
+        item.quest_name, recruitment.expiry_date.timestamp(),
+        details.join(" / "),
+        messages.get("recruitment_list.jump"),
+        recruitment.guild_id, recruitment.channel_id, recruitment.message_id,
+    )
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::services::permission::{has_bot_control_permission, PermissionService, PermissionSubject};
+use crate::repository::Database;
+use crate::types::{Capability, PoiseContext, PoiseError};
+use poise::serenity_prelude::{CreateEmbed, Role, User};
+use std::sync::Arc;
+
+/// 権限を付与する
+pub(crate) async fn grant(ctx: &PoiseContext<'_>, capability: Capability, role: Option<Role>, user: Option<User>) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx).await?;
+
+    let Some(subject) = subject(ctx, role, user).await? else {
+        return Ok(());
+    };
+
+    let content = match service.grant(guild_id, capability, subject).await {
+        Ok(_) => format!("{} に「{}」の権限を付与しました。", subject.mention(), capability.name()),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 権限を取り消す
+pub(crate) async fn revoke(ctx: &PoiseContext<'_>, capability: Capability, role: Option<Role>, user: Option<User>) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx).await?;
+
+    let Some(subject) = subject(ctx, role, user).await? else {
+        return Ok(());
+    };
+
+    let content = match service.revoke(guild_id, capability, subject).await {
+        Ok(_) => format!("{} から「{}」の権限を取り消しました。", subject.mention(), capability.name()),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 付与されている権限の一覧を表示する
+pub(crate) async fn list(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx).await?;
+
+    let permissions = match service.list(guild_id).await {
+        Ok(permissions) => permissions,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let fields = Capability::ALL.iter().map(|capability| {
+        let subjects: Vec<String> = permissions.iter()
+            .filter(|(granted, _)| granted == capability)
+            .map(|(_, subject)| subject.mention())
+            .collect();
+        let value = if subjects.is_empty() {
+            "なし".to_string()
+        } else {
+            subjects.join(" ")
+        };
+        (capability.name(), value, false)
+    });
+
+    let embed = CreateEmbed::new()
+        .title("権限一覧")
+        .description("サーバー管理者と管理ロールを持つメンバーは、すべての権限を持ちます。")
+        .fields(fields)
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// 権限チェックを行い、サービスを生成する
+async fn prepare(ctx: &PoiseContext<'_>) -> Result<(PermissionService, i64), PoiseError> {
+    // コマンド実行者の情報取得
+    let member = ctx.author_member().await
+        .ok_or_else(|| PoiseError::from("このコマンドはサーバー内でのみ使用できます。"))?;
+
+    // 権限の付与は管理者のみ
+    if let Err(permission_error) = has_bot_control_permission(ctx, &member).await {
+        return Err(permission_error.into());
+    }
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            let error_msg = format!("データベース接続エラー: {}", e);
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    Ok((PermissionService::new(db), member.guild_id.get() as i64))
+}
+
+/// ロールとユーザーのどちらか一方だけが指定されていることを確認する
+async fn subject(ctx: &PoiseContext<'_>, role: Option<Role>, user: Option<User>) -> Result<Option<PermissionSubject>, PoiseError> {
+    match (role, user) {
+        (Some(role), None) => Ok(Some(PermissionSubject::Role(role.id.get()))),
+        (None, Some(user)) => Ok(Some(PermissionSubject::User(user.id.get()))),
+        _ => {
+            reply(ctx, "ロールとユーザーのどちらか一方を指定してください。".to_string()).await?;
+            Ok(None)
+        }
+    }
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
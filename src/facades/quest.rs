@@ -0,0 +1,215 @@
+use crate::services::quest::QuestService;
+use crate::services::quest::catalog::{self, CatalogFormat};
+use crate::repository::Database;
+use super::{truncate_lines, EMBED_DESCRIPTION_LIMIT, MESSAGE_CONTENT_LIMIT};
+use crate::types::{BattleType, PoiseContext, PoiseError};
+use poise::serenity_prelude::{Attachment, CreateAttachment, CreateEmbed};
+use std::sync::Arc;
+
+/// クエストを追加する
+pub(crate) async fn add(ctx: &PoiseContext<'_>, target_id: i32, quest_name: &str, default_battle_type: i32) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let content = match service.add_quest(guild_scope(ctx), target_id, quest_name, default_battle_type).await {
+        Ok(quest) => format!(
+            "クエストを追加しました: `{}` {} [{}]",
+            quest.target_id, quest.quest_name, battle_type_name(quest.default_battle_type)
+        ),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// クエストを編集する
+pub(crate) async fn edit(ctx: &PoiseContext<'_>, target_id: i32, quest_name: Option<&str>, default_battle_type: Option<i32>) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let content = match service.edit_quest(guild_scope(ctx), target_id, quest_name, default_battle_type).await {
+        Ok(quest) => format!(
+            "クエストを更新しました: `{}` {} [{}]",
+            quest.target_id, quest.quest_name, battle_type_name(quest.default_battle_type)
+        ),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// クエストを削除する
+pub(crate) async fn remove(ctx: &PoiseContext<'_>, target_id: i32) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let content = match service.remove_quest(guild_scope(ctx), target_id).await {
+        Ok(_) => format!("クエスト `{}` と別名を削除しました。", target_id),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// クエスト一覧を表示する
+pub(crate) async fn list(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let quests = match service.list_quests(guild_scope(ctx)).await {
+        Ok(quests) => quests,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let lines: Vec<String> = quests.iter().map(|(quest, aliases)| {
+        let alias_text = if aliases.is_empty() {
+            "なし".to_string()
+        } else {
+            aliases.iter().map(|a| format!("{}{}", a.alias, scope_mark(a.guild_id))).collect::<Vec<_>>().join(", ")
+        };
+        format!(
+            "`{}` {}{} [{}] 別名: {}",
+            quest.target_id, quest.quest_name, scope_mark(quest.guild_id), battle_type_name(quest.default_battle_type), alias_text
+        )
+    }).collect();
+
+    send_list(ctx, "クエスト一覧（＊は全サーバー共通）", lines).await
+}
+
+/// クエストマスタをファイルから取り込む
+pub(crate) async fn import(ctx: &PoiseContext<'_>, file: &Attachment) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let Some(format) = CatalogFormat::from_filename(&file.filename) else {
+        return reply(ctx, "ファイルの拡張子は .json または .csv を指定してください。".to_string()).await;
+    };
+
+    let content = match file.download().await {
+        Ok(bytes) => match String::from_utf8(bytes) {
+            Ok(content) => content,
+            Err(_) => return reply(ctx, "ファイルはUTF-8で保存してください。".to_string()).await,
+        },
+        Err(e) => return reply(ctx, format!("ファイルの取得に失敗しました: {}", e)).await,
+    };
+
+    let records = match catalog::deserialize(&content, format) {
+        Ok(records) => records,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let content = match service.import_catalog(guild_scope(ctx), &records).await {
+        Ok(summary) => format!("クエストマスタを取り込みました: {}", summary),
+        Err(e) => {
+            let errors: Vec<String> = e.lines().map(|line| line.to_string()).collect();
+            format!("取り込みを中止しました:\n{}", truncate_lines(&errors, MESSAGE_CONTENT_LIMIT))
+        },
+    };
+    reply(ctx, content).await
+}
+
+/// クエストマスタをファイルに書き出す
+pub(crate) async fn export(ctx: &PoiseContext<'_>, format: CatalogFormat) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let content = match service.export_catalog(guild_scope(ctx)).await.and_then(|records| catalog::serialize(&records, format)) {
+        Ok(content) => content,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let filename = format!("quests.{}", format.extension());
+    ctx.send(poise::CreateReply::default()
+        .content("クエストマスタを書き出しました。")
+        .attachment(CreateAttachment::bytes(content.into_bytes(), filename))
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// クエストに別名を追加する
+pub(crate) async fn alias_add(ctx: &PoiseContext<'_>, target_id: i32, alias: &str) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let content = match service.add_alias(guild_scope(ctx), target_id, alias).await {
+        Ok(quest_alias) => format!("別名「{}」を `{}` に追加しました。", quest_alias.alias, quest_alias.target_id),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 別名を削除する
+pub(crate) async fn alias_remove(ctx: &PoiseContext<'_>, alias: &str) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let content = match service.remove_alias(guild_scope(ctx), alias).await {
+        Ok(_) => format!("別名「{}」を削除しました。", alias.trim()),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 別名一覧を表示する
+pub(crate) async fn alias_list(ctx: &PoiseContext<'_>, target_id: Option<i32>) -> Result<(), PoiseError> {
+    let service = prepare(ctx).await?;
+
+    let aliases = match service.list_aliases(guild_scope(ctx), target_id).await {
+        Ok(aliases) => aliases,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let lines: Vec<String> = aliases.iter()
+        .map(|a| format!("`{}` {}{}", a.target_id, a.alias, scope_mark(a.guild_id)))
+        .collect();
+
+    send_list(ctx, "別名一覧（＊は全サーバー共通）", lines).await
+}
+
+/// サービスを生成する
+/// 権限はコマンドの check（クエスト管理）で確認済み
+async fn prepare(ctx: &PoiseContext<'_>) -> Result<QuestService, PoiseError> {
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            let error_msg = format!("データベース接続エラー: {}", e);
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    Ok(QuestService::new(db))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+async fn send_list(ctx: &PoiseContext<'_>, title: &str, lines: Vec<String>) -> Result<(), PoiseError> {
+    let description = if lines.is_empty() {
+        "登録されていません。".to_string()
+    } else {
+        truncate_lines(&lines, EMBED_DESCRIPTION_LIMIT)
+    };
+
+    let embed = CreateEmbed::new()
+        .title(title)
+        .description(description)
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// クエストマスタの編集対象（コマンドを実行したサーバー独自の登録）
+/// 全サーバー共通の登録はコマンドラインから管理する
+fn guild_scope(ctx: &PoiseContext<'_>) -> Option<i64> {
+    ctx.guild_id().map(|guild_id| guild_id.get() as i64)
+}
+
+/// 全サーバー共通の登録に付ける印
+fn scope_mark(guild_id: Option<i64>) -> &'static str {
+    if guild_id.is_none() { "＊" } else { "" }
+}
+
+fn battle_type_name(value: i32) -> &'static str {
+    BattleType::from_value(value).map(|b| b.name()).unwrap_or("不明")
+}
@@ -0,0 +1,138 @@
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::models::recruitment_raid_id::find_raid_id;
+use crate::services::battle_recruitment::recruitment::RaidIdService;
+use crate::services::message::Messages;
+use crate::repository::Database;
+use crate::types::{PoiseContext, PoiseError};
+use crate::utils::discord_helper::parse_message_link;
+use poise::serenity_prelude as serenity;
+use std::sync::{Arc, Mutex};
+use chrono::Utc;
+use tracing::{debug, error, info};
+
+lazy_static::lazy_static! {
+    static ref DATABASE: Mutex<Option<Arc<Database>>> = Mutex::new(None);
+}
+
+/// 参戦IDを募集の参加者に共有する
+/// 募集を省略した場合は、このチャンネルで実行したユーザーが作成または参加した募集のうち開催日時が最も近いもの
+pub(crate) async fn share(ctx: &PoiseContext<'_>, text: &str, message_link: Option<&str>, direct_message: bool) -> Result<(), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some(guild_id) = ctx.guild_id() else {
+        return reply(ctx, messages.get("errors.guild_only")).await;
+    };
+    let Some(raid_id) = find_raid_id(text) else {
+        return reply(ctx, messages.get("raid_id.invalid")).await;
+    };
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            error!("データベース接続エラー: {}", e);
+            return reply(ctx, messages.get("errors.database_connection")).await;
+        }
+    };
+    let service = RaidIdService::new(db.clone());
+
+    let recruitment = match message_link {
+        Some(message_link) => find_recruitment(ctx, &messages, &db, guild_id, message_link).await,
+        None => service.current_recruitment(&messages, guild_id.get() as i64, ctx.channel_id().get() as i64, ctx.author().id.get() as i64, Utc::now()).await,
+    };
+    let recruitment = match recruitment {
+        Ok(recruitment) => recruitment,
+        Err(e) => return reply(ctx, e).await,
+    };
+
+    let content = match service.share(ctx.serenity_context(), &messages, &recruitment, ctx.author().id, &raid_id, direct_message).await {
+        Ok(count) => messages.get_with_params("raid_id.shared", &[
+            ("raid_id", &raid_id),
+            ("count", &count.to_string()),
+        ]),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 募集メッセージへの返信や募集スレッドに書かれた参戦IDを、募集の参加者にメンションで共有する
+/// 参戦IDを含まないメッセージや、募集と関係のないメッセージ、参加者以外のメッセージは何もしない
+pub(crate) async fn relay(ctx: &serenity::Context, message: &serenity::Message) -> Result<(), String> {
+    if message.author.bot {
+        return Ok(());
+    }
+    let Some(guild_id) = message.guild_id else {
+        return Ok(());
+    };
+    let Some(raid_id) = find_raid_id(&message.content) else {
+        return Ok(());
+    };
+
+    // 募集メッセージ（Botの投稿）への返信か、スレッドへの投稿のみデータベースで確認する
+    let referenced = message.referenced_message.as_deref()
+        .filter(|referenced| referenced.author.id == ctx.cache.current_user().id);
+    let in_thread = ctx.cache.guild(guild_id)
+        .is_some_and(|guild| guild.threads.iter().any(|thread| thread.id == message.channel_id));
+    if referenced.is_none() && !in_thread {
+        return Ok(());
+    }
+
+    let db = database().await?;
+    let mut recruitment = None;
+    if let Some(referenced) = referenced {
+        recruitment = db.battle_recruitment.get_by_message(guild_id.get() as i64, referenced.channel_id.get() as i64, referenced.id.get() as i64).await
+            .map_err(|e| format!("Database error: {}", e))?;
+    }
+    if recruitment.is_none() && in_thread {
+        recruitment = db.battle_recruitment.get_by_thread(guild_id.get() as i64, message.channel_id.get() as i64).await
+            .map_err(|e| format!("Database error: {}", e))?;
+    }
+    let Some(recruitment) = recruitment.filter(|recruitment| recruitment.cancelled_at.is_none()) else {
+        return Ok(());
+    };
+
+    let messages = Messages::for_guild(&db, guild_id.get() as i64).await;
+    match RaidIdService::new(db).share_if_participant(ctx, &messages, &recruitment, message.author.id, &raid_id, false).await? {
+        Some(count) => info!("参戦IDを共有しました: recruitment_id={}, count={}", recruitment.id, count),
+        None => debug!("参加者ではないメンバーの参戦IDは共有しません: recruitment_id={}", recruitment.id),
+    }
+    Ok(())
+}
+
+/// 参戦IDの転送で使うデータベース接続（メッセージごとに接続しない）
+/// 接続できなかった場合は次のメッセージで接続し直す
+async fn database() -> Result<Arc<Database>, String> {
+    if let Some(db) = DATABASE.lock().ok().and_then(|db| db.clone()) {
+        return Ok(db);
+    }
+
+    let db = Database::new().await
+        .map(Arc::new)
+        .map_err(|e| format!("Database connection error: {}", e))?;
+    if let Ok(mut shared) = DATABASE.lock() {
+        return Ok(shared.get_or_insert(db).clone());
+    }
+    Ok(db)
+}
+
+/// リンクで指定された募集
+async fn find_recruitment(ctx: &PoiseContext<'_>, messages: &Messages, db: &Database, guild_id: serenity::GuildId, message_link: &str) -> Result<BattleRecruitment, String> {
+    let (channel_id, message_id) = parse_message_link(message_link, ctx.channel_id())
+        .ok_or_else(|| messages.get("battle_recruitment.invalid_message"))?;
+
+    match db.battle_recruitment.get_by_message(guild_id.get() as i64, channel_id.get() as i64, message_id.get() as i64).await {
+        Ok(Some(recruitment)) => Ok(recruitment),
+        Ok(None) => Err(messages.get("battle_recruitment.not_found")),
+        Err(e) => {
+            error!("DB募集情報取得エラー: {:?}", e);
+            Err(messages.get("battle_recruitment.database_error"))
+        }
+    }
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
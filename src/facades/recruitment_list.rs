@@ -0,0 +1,155 @@
+use crate::services::battle_recruitment::recruitment::ListRecruitmentService;
+use crate::services::battle_recruitment::recruitment::list::RecruitmentPage;
+use crate::services::message::Messages;
+use crate::repository::Database;
+use crate::types::{BattleType, PoiseContext, PoiseError, RecruitmentStatus};
+use poise::serenity_prelude::{ButtonStyle, ChannelId, ComponentInteractionCollector, CreateActionRow, CreateButton, CreateEmbed};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use super::{truncate_lines, EMBED_DESCRIPTION_LIMIT};
+
+/// ページ送りのボタンを受け付ける時間
+const PAGE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// サーバーの募集を一覧表示し、ボタンでページを送る
+pub(crate) async fn list(ctx: &PoiseContext<'_>, channel_id: Option<ChannelId>, status: Option<RecruitmentStatus>, days: Option<u32>) -> Result<(), PoiseError> {
+    let (service, messages, guild_id) = prepare(ctx).await?;
+
+    let filter = ListRecruitmentService::filter(guild_id, channel_id.map(|channel_id| channel_id.get() as i64), status, days, Utc::now());
+    let mut page = match service.page(ctx.serenity_context(), &messages, &filter, 0).await {
+        Ok(page) => page,
+        Err(e) => return reply(ctx, e).await,
+    };
+    if page.total == 0 {
+        return reply(ctx, messages.get("recruitment_list.empty")).await;
+    }
+
+    let previous_id = format!("{}:previous", ctx.id());
+    let next_id = format!("{}:next", ctx.id());
+    let handle = ctx.send(poise::CreateReply::default()
+        .embed(page_embed(&messages, &page))
+        .components(page_buttons(&messages, &page, &previous_id, &next_id))
+        .ephemeral(true)
+    ).await?;
+    if page.page_count() <= 1 {
+        return Ok(());
+    }
+    let message = handle.message().await?;
+
+    while let Some(interaction) = ComponentInteractionCollector::new(ctx.serenity_context())
+        .author_id(ctx.author().id)
+        .message_id(message.id)
+        .custom_ids(vec![previous_id.clone(), next_id.clone()])
+        .timeout(PAGE_TIMEOUT)
+        .await
+    {
+        interaction.defer(ctx.serenity_context()).await?;
+        let requested = if interaction.data.custom_id == previous_id {
+            page.page.saturating_sub(1)
+        } else {
+            page.page + 1
+        };
+        page = match service.page(ctx.serenity_context(), &messages, &filter, requested).await {
+            Ok(page) => page,
+            Err(e) => {
+                handle.edit(*ctx, poise::CreateReply::default().content(e).components(Vec::new())).await?;
+                return Ok(());
+            }
+        };
+
+        handle.edit(*ctx, poise::CreateReply::default()
+            .embed(page_embed(&messages, &page))
+            .components(page_buttons(&messages, &page, &previous_id, &next_id))
+        ).await?;
+    }
+
+    // 時間切れ後はボタンを外す
+    handle.edit(*ctx, poise::CreateReply::default()
+        .embed(page_embed(&messages, &page))
+        .components(Vec::new())
+    ).await?;
+    Ok(())
+}
+
+fn page_embed(messages: &Messages, page: &RecruitmentPage) -> CreateEmbed {
+    let lines: Vec<String> = page.items.iter()
+        .map(|item| {
+            let recruitment = &item.recruitment;
+            let battle_type = BattleType::from_value(recruitment.battle_type_id).unwrap_or(BattleType::Default);
+            let count = item.participant_count
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| "?".to_string());
+            let link = item.url.as_ref()
+                .map(|url| format!(" [{}]({})", messages.get("recruitment_list.jump"), url))
+                .unwrap_or_default();
+            format!(
+                "**{}**（{}）<t:{}:f> <#{}>\n　{} / {}{}",
+                item.quest_name, battle_type.name(), recruitment.expiry_date.timestamp(), recruitment.channel_id,
+                messages.get_with_params("recruitment_list.participants", &[
+                    ("count", &count),
+                    ("capacity", &recruitment.capacity.to_string()),
+                ]),
+                messages.get(recruitment.status().message_key()),
+                link,
+            )
+        })
+        .collect();
+
+    CreateEmbed::new()
+        .title(messages.get_with_params("recruitment_list.title", &[
+            ("page", &(page.page + 1).to_string()),
+            ("pages", &page.page_count().to_string()),
+            ("total", &page.total.to_string()),
+        ]))
+        .description(truncate_lines(&lines, EMBED_DESCRIPTION_LIMIT))
+        .color(0x0099ff)
+}
+
+/// 前後のページのボタン（1ページしかない場合はなし）
+fn page_buttons(messages: &Messages, page: &RecruitmentPage, previous_id: &str, next_id: &str) -> Vec<CreateActionRow> {
+    if page.page_count() <= 1 {
+        return Vec::new();
+    }
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(previous_id)
+            .label(messages.get("recruitment_list.previous"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page.page == 0),
+        CreateButton::new(next_id)
+            .label(messages.get("recruitment_list.next"))
+            .style(ButtonStyle::Secondary)
+            .disabled(page.page + 1 >= page.page_count()),
+    ])]
+}
+
+/// サービスと実行したユーザーの言語のメッセージを用意する
+async fn prepare(ctx: &PoiseContext<'_>) -> Result<(ListRecruitmentService, Messages, i64), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some(guild_id) = ctx.guild_id() else {
+        let error_msg = messages.get("errors.guild_only");
+        reply(ctx, error_msg.clone()).await?;
+        return Err(error_msg.into());
+    };
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            tracing::error!("データベース接続エラー: {}", e);
+            let error_msg = messages.get("errors.database_connection");
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    Ok((ListRecruitmentService::new(db), messages, guild_id.get() as i64))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
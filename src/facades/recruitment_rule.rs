@@ -0,0 +1,153 @@
+use crate::services::recruitment_rule::{RecruitmentRuleService, RuleInput};
+use crate::services::settings::SettingsService;
+use crate::repository::Database;
+use crate::types::{BattleType, DayOfWeek, PoiseContext, PoiseError};
+use poise::serenity_prelude::{ChannelId, CreateEmbed};
+use std::sync::Arc;
+use super::{truncate_lines, EMBED_DESCRIPTION_LIMIT};
+
+/// ルールを追加する
+/// チャンネル未指定時はサーバー設定の募集チャンネル、なければ実行したチャンネルに投稿する
+pub(crate) async fn add(ctx: &PoiseContext<'_>, quest_alias: &str, weekday: DayOfWeek, start_time: Option<&str>, channel_id: Option<ChannelId>, lead_time_minutes: Option<i64>) -> Result<(), PoiseError> {
+    let (service, db, guild_id) = prepare(ctx).await?;
+
+    let channel_id = match channel_id {
+        Some(channel_id) => channel_id.get() as i64,
+        None => match SettingsService::new(db).get(guild_id).await {
+            Ok(settings) => settings.recruitment_channel_id.unwrap_or(ctx.channel_id().get() as i64),
+            Err(e) => return reply(ctx, e).await,
+        },
+    };
+
+    let input = RuleInput {
+        guild_id,
+        channel_id,
+        quest_alias,
+        battle_type: BattleType::Default,
+        weekday: weekday.weekday(),
+        start_time,
+        lead_time_minutes,
+        created_by: ctx.author().id.get() as i64,
+    };
+    let content = match service.add(input).await {
+        Ok(rule) => format!(
+            "定期募集 #{} を追加しました。毎週{} {} の{}に <#{}> へ「{}」の募集を投稿します。",
+            rule.id, DayOfWeek::label(rule.weekday()), rule.start_time, lead_time_label(rule.lead_time_minutes),
+            rule.channel_id, rule.quest_alias,
+        ),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// サーバーのルールを一覧表示する
+pub(crate) async fn list(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let (service, _, guild_id) = prepare(ctx).await?;
+
+    let rules = match service.list(guild_id).await {
+        Ok(rules) => rules,
+        Err(e) => return reply(ctx, e).await,
+    };
+    if rules.is_empty() {
+        return reply(ctx, "定期募集は登録されていません。".to_string()).await;
+    }
+
+    let lines: Vec<String> = rules.iter()
+        .map(|(rule, event)| {
+            let state = if rule.paused {
+                "⏸️ 停止中".to_string()
+            } else {
+                format!("次回 <t:{}:f>", event.timestamp())
+            };
+            format!(
+                "**#{}** 毎週{} {}「{}」<#{}>（{}に投稿）\n　{}",
+                rule.id, DayOfWeek::label(rule.weekday()), rule.start_time, rule.quest_alias,
+                rule.channel_id, lead_time_label(rule.lead_time_minutes), state,
+            )
+        })
+        .collect();
+
+    let embed = CreateEmbed::new()
+        .title("定期募集の一覧")
+        .description(truncate_lines(&lines, EMBED_DESCRIPTION_LIMIT))
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// ルールを停止・再開する
+pub(crate) async fn set_paused(ctx: &PoiseContext<'_>, id: i32, paused: bool) -> Result<(), PoiseError> {
+    let (service, _, guild_id) = prepare(ctx).await?;
+
+    let content = match service.set_paused(guild_id, id, paused).await {
+        Ok(true) if paused => format!("定期募集 #{} を停止しました。", id),
+        Ok(true) => format!("定期募集 #{} を再開しました。", id),
+        Ok(false) => format!("定期募集 #{} が見つかりません。", id),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// ルールを削除する
+pub(crate) async fn delete(ctx: &PoiseContext<'_>, id: i32) -> Result<(), PoiseError> {
+    let (service, _, guild_id) = prepare(ctx).await?;
+
+    let content = match service.delete(guild_id, id).await {
+        Ok(true) => format!("定期募集 #{} を削除しました。", id),
+        Ok(false) => format!("定期募集 #{} が見つかりません。", id),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// サービスを生成する
+/// 権限はコマンドの check（Bot管理）で確認済み
+async fn prepare(ctx: &PoiseContext<'_>) -> Result<(RecruitmentRuleService, Arc<Database>, i64), PoiseError> {
+    let guild_id = ctx.guild_id()
+        .ok_or_else(|| PoiseError::from("このコマンドはサーバー内でのみ使用できます。"))?;
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            let error_msg = format!("データベース接続エラー: {}", e);
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    Ok((RecruitmentRuleService::new(db.clone()), db, guild_id.get() as i64))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// 投稿する時刻の表示（例: 1日前、1時間30分前）
+fn lead_time_label(minutes: i32) -> String {
+    if minutes == 0 {
+        return "開始時".to_string();
+    }
+    let (days, hours, minutes) = (minutes / (24 * 60), minutes / 60 % 24, minutes % 60);
+    let mut label = String::new();
+    if days > 0 {
+        label.push_str(&format!("{}日", days));
+    }
+    if hours > 0 {
+        label.push_str(&format!("{}時間", hours));
+    }
+    if minutes > 0 {
+        label.push_str(&format!("{}分", minutes));
+    }
+    label.push('前');
+    label
+}
+
@@ -0,0 +1,142 @@
+use crate::services::recruitment_template::{RecruitmentTemplateService, TemplateInput};
+use crate::services::message::Messages;
+use crate::repository::Database;
+use crate::types::{BattleType, PoiseContext, PoiseError};
+use poise::serenity_prelude::{ChannelId, CreateEmbed};
+use std::sync::Arc;
+use super::{truncate_lines, EMBED_DESCRIPTION_LIMIT};
+
+/// 募集テンプレートを保存する（同じ名前の自分のテンプレートは上書きする）
+pub(crate) async fn save(ctx: &PoiseContext<'_>, name: &str, quest_alias: &str, battle_type: BattleType, capacity: Option<i64>, notes: Option<&str>, channel_id: Option<ChannelId>) -> Result<(), PoiseError> {
+    let (service, messages, guild_id) = prepare(ctx).await?;
+
+    let input = TemplateInput {
+        guild_id,
+        owner_id: ctx.author().id.get() as i64,
+        name,
+        quest_alias,
+        battle_type,
+        capacity,
+        notes,
+        channel_id: channel_id.map(|channel_id| channel_id.get() as i64),
+    };
+    let content = match service.save(&messages, input).await {
+        Ok(template) => messages.get_with_params("recruitment_template.saved", &[("name", &template.name)]),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 使える募集テンプレートを一覧表示する
+pub(crate) async fn list(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let (service, messages, guild_id) = prepare(ctx).await?;
+
+    let templates = match service.list(&messages, guild_id, ctx.author().id.get() as i64).await {
+        Ok(templates) => templates,
+        Err(e) => return reply(ctx, e).await,
+    };
+    if templates.is_empty() {
+        return reply(ctx, messages.get("recruitment_template.list_empty")).await;
+    }
+
+    let lines: Vec<String> = templates.iter()
+        .map(|template| {
+            let public = if template.is_public {
+                format!("（{}）", messages.get("recruitment_template.public"))
+            } else {
+                String::new()
+            };
+            let channel = template.channel_id
+                .map(|channel_id| format!("<#{}>", channel_id))
+                .unwrap_or_else(|| messages.get("recruitment_template.default_channel"));
+            let mut line = format!(
+                "**{}**{} {} / {} / {} / {}",
+                template.name, public, template.quest_alias, template.battle_type().name(),
+                messages.get_with_params("recruitment_template.capacity", &[("capacity", &template.capacity.to_string())]),
+                channel,
+            );
+            if let Some(notes) = &template.notes {
+                line.push_str(&format!("\n　{}", notes));
+            }
+            line
+        })
+        .collect();
+
+    let embed = CreateEmbed::new()
+        .title(messages.get("recruitment_template.list_title"))
+        .description(truncate_lines(&lines, EMBED_DESCRIPTION_LIMIT))
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+/// 自分の募集テンプレートを削除する
+pub(crate) async fn delete(ctx: &PoiseContext<'_>, name: &str) -> Result<(), PoiseError> {
+    let (service, messages, guild_id) = prepare(ctx).await?;
+
+    let content = match service.delete(&messages, guild_id, ctx.author().id.get() as i64, name).await {
+        Ok(true) => messages.get_with_params("recruitment_template.deleted", &[("name", name)]),
+        Ok(false) => messages.get_with_params("recruitment_template.not_found", &[("name", name)]),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 自分の募集テンプレートをサーバー全体に公開する
+/// 権限はコマンドの check（Bot管理）で確認済み
+pub(crate) async fn publish(ctx: &PoiseContext<'_>, name: &str) -> Result<(), PoiseError> {
+    let (service, messages, guild_id) = prepare(ctx).await?;
+
+    let content = match service.publish(&messages, guild_id, ctx.author().id.get() as i64, name).await {
+        Ok(template) => messages.get_with_params("recruitment_template.published", &[("name", &template.name)]),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// 公開された募集テンプレートを非公開に戻す
+/// 権限はコマンドの check（Bot管理）で確認済み
+pub(crate) async fn unpublish(ctx: &PoiseContext<'_>, name: &str) -> Result<(), PoiseError> {
+    let (service, messages, guild_id) = prepare(ctx).await?;
+
+    let content = match service.unpublish(&messages, guild_id, ctx.author().id.get() as i64, name).await {
+        Ok(template) => messages.get_with_params("recruitment_template.unpublished", &[("name", &template.name)]),
+        Err(e) => e,
+    };
+    reply(ctx, content).await
+}
+
+/// サービスと実行したユーザーの言語のメッセージを用意する
+async fn prepare(ctx: &PoiseContext<'_>) -> Result<(RecruitmentTemplateService, Messages, i64), PoiseError> {
+    let messages = Messages::for_context(ctx).await;
+    let Some(guild_id) = ctx.guild_id() else {
+        let error_msg = messages.get("errors.guild_only");
+        reply(ctx, error_msg.clone()).await?;
+        return Err(error_msg.into());
+    };
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            tracing::error!("データベース接続エラー: {}", e);
+            let error_msg = messages.get("errors.database_connection");
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    Ok((RecruitmentTemplateService::new(db), messages, guild_id.get() as i64))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! Posts the recurring recruitments of `recruitment_rules`, and the reminders and start
+//! calls of posted recruitments.
+//!
+//! Every [`TICK_INTERVAL`] the rules whose posting time has passed are claimed with
+//! `mark_posted` before posting, so that an event is posted only once even if several
+//! processes are running. A failed post is logged and not retried until the next event.
+//!
+//! Reminders and start calls are claimed the same way (`mark_reminded` / `mark_started`).
+//! They are derived from the event time stored on each recruitment, so editing the time
+//! reschedules them without any timer to cancel.
+//!
+//! The start and end of each imported Guild War round are announced the same way
+//! (`mark_notified`); notices more than `NOTICE_GRACE_MINUTES` late are dropped.
+//!
+//! The three phases run independently: an error in one of them is logged and the others
+//! still run in the same tick.
+
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use poise::serenity_prelude as serenity;
+use tracing::{error, info};
+
+use crate::facades::battle_recruitment;
+use crate::models::battle_recruitment::ScheduledAction;
+use crate::models::recruitment_rule::RecruitmentRule;
+use crate::repository::Database;
+use crate::services::battle_recruitment::params::CreateParameter;
+use crate::services::battle_recruitment::recruitment::{NewRecruitmentService, ReminderService};
+use crate::services::battle_recruitment::DEFAULT_CAPACITY;
+use crate::services::guild_war::GuildWarService;
+use crate::services::message::Messages;
+use crate::services::recruitment_rule::RecruitmentRuleService;
+use crate::services::settings::SettingsService;
+use crate::types::BattleType;
+
+/// ルールを確認する間隔
+const TICK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 定期募集の投稿と、募集のリマインド・開始時刻の呼び出し、古戦場のお知らせをバックグラウンドで開始する
+#[allow(dead_code)] // main.rs からのみ起動する（ライブラリでは使われない）
+pub(crate) fn spawn(ctx: serenity::Context) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut db: Option<Arc<Database>> = None;
+        let mut interval = tokio::time::interval(TICK_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            // 接続できなかった場合は次の確認時に接続し直す
+            let database = match &db {
+                Some(database) => database.clone(),
+                None => match Database::new().await {
+                    Ok(database) => db.insert(Arc::new(database)).clone(),
+                    Err(e) => {
+                        error!("Recruitment scheduler skipped, database connection error: {}", e);
+                        continue;
+                    }
+                },
+            };
+
+            // 失敗した処理があった場合は、次の確認時にデータベースに接続し直す
+            if let Err(e) = tick(&ctx, database).await {
+                error!("Recruitment scheduler error: {}", e);
+                db = None;
+            }
+        }
+    })
+}
+
+/// 定期募集、リマインド・開始時刻の呼び出し、古戦場のお知らせを順に処理する
+/// 1つの処理が失敗しても残りの処理は続け、失敗した処理をまとめて返す
+async fn tick(ctx: &serenity::Context, db: Arc<Database>) -> Result<(), String> {
+    let results = [
+        ("recurring recruitments", post_recurring(ctx, db.clone()).await),
+        ("reminders", run_reminders(ctx, db.clone()).await),
+        ("guild war notices", announce_guild_war(ctx, db.clone()).await),
+    ];
+
+    let mut failed = Vec::new();
+    for (phase, result) in results {
+        if let Err(e) = result {
+            error!("Scheduler {} failed: {}", phase, e);
+            failed.push(phase);
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("failed: {}", failed.join(", ")))
+    }
+}
+
+/// 投稿時刻を過ぎた定期募集を投稿する
+async fn post_recurring(ctx: &serenity::Context, db: Arc<Database>) -> Result<(), String> {
+    let service = RecruitmentRuleService::new(db.clone());
+
+    for (rule, event) in service.due(Utc::now()).await? {
+        // 他のプロセスが先に投稿した回は飛ばす
+        match service.claim(&rule, event).await {
+            Ok(true) => {},
+            Ok(false) => continue,
+            Err(e) => {
+                error!("Recurring recruitment claim failed: rule_id={}, error={}", rule.id, e);
+                continue;
+            }
+        }
+
+        let messages = Messages::for_guild(&db, rule.guild_id).await;
+        match post(ctx, db.clone(), &messages, &rule, event).await {
+            Ok(message) => info!("Recurring recruitment posted: rule_id={}, message_id={}", rule.id, message.id),
+            Err(e) => error!("Recurring recruitment failed: rule_id={}, error={}", rule.id, e),
+        }
+    }
+    Ok(())
+}
+
+/// 募集のリマインドと開始時刻の呼び出しを送信する
+async fn run_reminders(ctx: &serenity::Context, db: Arc<Database>) -> Result<(), String> {
+    let reminders = ReminderService::new(db.clone());
+    for (recruitment, action) in reminders.due(Utc::now()).await? {
+        let result = match action {
+            ScheduledAction::Remind(offset_minutes) => reminders.remind(ctx, &recruitment, offset_minutes).await.map(|_| ()),
+            ScheduledAction::Start => battle_recruitment::start(
+                ctx,
+                db.clone(),
+                recruitment.guild_id as u64,
+                recruitment.channel_id as u64,
+                recruitment.message_id as u64,
+            ).await,
+            ScheduledAction::Expire => reminders.expire(&recruitment).await,
+        };
+        if let Err(e) = result {
+            error!("Scheduled {:?} failed: recruitment_id={}, error={}", action, recruitment.id, e);
+        }
+    }
+    Ok(())
+}
+
+/// 古戦場の区切りの開始・終了をお知らせする
+async fn announce_guild_war(ctx: &serenity::Context, db: Arc<Database>) -> Result<(), String> {
+    let guild_war = GuildWarService::new(db);
+    for (round, notice) in guild_war.due(Utc::now()).await? {
+        if let Err(e) = guild_war.announce(ctx, &round, notice).await {
+            error!("Guild war {:?} notice failed: schedule_id={}, error={}", notice, round.id, e);
+        }
+    }
+    Ok(())
+}
+
+async fn post(ctx: &serenity::Context, db: Arc<Database>, messages: &Messages, rule: &RecruitmentRule, event: chrono::DateTime<Utc>) -> Result<serenity::Message, String> {
+    // 開催日時はサーバーのタイムゾーンで表示する
+    let timezone = SettingsService::new(db.clone()).timezone(rule.guild_id).await;
+    let params = CreateParameter {
+        guild_id: rule.guild_id as u64,
+        channel_id: rule.channel_id as u64,
+        quest_alias: &rule.quest_alias,
+        battle_type: BattleType::from_value(rule.battle_type_id).unwrap_or(BattleType::Default),
+        event_date: Some(event.with_timezone(&timezone)),
+        capacity: DEFAULT_CAPACITY,
+        notes: None,
+        created_by: rule.created_by as u64,
+    };
+    NewRecruitmentService::new(db).create_recruitment(ctx, messages, params).await
+}
@@ -0,0 +1,97 @@
+use crate::services::permission::has_bot_control_permission;
+use crate::services::settings::{SettingKey, SettingsService};
+use crate::models::guild_settings::GuildSettings;
+use crate::repository::Database;
+use crate::types::{PoiseContext, PoiseError};
+use poise::serenity_prelude::CreateEmbed;
+use std::sync::Arc;
+
+/// サーバー設定を表示する
+pub(crate) async fn show(ctx: &PoiseContext<'_>) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx, false).await?;
+
+    match service.get(guild_id).await {
+        Ok(settings) => send_settings(ctx, "サーバー設定", &settings).await,
+        Err(e) => reply(ctx, e).await,
+    }
+}
+
+/// サーバー設定を変更する
+pub(crate) async fn set(ctx: &PoiseContext<'_>, key: SettingKey, value: &str) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx, true).await?;
+
+    match service.set(guild_id, key, value).await {
+        Ok(settings) => send_settings(ctx, &format!("{}を変更しました", key.label()), &settings).await,
+        Err(e) => reply(ctx, e).await,
+    }
+}
+
+/// サーバー設定を既定値に戻す
+pub(crate) async fn reset(ctx: &PoiseContext<'_>, key: Option<SettingKey>) -> Result<(), PoiseError> {
+    let (service, guild_id) = prepare(ctx, true).await?;
+
+    let title = match key {
+        Some(key) => format!("{}を既定値に戻しました", key.label()),
+        None => "すべての設定を既定値に戻しました".to_string(),
+    };
+    match service.reset(guild_id, key).await {
+        Ok(settings) => send_settings(ctx, &title, &settings).await,
+        Err(e) => reply(ctx, e).await,
+    }
+}
+
+/// 権限チェックを行い、サービスを生成する
+async fn prepare(ctx: &PoiseContext<'_>, require_permission: bool) -> Result<(SettingsService, i64), PoiseError> {
+    let guild_id = ctx.guild_id()
+        .ok_or_else(|| PoiseError::from("このコマンドはサーバー内でのみ使用できます。"))?;
+
+    if require_permission {
+        // コマンド実行者の情報取得
+        let member = ctx.author_member().await
+            .ok_or_else(|| PoiseError::from("このコマンドはサーバー内でのみ使用できます。"))?;
+
+        // 権限チェック
+        if let Err(permission_error) = has_bot_control_permission(ctx, &member).await {
+            return Err(permission_error.into());
+        }
+    }
+
+    // データベース接続
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            let error_msg = format!("データベース接続エラー: {}", e);
+            reply(ctx, error_msg.clone()).await?;
+            return Err(error_msg.into());
+        }
+    };
+
+    Ok((SettingsService::new(db), guild_id.get() as i64))
+}
+
+async fn reply(ctx: &PoiseContext<'_>, content: String) -> Result<(), PoiseError> {
+    ctx.send(poise::CreateReply::default()
+        .content(content)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
+
+async fn send_settings(ctx: &PoiseContext<'_>, title: &str, settings: &GuildSettings) -> Result<(), PoiseError> {
+    let fields = SettingKey::ALL.iter().map(|key| {
+        let value = key.display_value(settings)
+            .unwrap_or_else(|| format!("未設定（既定値: {}）", key.default_description()));
+        (key.label(), value, false)
+    });
+
+    let embed = CreateEmbed::new()
+        .title(title)
+        .fields(fields)
+        .color(0x0099ff);
+
+    ctx.send(poise::CreateReply::default()
+        .embed(embed)
+        .ephemeral(true)
+    ).await?;
+    Ok(())
+}
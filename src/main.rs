@@ -1,10 +1,12 @@
 use std::env;
 use std::path::Path;
 use std::sync::Arc;
-use std::time::Duration;
 use poise::serenity_prelude::{self as serenity, GatewayIntents};
 use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
 
+// Initialize rust-i18n at the binary root as well, since the binary declares its own module tree
+rust_i18n::i18n!("locales");
+
 mod events;
 mod services;
 mod utils;
@@ -12,8 +14,10 @@ mod models;
 mod types;
 mod repository;
 mod facades;
+mod cli;
 
 use crate::events::handler::event_handler;
+use crate::services::environment::config::Config;
 use crate::types::{PoiseData, PoiseError};
 
 #[tokio::main]
@@ -26,8 +30,43 @@ async fn main() {
     let dotenv_path = Path::new(&config_folder).join(".env");
     dotenv::from_path(dotenv_path).ok();
 
-    // Get Discord token
-    let token = env::var("DISCORD_TOKEN").expect("Expected a token in the environment");
+    // サブコマンドが指定された場合はBotを起動せずに実行する
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        if let Err(e) = cli::run(&args).await {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // 設定を読み込み、不正・不足しているキーがあればすべて表示して終了する
+    let config = match services::environment::init::init_config().await {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // メッセージの上書き（<CONFIG_FOLDER>/messages/<locale>.json）を読み込む
+    let messages_path = Path::new(&config_folder).join("messages");
+    if messages_path.is_dir() {
+        let messages_path = messages_path.to_string_lossy();
+        if let Err(e) = services::message::message_service::messages::load_custom_messages(&messages_path).await {
+            eprintln!("Failed to load custom messages from {}: {}", messages_path, e);
+        }
+    }
+
+    // environments / message_texts の変更を検知して読み込み直す
+    if config.config_hot_reload {
+        services::environment::listener::spawn(&config);
+    }
+
+    // カレンダーの配信URLを提供する（メンバーの確認はゲートウェイに接続せずにAPIで行う）
+    if let Some(addr) = config.calendar_feed_addr {
+        facades::calendar_feed::spawn(addr, Arc::new(serenity::Http::new(&config.discord_token)));
+    }
 
     // Set up intents
     let intents = GatewayIntents::GUILD_MESSAGES 
@@ -39,19 +78,27 @@ async fn main() {
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
             commands: commands(),
-            ..Default::default()
-        })
-        .options(poise::FrameworkOptions {
             event_handler: |ctx, event, framework, data| {
                 Box::pin(event_handler(ctx, event, framework, data))
             },
+            owners: config.owner_ids.iter().map(|id| serenity::UserId::new(*id)).collect(),
             ..Default::default()
         })
-        .setup(move |ctx, _ready, framework| {
-            Box::pin(async move {
-                poise::builtins::register_globally(ctx, &framework.options().commands).await?;
-
-                let db_conn = db_connect().await?;
+        .setup({
+            let config = config.clone();
+            move |ctx, _ready, framework| Box::pin(async move {
+                match config.guild_id {
+                    Some(guild_id) if !config.register_commands_globally => {
+                        let guild_id = serenity::GuildId::new(guild_id);
+                        poise::builtins::register_in_guild(ctx, &framework.options().commands, guild_id).await?;
+                    }
+                    _ => poise::builtins::register_globally(ctx, &framework.options().commands).await?,
+                }
+
+                // 定期募集の投稿を開始
+                facades::scheduler::spawn(ctx.clone());
+
+                let db_conn = db_connect(&config).await?;
                 let database_service: Arc<dyn crate::utils::database::DatabaseService> = Arc::new(
                     crate::utils::database::SeaOrmDatabase::new(db_conn)
                 );
@@ -67,26 +114,23 @@ async fn main() {
         .build();
 
     // Create client with poise
-    let client = serenity::ClientBuilder::new(&token, intents)
+    let client = serenity::ClientBuilder::new(&config.discord_token, intents)
         .framework(framework)
         .await;
 
     client.unwrap().start().await.unwrap();
 }
 
-async fn db_connect() -> Result<DatabaseConnection, DbErr> {
-    // データベースURL取得
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-
+async fn db_connect(config: &Config) -> Result<DatabaseConnection, DbErr> {
     // SeaORMでの接続設定
-    let mut opt = ConnectOptions::new(&database_url);
-    opt.max_connections(20)
-        .min_connections(5)
-        .connect_timeout(Duration::from_secs(8))
-        .acquire_timeout(Duration::from_secs(8))
-        .idle_timeout(Duration::from_secs(8))
-        .max_lifetime(Duration::from_secs(8))
-        .sqlx_logging(true)
+    let mut opt = ConnectOptions::new(&config.database_url);
+    opt.max_connections(config.db_max_connections)
+        .min_connections(config.db_min_connections)
+        .connect_timeout(config.db_connect_timeout)
+        .acquire_timeout(config.db_connect_timeout)
+        .idle_timeout(config.db_idle_timeout)
+        .max_lifetime(config.db_max_lifetime)
+        .sqlx_logging(config.db_sql_logging)
         .sqlx_logging_level(log::LevelFilter::Info);
 
     // SeaORMでDB接続
@@ -96,7 +140,25 @@ async fn db_connect() -> Result<DatabaseConnection, DbErr> {
 
 fn commands() -> Vec<poise::Command<PoiseData, PoiseError>> {
     vec![
-        // events::interactions::command_interactions::slash::environ_load::handle_environ_load_command(),
+        events::interactions::command_interactions::slash::recruit::handle_recruit_command(),
+        events::interactions::command_interactions::slash::quest::quest(),
+        events::interactions::command_interactions::slash::quest::alias(),
+        events::interactions::command_interactions::slash::settings::settings(),
+        events::interactions::command_interactions::slash::permission::permission(),
+        events::interactions::command_interactions::slash::environ_load::environ_load(),
+        events::interactions::command_interactions::slash::environ::environ(),
+        events::interactions::command_interactions::slash::message_text::message_text(),
+        events::interactions::command_interactions::slash::recruit_rule::recruit_rule(),
+        events::interactions::command_interactions::slash::recruit_template::recruit_template(),
+        events::interactions::command_interactions::slash::recruit_edit::recruit_edit(),
+        events::interactions::command_interactions::slash::recruit_start::recruit_start(),
+        events::interactions::command_interactions::slash::recruit_cancel::recruit_cancel(),
+        events::interactions::command_interactions::slash::recruit_transfer::recruit_transfer(),
+        events::interactions::command_interactions::slash::recruit_list::recruit_list(),
+        events::interactions::command_interactions::slash::my_recruitments::my_recruitments(),
+        events::interactions::command_interactions::slash::raid_id::raid_id(),
+        events::interactions::command_interactions::slash::calendar::calendar(),
+        events::interactions::command_interactions::slash::guild_war::guild_war(),
         // events::interactions::command_interactions::contextmenu::reaction_users_context_menu::get_reaction_members(),
         // events::interactions::command_interactions::contextmenu::reaction_grouping_users_context_menu::get_reaction_grouping_members(),
     ]
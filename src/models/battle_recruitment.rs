@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set, DbErr};
 
 use crate::models::entities::{battle_recruitment, battle_recruitment::Entity as BattleRecruitmentEntity};
 use crate::models::database::Database;
+use crate::types::RecruitmentStatus;
 
 /// Battle recruitment domain model
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,10 +17,89 @@ pub struct BattleRecruitment {
     pub battle_type_id: i32,
     pub expiry_date: DateTime<Utc>,
     pub recruit_end_message_id: Option<i64>,
+    /// 募集人数
+    pub capacity: i32,
+    /// 募集メッセージに添える補足
+    pub notes: Option<String>,
+    /// 送信済みのリマインドのうち最も開始時刻に近いもの（開始の何分前か）
+    pub reminded_offset_minutes: Option<i32>,
+    /// 開始時刻の呼び出しを送信した日時
+    pub started_at: Option<DateTime<Utc>>,
+    /// 募集をキャンセルした日時
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// 募集を作成したメンバー（記録前に作成された募集は None）
+    pub created_by: Option<i64>,
+    /// 募集メッセージに作成したスレッド
+    pub thread_id: Option<i64>,
+    /// 募集を登録したDiscordのイベント
+    pub scheduled_event_id: Option<i64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// 開始時刻を過ぎてから呼び出しを送信する猶予（分）
+/// Botが停止していたなどでこれより遅れた場合は、呼び出しを送信せずに開始済みにする
+pub const START_GRACE_MINUTES: i64 = 15;
+
+/// 募集の予定された処理
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduledAction {
+    /// 開始の指定分前のリマインド
+    Remind(i32),
+    /// 開始時刻の呼び出し
+    Start,
+    /// 開始時刻を過ぎすぎたため、呼び出しを送信せずに開始済みにする
+    Expire,
+}
+
+impl BattleRecruitment {
+    /// 募集の状態
+    pub fn status(&self) -> RecruitmentStatus {
+        if self.cancelled_at.is_some() {
+            RecruitmentStatus::Cancelled
+        } else if self.started_at.is_some() {
+            RecruitmentStatus::Started
+        } else if self.recruit_end_message_id.is_some() {
+            RecruitmentStatus::Full
+        } else {
+            RecruitmentStatus::Open
+        }
+    }
+
+    /// 募集を作成したメンバーか
+    pub fn is_created_by(&self, user_id: u64) -> bool {
+        self.created_by == Some(user_id as i64)
+    }
+
+    /// 募集メッセージへのリンク
+    pub fn message_url(&self) -> String {
+        format!("https://discord.com/channels/{}/{}/{}", self.guild_id, self.channel_id, self.message_id)
+    }
+
+    /// 現在行うべき予定された処理
+    /// リマインドは開始時刻に近いものから遡って送信済みかを判定し、過ぎてしまったものはまとめて1回にする
+    pub fn scheduled_action(&self, reminder_offsets: &[Duration], now: DateTime<Utc>) -> Option<ScheduledAction> {
+        if self.started_at.is_some() || self.cancelled_at.is_some() {
+            return None;
+        }
+        if now >= self.expiry_date {
+            return if now - self.expiry_date <= Duration::minutes(START_GRACE_MINUTES) {
+                Some(ScheduledAction::Start)
+            } else {
+                Some(ScheduledAction::Expire)
+            };
+        }
+
+        let remaining = self.expiry_date - now;
+        reminder_offsets.iter()
+            .filter(|offset| remaining <= **offset)
+            .map(|offset| offset.num_minutes() as i32)
+            .filter(|minutes| self.reminded_offset_minutes.is_none_or(|reminded| *minutes < reminded))
+            .min()
+            .map(ScheduledAction::Remind)
+    }
+}
+
 impl From<battle_recruitment::Model> for BattleRecruitment {
     fn from(model: battle_recruitment::Model) -> Self {
         Self {
@@ -31,6 +111,14 @@ impl From<battle_recruitment::Model> for BattleRecruitment {
             battle_type_id: model.battle_type_id,
             expiry_date: model.expiry_date,
             recruit_end_message_id: model.recruit_end_message_id,
+            capacity: model.capacity,
+            notes: model.notes,
+            reminded_offset_minutes: model.reminded_offset_minutes,
+            started_at: model.started_at,
+            cancelled_at: model.cancelled_at,
+            created_by: model.created_by,
+            thread_id: model.thread_id,
+            scheduled_event_id: model.scheduled_event_id,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -105,4 +193,74 @@ impl Database {
         
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recruitment(expiry_date: DateTime<Utc>) -> BattleRecruitment {
+        BattleRecruitment {
+            id: 1,
+            guild_id: 1,
+            channel_id: 1,
+            message_id: 1,
+            target_id: 1,
+            battle_type_id: 0,
+            expiry_date,
+            recruit_end_message_id: None,
+            capacity: 6,
+            notes: None,
+            reminded_offset_minutes: None,
+            started_at: None,
+            cancelled_at: None,
+            created_by: None,
+            thread_id: None,
+            scheduled_event_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_scheduled_action() {
+        let now = Utc::now();
+        let offsets = [Duration::minutes(60), Duration::minutes(15)];
+
+        assert_eq!(recruitment(now + Duration::minutes(90)).scheduled_action(&offsets, now), None);
+        assert_eq!(recruitment(now + Duration::minutes(45)).scheduled_action(&offsets, now), Some(ScheduledAction::Remind(60)));
+        // 送信し損ねたリマインドは開始時刻に近いもの1回にまとめる
+        assert_eq!(recruitment(now + Duration::minutes(10)).scheduled_action(&offsets, now), Some(ScheduledAction::Remind(15)));
+
+        let mut reminded = recruitment(now + Duration::minutes(45));
+        reminded.reminded_offset_minutes = Some(60);
+        assert_eq!(reminded.scheduled_action(&offsets, now), None);
+        reminded.expiry_date = now + Duration::minutes(10);
+        assert_eq!(reminded.scheduled_action(&offsets, now), Some(ScheduledAction::Remind(15)));
+        reminded.reminded_offset_minutes = Some(15);
+        assert_eq!(reminded.scheduled_action(&offsets, now), None);
+
+        assert_eq!(recruitment(now).scheduled_action(&[], now), Some(ScheduledAction::Start));
+        assert_eq!(recruitment(now - Duration::minutes(START_GRACE_MINUTES + 1)).scheduled_action(&[], now), Some(ScheduledAction::Expire));
+
+        let mut started = recruitment(now);
+        started.started_at = Some(now);
+        assert_eq!(started.scheduled_action(&offsets, now), None);
+
+        // キャンセルした募集はリマインドも呼び出しも送信しない
+        let mut cancelled = recruitment(now + Duration::minutes(10));
+        cancelled.cancelled_at = Some(now);
+        assert_eq!(cancelled.scheduled_action(&offsets, now), None);
+        assert_eq!(cancelled.status(), RecruitmentStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_is_created_by() {
+        let mut created = recruitment(Utc::now());
+        assert!(!created.is_created_by(1));
+
+        created.created_by = Some(1);
+        assert!(created.is_created_by(1));
+        assert!(!created.is_created_by(2));
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::entities::calendar_feed;
+
+/// メンバーのカレンダーの配信URL（サーバーと範囲ごと）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarFeed {
+    pub id: i32,
+    pub guild_id: i64,
+    pub user_id: i64,
+    /// `CalendarScope::key()` の値
+    pub scope: String,
+    /// 配信URLに含める推測できない文字列
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<calendar_feed::Model> for CalendarFeed {
+    fn from(model: calendar_feed::Model) -> Self {
+        Self {
+            id: model.id,
+            guild_id: model.guild_id,
+            user_id: model.user_id,
+            scope: model.scope,
+            token: model.token,
+            created_at: model.created_at,
+        }
+    }
+}
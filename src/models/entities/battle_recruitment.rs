@@ -13,6 +13,14 @@ pub struct Model {
     pub battle_type_id: i32,
     pub expiry_date: DateTimeUtc,
     pub recruit_end_message_id: Option<i64>,
+    pub capacity: i32,
+    pub notes: Option<String>,
+    pub reminded_offset_minutes: Option<i32>,
+    pub started_at: Option<DateTimeUtc>,
+    pub cancelled_at: Option<DateTimeUtc>,
+    pub created_by: Option<i64>,
+    pub thread_id: Option<i64>,
+    pub scheduled_event_id: Option<i64>,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
@@ -8,6 +8,7 @@ pub struct Model {
     pub id: i32,
     pub key: String,
     pub value: String,
+    pub is_secret: bool,
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
 }
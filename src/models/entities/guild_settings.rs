@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "guild_settings")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    #[sea_orm(unique)]
+    pub guild_id: i64,
+    pub recruitment_channel_id: Option<i64>,
+    pub default_start_time: Option<String>,
+    pub admin_role_name: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub reminder_offsets: Option<String>,
+    pub recruitment_threads: Option<bool>,
+    pub scheduled_events: Option<bool>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
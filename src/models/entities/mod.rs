@@ -3,10 +3,19 @@ pub mod quest_alias;
 pub mod battle_recruitment;
 pub mod environment;
 pub mod message_text;
+pub mod guild_settings;
+pub mod guild_permission;
+pub mod environment_audit_log;
+pub mod recruitment_rule;
+pub mod recruitment_template;
+pub mod recruitment_participant;
+pub mod recruitment_raid_id;
+pub mod calendar_feed;
+pub mod guild_war_schedule;
 
 // Re-export entities for easier access
 pub use quest::Entity as Quest;
 pub use quest_alias::Entity as QuestAlias;
 pub use battle_recruitment::Entity as BattleRecruitment;
 pub use environment::Entity as Environment;
-pub use message_text::Entity as MessageText;
\ No newline at end of file
+pub use message_text::Entity as MessageText;
@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    pub guild_id: Option<i64>,
     pub target_id: i32,
     pub quest_name: String,
     pub default_battle_type: i32,
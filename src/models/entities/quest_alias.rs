@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
+    pub guild_id: Option<i64>,
     pub target_id: i32,
     pub alias: String,
     pub created_at: DateTimeUtc,
@@ -0,0 +1,19 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "battle_recruitment_participants")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub recruitment_id: i32,
+    pub guild_id: i64,
+    pub user_id: i64,
+    pub element_id: i32,
+    pub joined_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -8,6 +8,7 @@ pub struct Environment {
     pub id: i32,
     pub key: String,
     pub value: String,
+    pub is_secret: bool,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -18,6 +19,7 @@ impl From<environment::Model> for Environment {
             id: model.id,
             key: model.key,
             value: model.value,
+            is_secret: model.is_secret,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use crate::models::entities::environment_audit_log;
+
+/// 環境変数の変更操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvironmentAuditAction {
+    Set,
+    Delete,
+}
+
+impl EnvironmentAuditAction {
+    /// データベースに保存する値
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Set => "set",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// `/environ` による環境変数の変更履歴（秘密の値はマスクして保存する）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvironmentAuditLog {
+    pub id: i32,
+    pub key: String,
+    pub action: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub guild_id: Option<i64>,
+    pub user_id: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<environment_audit_log::Model> for EnvironmentAuditLog {
+    fn from(model: environment_audit_log::Model) -> Self {
+        Self {
+            id: model.id,
+            key: model.key,
+            action: model.action,
+            old_value: model.old_value,
+            new_value: model.new_value,
+            guild_id: model.guild_id,
+            user_id: model.user_id,
+            created_at: model.created_at,
+        }
+    }
+}
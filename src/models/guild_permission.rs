@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use crate::models::entities::guild_permission;
+
+/// サーバーでロールまたはユーザーに付与された権限
+/// role_id と user_id はどちらか一方だけが設定される
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildPermission {
+    pub id: i32,
+    pub guild_id: i64,
+    pub capability: String,
+    pub role_id: Option<i64>,
+    pub user_id: Option<i64>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<guild_permission::Model> for GuildPermission {
+    fn from(model: guild_permission::Model) -> Self {
+        Self {
+            id: model.id,
+            guild_id: model.guild_id,
+            capability: model.capability,
+            role_id: model.role_id,
+            user_id: model.user_id,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::entities::guild_settings;
+use crate::utils::constants::ROLL_GBF_BOT_CONTROLS;
+
+/// 募集開始時刻の既定値
+pub const DEFAULT_START_TIME: &str = "21:00";
+
+/// 言語の既定値
+pub const DEFAULT_LOCALE: &str = "ja";
+
+/// タイムゾーンの既定値（グラブルのサーバー時刻に合わせて日本時間）
+pub const DEFAULT_TIMEZONE: &str = "+09:00";
+
+/// サーバーごとの設定
+/// 未設定の項目は None で保持し、取得時に既定値を補う
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildSettings {
+    pub id: i32,
+    pub guild_id: i64,
+    pub recruitment_channel_id: Option<i64>,
+    /// "HH:MM" 形式
+    pub default_start_time: Option<String>,
+    pub admin_role_name: Option<String>,
+    pub locale: Option<String>,
+    /// "+09:00" 形式のUTCからの時差
+    pub timezone: Option<String>,
+    /// 開始何分前にリマインドするか（カンマ区切り、降順）
+    pub reminder_offsets: Option<String>,
+    /// 募集メッセージにスレッドを作成するか
+    pub recruitment_threads: Option<bool>,
+    /// 募集をDiscordのイベントにも登録するか
+    pub scheduled_events: Option<bool>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<guild_settings::Model> for GuildSettings {
+    fn from(model: guild_settings::Model) -> Self {
+        Self {
+            id: model.id,
+            guild_id: model.guild_id,
+            recruitment_channel_id: model.recruitment_channel_id,
+            default_start_time: model.default_start_time,
+            admin_role_name: model.admin_role_name,
+            locale: model.locale,
+            timezone: model.timezone,
+            reminder_offsets: model.reminder_offsets,
+            recruitment_threads: model.recruitment_threads,
+            scheduled_events: model.scheduled_events,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl GuildSettings {
+    /// 何も設定されていないサーバーの設定
+    pub fn empty(guild_id: i64) -> Self {
+        Self {
+            id: 0,
+            guild_id,
+            recruitment_channel_id: None,
+            default_start_time: None,
+            admin_role_name: None,
+            locale: None,
+            timezone: None,
+            reminder_offsets: None,
+            recruitment_threads: None,
+            scheduled_events: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Botを管理できるロール名
+    pub fn admin_role_name(&self) -> &str {
+        self.admin_role_name.as_deref().unwrap_or(ROLL_GBF_BOT_CONTROLS)
+    }
+
+    pub fn locale(&self) -> &str {
+        self.locale.as_deref().unwrap_or(DEFAULT_LOCALE)
+    }
+
+    pub fn timezone(&self) -> FixedOffset {
+        self.timezone.as_deref()
+            .and_then(|timezone| timezone.parse().ok())
+            .unwrap_or_else(|| DEFAULT_TIMEZONE.parse().expect("default timezone is valid"))
+    }
+
+    pub fn default_start_time(&self) -> NaiveTime {
+        self.default_start_time.as_deref()
+            .and_then(|time| NaiveTime::parse_from_str(time, "%H:%M").ok())
+            .unwrap_or_else(|| NaiveTime::parse_from_str(DEFAULT_START_TIME, "%H:%M").expect("default start time is valid"))
+    }
+
+    /// リマインドのタイミング（開始前の時間、長い順）
+    pub fn reminder_offsets(&self) -> Vec<Duration> {
+        self.reminder_offsets.as_deref()
+            .map(|offsets| offsets.split(',')
+                .filter_map(|minutes| minutes.trim().parse::<i64>().ok())
+                .map(Duration::minutes)
+                .collect())
+            .unwrap_or_default()
+    }
+
+    /// 募集メッセージにスレッドを作成するか（既定は作成しない）
+    pub fn recruitment_threads(&self) -> bool {
+        self.recruitment_threads.unwrap_or(false)
+    }
+
+    /// 募集をDiscordのイベントにも登録するか（既定は登録しない）
+    pub fn scheduled_events(&self) -> bool {
+        self.scheduled_events.unwrap_or(false)
+    }
+
+    /// 日時が指定されなかった場合の開催日時（サーバーのタイムゾーンで今日の既定の開始時刻）
+    /// 今日の開始時刻を過ぎている場合は翌日の開始時刻
+    pub fn default_event_date(&self) -> DateTime<FixedOffset> {
+        let timezone = self.timezone();
+        let now = Utc::now().with_timezone(&timezone);
+        match timezone.from_local_datetime(&now.date_naive().and_time(self.default_start_time())).single() {
+            Some(today) if today > now => today,
+            Some(today) => today + Duration::days(1),
+            None => now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let settings = GuildSettings::empty(1);
+        assert_eq!(settings.admin_role_name(), ROLL_GBF_BOT_CONTROLS);
+        assert_eq!(settings.locale(), "ja");
+        assert_eq!(settings.timezone(), FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(settings.default_start_time(), NaiveTime::from_hms_opt(21, 0, 0).unwrap());
+        assert!(settings.reminder_offsets().is_empty());
+        assert!(!settings.recruitment_threads());
+        assert!(!settings.scheduled_events());
+    }
+
+    #[test]
+    fn test_configured_values() {
+        let mut settings = GuildSettings::empty(1);
+        settings.admin_role_name = Some("raid_admin".to_string());
+        settings.timezone = Some("-05:00".to_string());
+        settings.default_start_time = Some("19:30".to_string());
+        settings.reminder_offsets = Some("60,15".to_string());
+
+        assert_eq!(settings.admin_role_name(), "raid_admin");
+        assert_eq!(settings.timezone(), FixedOffset::west_opt(5 * 3600).unwrap());
+        assert_eq!(settings.reminder_offsets(), vec![Duration::minutes(60), Duration::minutes(15)]);
+
+        let event_date = settings.default_event_date().with_timezone(&settings.timezone());
+        assert_eq!(event_date.time(), NaiveTime::from_hms_opt(19, 30, 0).unwrap());
+        // 今日の開始時刻を過ぎていれば翌日になる
+        assert!(event_date > Utc::now());
+        assert!(event_date <= Utc::now() + Duration::days(1));
+    }
+}
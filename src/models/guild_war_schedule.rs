@@ -0,0 +1,146 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::entities::guild_war_schedule;
+use crate::types::GuildWarRound;
+
+/// 区切りの開始・終了のお知らせを投稿する猶予（停止していた間の古いお知らせは投稿しない）
+pub const NOTICE_GRACE_MINUTES: i64 = 30;
+
+/// 古戦場の区切り1つ分の日程
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildWarSchedule {
+    pub id: i32,
+    pub guild_id: i64,
+    /// `GuildWarRound::key()` の値
+    pub round: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// お知らせを投稿するチャンネル
+    pub channel_id: i64,
+    /// お知らせでメンションするロール
+    pub role_id: Option<i64>,
+    pub start_notified_at: Option<DateTime<Utc>>,
+    pub end_notified_at: Option<DateTime<Utc>>,
+    pub created_by: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 区切りのお知らせの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuildWarNotice {
+    Start,
+    End,
+}
+
+impl From<guild_war_schedule::Model> for GuildWarSchedule {
+    fn from(model: guild_war_schedule::Model) -> Self {
+        Self {
+            id: model.id,
+            guild_id: model.guild_id,
+            round: model.round,
+            starts_at: model.starts_at,
+            ends_at: model.ends_at,
+            channel_id: model.channel_id,
+            role_id: model.role_id,
+            start_notified_at: model.start_notified_at,
+            end_notified_at: model.end_notified_at,
+            created_by: model.created_by,
+            created_at: model.created_at,
+        }
+    }
+}
+
+impl GuildWarSchedule {
+    pub fn round(&self) -> Option<GuildWarRound> {
+        GuildWarRound::from_key(&self.round)
+    }
+
+    /// 現在投稿するべきお知らせ（開始と終了が同時の場合は開始を先にする）
+    pub fn due_notice(&self, now: DateTime<Utc>) -> Option<GuildWarNotice> {
+        let grace = Duration::minutes(NOTICE_GRACE_MINUTES);
+        let due = |at: DateTime<Utc>, notified: Option<DateTime<Utc>>| notified.is_none() && at <= now && now - at <= grace;
+        if due(self.starts_at, self.start_notified_at) {
+            Some(GuildWarNotice::Start)
+        } else if due(self.ends_at, self.end_notified_at) {
+            Some(GuildWarNotice::End)
+        } else {
+            None
+        }
+    }
+
+    /// 区切りの期間中で最初に `time`（サーバーのタイムゾーンの時刻）になる日時
+    /// 時刻を省略した場合は区切りの開始日時、期間中にその時刻が無い場合は None
+    pub fn event_date(&self, time: Option<NaiveTime>, timezone: FixedOffset) -> Option<DateTime<Utc>> {
+        let Some(time) = time else {
+            return Some(self.starts_at);
+        };
+
+        let first_day = self.starts_at.with_timezone(&timezone).date_naive();
+        let last_day = self.ends_at.with_timezone(&timezone).date_naive();
+        first_day.iter_days()
+            .take_while(|day| *day <= last_day)
+            .filter_map(|day| timezone.from_local_datetime(&day.and_time(time)).single())
+            .map(|date| date.with_timezone(&Utc))
+            .find(|date| *date >= self.starts_at && *date < self.ends_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> GuildWarSchedule {
+        GuildWarSchedule {
+            id: 1,
+            guild_id: 1,
+            round: GuildWarRound::Preliminaries.key().to_string(),
+            starts_at,
+            ends_at,
+            channel_id: 1,
+            role_id: None,
+            start_notified_at: None,
+            end_notified_at: None,
+            created_by: 1,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_due_notice() {
+        let now = Utc::now();
+        let mut round = schedule(now + Duration::minutes(10), now + Duration::hours(5));
+        assert_eq!(round.due_notice(now), None);
+
+        round.starts_at = now - Duration::minutes(1);
+        assert_eq!(round.due_notice(now), Some(GuildWarNotice::Start));
+        round.start_notified_at = Some(now);
+        assert_eq!(round.due_notice(now), None);
+
+        round.ends_at = now;
+        assert_eq!(round.due_notice(now), Some(GuildWarNotice::End));
+        round.end_notified_at = Some(now);
+        assert_eq!(round.due_notice(now), None);
+
+        // 停止していた間の古いお知らせは投稿しない
+        let stale = schedule(now - Duration::hours(5), now - Duration::minutes(NOTICE_GRACE_MINUTES + 1));
+        assert_eq!(stale.due_notice(now), None);
+    }
+
+    #[test]
+    fn test_event_date() {
+        let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let at = |day: u32, hour: u32, minute: u32| jst.with_ymd_and_hms(2026, 11, day, hour, minute, 0).unwrap().with_timezone(&Utc);
+        let time = |hour: u32, minute: u32| NaiveTime::from_hms_opt(hour, minute, 0);
+
+        // 予選は1日目の19時から2日目の23時59分まで
+        let preliminaries = schedule(at(1, 19, 0), at(2, 23, 59));
+        assert_eq!(preliminaries.event_date(None, jst), Some(at(1, 19, 0)));
+        assert_eq!(preliminaries.event_date(time(22, 0), jst), Some(at(1, 22, 0)));
+        // 1日目には過ぎている時刻は2日目
+        assert_eq!(preliminaries.event_date(time(12, 0), jst), Some(at(2, 12, 0)));
+
+        let finals = schedule(at(4, 7, 0), at(4, 23, 59));
+        assert_eq!(finals.event_date(time(22, 0), jst), Some(at(4, 22, 0)));
+        assert_eq!(finals.event_date(time(6, 0), jst), None);
+    }
+}
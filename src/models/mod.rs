@@ -4,4 +4,13 @@ pub(crate) mod quest;
 pub(crate) mod battle_recruitment;
 pub(crate) mod message_text;
 pub(crate) mod environment;
+pub(crate) mod guild_settings;
+pub(crate) mod guild_permission;
+pub(crate) mod environment_audit_log;
+pub(crate) mod recruitment_rule;
+pub(crate) mod recruitment_template;
+pub(crate) mod recruitment_participant;
+pub(crate) mod recruitment_raid_id;
+pub(crate) mod calendar_feed;
+pub(crate) mod guild_war_schedule;
 pub(crate) mod entities;
@@ -7,6 +7,8 @@ use crate::models::database::Database;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Quest {
     pub id: i32,
+    /// None の場合は全サーバー共通のクエスト
+    pub guild_id: Option<i64>,
     pub target_id: i32,
     pub quest_name: String,
     pub default_battle_type: i32,
@@ -17,6 +19,8 @@ pub struct Quest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestAlias {
     pub id: i32,
+    /// None の場合は全サーバー共通の別名
+    pub guild_id: Option<i64>,
     pub target_id: i32,
     pub alias: String,
     pub created_at: chrono::DateTime<chrono::Utc>,
@@ -27,6 +31,7 @@ impl From<quest::Model> for Quest {
     fn from(model: quest::Model) -> Self {
         Self {
             id: model.id,
+            guild_id: model.guild_id,
             target_id: model.target_id,
             quest_name: model.quest_name,
             default_battle_type: model.default_battle_type,
@@ -40,6 +45,7 @@ impl From<quest_alias::Model> for QuestAlias {
     fn from(model: quest_alias::Model) -> Self {
         Self {
             id: model.id,
+            guild_id: model.guild_id,
             target_id: model.target_id,
             alias: model.alias,
             created_at: model.created_at,
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::entities::recruitment_participant;
+
+/// 募集に参加したメンバー（リアクションした属性ごとに1件）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecruitmentParticipant {
+    pub id: i32,
+    pub recruitment_id: i32,
+    pub guild_id: i64,
+    pub user_id: i64,
+    /// リアクションした属性（BattleType の値）
+    pub element_id: i32,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl From<recruitment_participant::Model> for RecruitmentParticipant {
+    fn from(model: recruitment_participant::Model) -> Self {
+        Self {
+            id: model.id,
+            recruitment_id: model.recruitment_id,
+            guild_id: model.guild_id,
+            user_id: model.user_id,
+            element_id: model.element_id,
+            joined_at: model.joined_at,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use crate::models::entities::recruitment_raid_id;
+
+lazy_static::lazy_static! {
+    /// 参戦ID（前後に英数字が続かない8桁の16進数）
+    /// 日本語の文章に続けて書かれることが多いため、単語の区切りは英数字のみで判定する
+    static ref RAID_ID_PATTERN: Regex = Regex::new(r"(?:^|[^0-9A-Za-z])([0-9A-Fa-f]{8})(?:$|[^0-9A-Za-z])").unwrap();
+}
+
+/// 募集の参加者に共有した参戦ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecruitmentRaidId {
+    pub id: i32,
+    pub recruitment_id: i32,
+    pub guild_id: i64,
+    /// 大文字の参戦ID
+    pub raid_id: String,
+    /// 参戦IDを共有したメンバー
+    pub posted_by: i64,
+    pub posted_at: DateTime<Utc>,
+}
+
+impl From<recruitment_raid_id::Model> for RecruitmentRaidId {
+    fn from(model: recruitment_raid_id::Model) -> Self {
+        Self {
+            id: model.id,
+            recruitment_id: model.recruitment_id,
+            guild_id: model.guild_id,
+            raid_id: model.raid_id,
+            posted_by: model.posted_by,
+            posted_at: model.posted_at,
+        }
+    }
+}
+
+/// 文章に含まれる最初の参戦IDを大文字で返す
+pub fn find_raid_id(text: &str) -> Option<String> {
+    RAID_ID_PATTERN.captures(text).map(|captures| captures[1].to_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_raid_id() {
+        assert_eq!(find_raid_id("A1B2C3D4"), Some("A1B2C3D4".to_string()));
+        assert_eq!(find_raid_id("参戦ID: 0f1e2d3c :参戦ID\nよろしく"), Some("0F1E2D3C".to_string()));
+        assert_eq!(find_raid_id("救援1234ABCDお願いします"), Some("1234ABCD".to_string()));
+
+        // 桁数が違うものや16進数以外を含むものは参戦IDではない
+        assert_eq!(find_raid_id("A1B2C3D"), None);
+        assert_eq!(find_raid_id("A1B2C3D4E"), None);
+        assert_eq!(find_raid_id("G1B2C3D4"), None);
+        assert_eq!(find_raid_id("よろしくお願いします"), None);
+    }
+}
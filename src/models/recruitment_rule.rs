@@ -0,0 +1,158 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveTime, TimeZone, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use crate::models::entities::recruitment_rule;
+
+/// 毎週決まった曜日・時刻の募集を、開始時刻の一定時間前に投稿するルール
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecruitmentRule {
+    pub id: i32,
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub quest_alias: String,
+    pub battle_type_id: i32,
+    /// 0（月曜）〜 6（日曜）
+    pub weekday: i16,
+    /// サーバーのタイムゾーンでの "HH:MM"
+    pub start_time: String,
+    /// 開始時刻の何分前に投稿するか
+    pub lead_time_minutes: i32,
+    pub paused: bool,
+    /// 最後に投稿した回の開始時刻
+    pub last_posted_for: Option<DateTime<Utc>>,
+    pub created_by: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<recruitment_rule::Model> for RecruitmentRule {
+    fn from(model: recruitment_rule::Model) -> Self {
+        Self {
+            id: model.id,
+            guild_id: model.guild_id,
+            channel_id: model.channel_id,
+            quest_alias: model.quest_alias,
+            battle_type_id: model.battle_type_id,
+            weekday: model.weekday,
+            start_time: model.start_time,
+            lead_time_minutes: model.lead_time_minutes,
+            paused: model.paused,
+            last_posted_for: model.last_posted_for,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl RecruitmentRule {
+    pub fn weekday(&self) -> Weekday {
+        u8::try_from(self.weekday).ok()
+            .and_then(|weekday| Weekday::try_from(weekday).ok())
+            .unwrap_or(Weekday::Mon)
+    }
+
+    pub fn start_time(&self) -> NaiveTime {
+        NaiveTime::parse_from_str(&self.start_time, "%H:%M").unwrap_or(NaiveTime::MIN)
+    }
+
+    pub fn lead_time(&self) -> Duration {
+        Duration::minutes(self.lead_time_minutes as i64)
+    }
+
+    /// `now` 以降で最も近い開始時刻
+    pub fn next_event(&self, now: DateTime<Utc>, timezone: FixedOffset) -> DateTime<Utc> {
+        let local_now = now.with_timezone(&timezone);
+        let days_ahead = (7 + self.weekday().num_days_from_monday() - local_now.weekday().num_days_from_monday()) % 7;
+        let date = local_now.date_naive() + Duration::days(days_ahead as i64);
+
+        let event = timezone.from_local_datetime(&date.and_time(self.start_time()))
+            .single()
+            .map(|event| event.with_timezone(&Utc))
+            .unwrap_or(now);
+        if event < now { event + Duration::days(7) } else { event }
+    }
+
+    /// 投稿する回の開始時刻（投稿時刻を過ぎていて、その回をまだ投稿していない場合）
+    pub fn due_event(&self, now: DateTime<Utc>, timezone: FixedOffset) -> Option<DateTime<Utc>> {
+        if self.paused {
+            return None;
+        }
+
+        let event = self.next_event(now, timezone);
+        (now >= event - self.lead_time() && self.last_posted_for != Some(event)).then_some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(weekday: Weekday, start_time: &str, lead_time_minutes: i32) -> RecruitmentRule {
+        RecruitmentRule {
+            id: 1,
+            guild_id: 1,
+            channel_id: 1,
+            quest_alias: "ルシHL".to_string(),
+            battle_type_id: 0,
+            weekday: weekday.num_days_from_monday() as i16,
+            start_time: start_time.to_string(),
+            lead_time_minutes,
+            paused: false,
+            last_posted_for: None,
+            created_by: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn jst() -> FixedOffset {
+        FixedOffset::east_opt(9 * 3600).unwrap()
+    }
+
+    /// 日本時間での日時
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        // 2026-10-17 は土曜日
+        jst().with_ymd_and_hms(2026, 10, day, hour, minute, 0).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_next_event() {
+        let rule = rule(Weekday::Sat, "21:00", 60);
+
+        // 同じ日の開始前
+        assert_eq!(rule.next_event(at(17, 12, 0), jst()), at(17, 21, 0));
+        // 開始時刻ちょうど
+        assert_eq!(rule.next_event(at(17, 21, 0), jst()), at(17, 21, 0));
+        // 開始後は翌週
+        assert_eq!(rule.next_event(at(17, 21, 1), jst()), at(24, 21, 0));
+        // 週の途中
+        assert_eq!(rule.next_event(at(20, 9, 0), jst()), at(24, 21, 0));
+    }
+
+    #[test]
+    fn test_next_event_uses_guild_timezone() {
+        let rule = rule(Weekday::Sat, "21:00", 60);
+        let utc = FixedOffset::east_opt(0).unwrap();
+
+        // タイムゾーンがUTCのサーバーでは、UTCの土曜 21:00
+        let event = rule.next_event(at(17, 12, 0), utc);
+        assert_eq!(event, Utc.with_ymd_and_hms(2026, 10, 17, 21, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_due_event() {
+        let mut rule = rule(Weekday::Sat, "21:00", 60);
+
+        assert_eq!(rule.due_event(at(17, 19, 59), jst()), None);
+        assert_eq!(rule.due_event(at(17, 20, 0), jst()), Some(at(17, 21, 0)));
+
+        // 投稿済みの回は投稿しない
+        rule.last_posted_for = Some(at(17, 21, 0));
+        assert_eq!(rule.due_event(at(17, 20, 30), jst()), None);
+
+        // 停止中は投稿しない
+        rule.last_posted_for = None;
+        rule.paused = true;
+        assert_eq!(rule.due_event(at(17, 20, 30), jst()), None);
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::entities::recruitment_template;
+use crate::types::BattleType;
+
+/// 名前を付けて保存した募集の設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecruitmentTemplate {
+    pub id: i32,
+    pub guild_id: i64,
+    /// 保存したメンバー
+    pub owner_id: i64,
+    pub name: String,
+    pub quest_alias: String,
+    pub battle_type_id: i32,
+    pub capacity: i32,
+    pub notes: Option<String>,
+    /// 投稿するチャンネル（未設定時はサーバー設定の募集チャンネル）
+    pub channel_id: Option<i64>,
+    /// サーバー全体に公開されているか
+    pub is_public: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<recruitment_template::Model> for RecruitmentTemplate {
+    fn from(model: recruitment_template::Model) -> Self {
+        Self {
+            id: model.id,
+            guild_id: model.guild_id,
+            owner_id: model.owner_id,
+            name: model.name,
+            quest_alias: model.quest_alias,
+            battle_type_id: model.battle_type_id,
+            capacity: model.capacity,
+            notes: model.notes,
+            channel_id: model.channel_id,
+            is_public: model.is_public,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl RecruitmentTemplate {
+    pub fn battle_type(&self) -> BattleType {
+        BattleType::from_value(self.battle_type_id).unwrap_or(BattleType::Default)
+    }
+}
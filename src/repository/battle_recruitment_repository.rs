@@ -1,35 +1,119 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, QueryFilter, ConnectionTrait};
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, Condition, QueryFilter, QueryOrder, QuerySelect, PaginatorTrait, Select, ConnectionTrait};
+use sea_orm::sea_query::Expr;
 use chrono::{DateTime, Utc};
-use crate::types::PoiseError;
+use crate::types::{PoiseError, RecruitmentStatus};
 use crate::models::battle_recruitment::BattleRecruitment;
 use crate::models::entities::{battle_recruitment, battle_recruitment::Entity as BattleRecruitmentEntity};
 use crate::utils::database::Transaction;
 
+/// Values of a new battle recruitment
+pub struct NewBattleRecruitment {
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub message_id: i64,
+    pub target_id: i32,
+    pub battle_type_id: i32,
+    pub expiry_date: DateTime<Utc>,
+    pub capacity: i32,
+    pub notes: Option<String>,
+    /// User ID of the member who created the recruitment
+    pub created_by: Option<i64>,
+    /// Thread created on the recruitment message
+    pub thread_id: Option<i64>,
+    /// Discord scheduled event mirroring the recruitment
+    pub scheduled_event_id: Option<i64>,
+}
+
+impl NewBattleRecruitment {
+    fn into_active_model(self) -> battle_recruitment::ActiveModel {
+        battle_recruitment::ActiveModel {
+            guild_id: Set(self.guild_id),
+            channel_id: Set(self.channel_id),
+            message_id: Set(self.message_id),
+            target_id: Set(self.target_id),
+            battle_type_id: Set(self.battle_type_id),
+            expiry_date: Set(self.expiry_date),
+            capacity: Set(self.capacity),
+            notes: Set(self.notes),
+            created_by: Set(self.created_by),
+            thread_id: Set(self.thread_id),
+            scheduled_event_id: Set(self.scheduled_event_id),
+            ..Default::default()
+        }
+    }
+}
+
+/// Values of a battle recruitment that can be edited after posting
+pub struct RecruitmentDetails {
+    pub battle_type_id: i32,
+    pub expiry_date: DateTime<Utc>,
+    pub capacity: i32,
+    pub notes: Option<String>,
+}
+
+/// Conditions to search the battle recruitments of a guild
+pub struct RecruitmentFilter {
+    pub guild_id: i64,
+    pub channel_id: Option<i64>,
+    /// Any of these statuses (all statuses when empty)
+    pub statuses: Vec<RecruitmentStatus>,
+    /// Event time from (inclusive)
+    pub from: Option<DateTime<Utc>>,
+    /// Event time until (exclusive)
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl RecruitmentFilter {
+    fn apply(&self, query: Select<BattleRecruitmentEntity>) -> Select<BattleRecruitmentEntity> {
+        let mut query = query.filter(battle_recruitment::Column::GuildId.eq(self.guild_id));
+        if let Some(channel_id) = self.channel_id {
+            query = query.filter(battle_recruitment::Column::ChannelId.eq(channel_id));
+        }
+        if !self.statuses.is_empty() {
+            let statuses = self.statuses.iter().fold(Condition::any(), |condition, status| {
+                condition.add(match status {
+                    RecruitmentStatus::Open => Condition::all()
+                        .add(battle_recruitment::Column::CancelledAt.is_null())
+                        .add(battle_recruitment::Column::StartedAt.is_null())
+                        .add(battle_recruitment::Column::RecruitEndMessageId.is_null()),
+                    RecruitmentStatus::Full => Condition::all()
+                        .add(battle_recruitment::Column::CancelledAt.is_null())
+                        .add(battle_recruitment::Column::StartedAt.is_null())
+                        .add(battle_recruitment::Column::RecruitEndMessageId.is_not_null()),
+                    RecruitmentStatus::Started => Condition::all()
+                        .add(battle_recruitment::Column::CancelledAt.is_null())
+                        .add(battle_recruitment::Column::StartedAt.is_not_null()),
+                    RecruitmentStatus::Cancelled => Condition::all()
+                        .add(battle_recruitment::Column::CancelledAt.is_not_null()),
+                })
+            });
+            query = query.filter(statuses);
+        }
+        if let Some(from) = self.from {
+            query = query.filter(battle_recruitment::Column::ExpiryDate.gte(from));
+        }
+        if let Some(until) = self.until {
+            query = query.filter(battle_recruitment::Column::ExpiryDate.lt(until));
+        }
+        query
+    }
+}
+
 /// Repository trait for battle recruitment operations
 #[async_trait]
 pub trait BattleRecruitmentRepository: Send + Sync {
     /// Create new battle recruitment (auto-commit)
     async fn create(
         &self,
-        guild_id: i64,
-        channel_id: i64,
-        message_id: i64,
-        target_id: i32,
-        battle_type_id: i32,
-        expiry_date: DateTime<Utc>,
+        recruitment: NewBattleRecruitment,
     ) -> Result<BattleRecruitment, PoiseError>;
 
     /// Create new battle recruitment within a transaction
     async fn create_in_txn(
         &self,
         txn: &Transaction,
-        guild_id: i64,
-        channel_id: i64,
-        message_id: i64,
-        target_id: i32,
-        battle_type_id: i32,
-        expiry_date: DateTime<Utc>,
+        recruitment: NewBattleRecruitment,
     ) -> Result<BattleRecruitment, PoiseError>;
 
     /// Get battle recruitment by identifiers (auto-commit)
@@ -63,6 +147,85 @@ pub trait BattleRecruitmentRepository: Send + Sync {
         recruitment_id: i32,
         message_id: i64,
     ) -> Result<(), PoiseError>;
+
+    /// Update the editable values of a recruitment.
+    /// When the event time changes, the reminders and the start call are scheduled again
+    async fn update_details(
+        &self,
+        recruitment_id: i32,
+        details: RecruitmentDetails,
+    ) -> Result<BattleRecruitment, PoiseError>;
+
+    /// Change the member who owns a recruitment
+    async fn set_creator(
+        &self,
+        recruitment_id: i32,
+        user_id: i64,
+    ) -> Result<BattleRecruitment, PoiseError>;
+
+    /// Get the recruitments a member created in a guild
+    async fn get_by_creator(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError>;
+
+    /// Get the recruitment whose thread is the given channel
+    async fn get_by_thread(
+        &self,
+        guild_id: i64,
+        thread_id: i64,
+    ) -> Result<Option<BattleRecruitment>, PoiseError>;
+
+    /// Get recruitments by their IDs in order of event time
+    async fn get_by_ids(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError>;
+
+    /// Search the recruitments of a guild in order of event time
+    async fn search(
+        &self,
+        filter: &RecruitmentFilter,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError>;
+
+    /// Count the recruitments of a guild
+    async fn count(
+        &self,
+        filter: &RecruitmentFilter,
+    ) -> Result<u64, PoiseError>;
+
+    /// Get the recruitments neither started nor cancelled yet whose event time is until the given time
+    async fn get_pending_start(
+        &self,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError>;
+
+    /// Record that the reminder of the given offset was sent.
+    /// Returns false when it or a later reminder was already recorded by another process
+    async fn mark_reminded(
+        &self,
+        recruitment_id: i32,
+        offset_minutes: i32,
+    ) -> Result<bool, PoiseError>;
+
+    /// Record that the recruitment started.
+    /// Returns false when it was already started or cancelled by another process
+    async fn mark_started(
+        &self,
+        recruitment_id: i32,
+        started_at: DateTime<Utc>,
+    ) -> Result<bool, PoiseError>;
+
+    /// Record that the recruitment was cancelled.
+    /// Returns false when it was already started or cancelled by another process
+    async fn mark_cancelled(
+        &self,
+        recruitment_id: i32,
+        cancelled_at: DateTime<Utc>,
+    ) -> Result<bool, PoiseError>;
 }
 
 /// SeaORM implementation of BattleRecruitmentRepository
@@ -80,49 +243,19 @@ impl SeaOrmBattleRecruitmentRepository {
 impl BattleRecruitmentRepository for SeaOrmBattleRecruitmentRepository {
     async fn create(
         &self,
-        guild_id: i64,
-        channel_id: i64,
-        message_id: i64,
-        target_id: i32,
-        battle_type_id: i32,
-        expiry_date: DateTime<Utc>,
+        recruitment: NewBattleRecruitment,
     ) -> Result<BattleRecruitment, PoiseError> {
-        let battle_recruitment = battle_recruitment::ActiveModel {
-            guild_id: Set(guild_id),
-            channel_id: Set(channel_id),
-            message_id: Set(message_id),
-            target_id: Set(target_id),
-            battle_type_id: Set(battle_type_id),
-            expiry_date: Set(expiry_date),
-            ..Default::default()
-        };
-
-        let result = battle_recruitment.insert(&self.conn).await?;
+        let result = recruitment.into_active_model().insert(&self.conn).await?;
         Ok(result.into())
     }
 
     async fn create_in_txn(
         &self,
         txn: &Transaction,
-        guild_id: i64,
-        channel_id: i64,
-        message_id: i64,
-        target_id: i32,
-        battle_type_id: i32,
-        expiry_date: DateTime<Utc>,
+        recruitment: NewBattleRecruitment,
     ) -> Result<BattleRecruitment, PoiseError> {
         let sea_txn = txn.get_txn()?;
-        let battle_recruitment = battle_recruitment::ActiveModel {
-            guild_id: Set(guild_id),
-            channel_id: Set(channel_id),
-            message_id: Set(message_id),
-            target_id: Set(target_id),
-            battle_type_id: Set(battle_type_id),
-            expiry_date: Set(expiry_date),
-            ..Default::default()
-        };
-
-        let result = battle_recruitment.insert(sea_txn).await?;
+        let result = recruitment.into_active_model().insert(sea_txn).await?;
         Ok(result.into())
     }
 
@@ -197,6 +330,197 @@ impl BattleRecruitmentRepository for SeaOrmBattleRecruitmentRepository {
 
         Ok(())
     }
+
+    async fn update_details(
+        &self,
+        recruitment_id: i32,
+        details: RecruitmentDetails,
+    ) -> Result<BattleRecruitment, PoiseError> {
+        let recruitment = BattleRecruitmentEntity::find_by_id(recruitment_id)
+            .one(&self.conn)
+            .await?
+            .ok_or_else(|| PoiseError::from(format!("Battle recruitment not found: {}", recruitment_id)))?;
+
+        let rescheduled = recruitment.expiry_date != details.expiry_date;
+        let mut active_model: battle_recruitment::ActiveModel = recruitment.into();
+        active_model.battle_type_id = Set(details.battle_type_id);
+        active_model.expiry_date = Set(details.expiry_date);
+        active_model.capacity = Set(details.capacity);
+        active_model.notes = Set(details.notes);
+        if rescheduled {
+            active_model.reminded_offset_minutes = Set(None);
+            active_model.started_at = Set(None);
+        }
+        active_model.updated_at = Set(Utc::now());
+
+        let model = active_model.update(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to update battle recruitment: {}", e)))?;
+        Ok(model.into())
+    }
+
+    async fn set_creator(
+        &self,
+        recruitment_id: i32,
+        user_id: i64,
+    ) -> Result<BattleRecruitment, PoiseError> {
+        let recruitment = BattleRecruitmentEntity::find_by_id(recruitment_id)
+            .one(&self.conn)
+            .await?
+            .ok_or_else(|| PoiseError::from(format!("Battle recruitment not found: {}", recruitment_id)))?;
+
+        let mut active_model: battle_recruitment::ActiveModel = recruitment.into();
+        active_model.created_by = Set(Some(user_id));
+        active_model.updated_at = Set(Utc::now());
+
+        let model = active_model.update(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to update battle recruitment: {}", e)))?;
+        Ok(model.into())
+    }
+
+    async fn get_by_creator(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError> {
+        let recruitments = BattleRecruitmentEntity::find()
+            .filter(battle_recruitment::Column::GuildId.eq(guild_id))
+            .filter(battle_recruitment::Column::CreatedBy.eq(user_id))
+            .order_by_asc(battle_recruitment::Column::ExpiryDate)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get battle recruitments: {}", e)))?;
+
+        Ok(recruitments.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn get_by_thread(
+        &self,
+        guild_id: i64,
+        thread_id: i64,
+    ) -> Result<Option<BattleRecruitment>, PoiseError> {
+        let result = BattleRecruitmentEntity::find()
+            .filter(battle_recruitment::Column::GuildId.eq(guild_id))
+            .filter(battle_recruitment::Column::ThreadId.eq(thread_id))
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get battle recruitment: {}", e)))?;
+
+        Ok(result.map(|model| model.into()))
+    }
+
+    async fn get_by_ids(
+        &self,
+        ids: Vec<i32>,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError> {
+        let recruitments = BattleRecruitmentEntity::find()
+            .filter(battle_recruitment::Column::Id.is_in(ids))
+            .order_by_asc(battle_recruitment::Column::ExpiryDate)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get battle recruitments: {}", e)))?;
+
+        Ok(recruitments.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn search(
+        &self,
+        filter: &RecruitmentFilter,
+        offset: u64,
+        limit: u64,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError> {
+        let recruitments = filter.apply(BattleRecruitmentEntity::find())
+            .order_by_asc(battle_recruitment::Column::ExpiryDate)
+            .order_by_asc(battle_recruitment::Column::Id)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to search battle recruitments: {}", e)))?;
+
+        Ok(recruitments.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn count(
+        &self,
+        filter: &RecruitmentFilter,
+    ) -> Result<u64, PoiseError> {
+        filter.apply(BattleRecruitmentEntity::find())
+            .count(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to count battle recruitments: {}", e)))
+    }
+
+    async fn get_pending_start(
+        &self,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<BattleRecruitment>, PoiseError> {
+        let recruitments = BattleRecruitmentEntity::find()
+            .filter(battle_recruitment::Column::StartedAt.is_null())
+            .filter(battle_recruitment::Column::CancelledAt.is_null())
+            .filter(battle_recruitment::Column::ExpiryDate.lte(until))
+            .order_by_asc(battle_recruitment::Column::ExpiryDate)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get pending battle recruitments: {}", e)))?;
+
+        Ok(recruitments.into_iter().map(|model| model.into()).collect())
+    }
+
+    async fn mark_reminded(
+        &self,
+        recruitment_id: i32,
+        offset_minutes: i32,
+    ) -> Result<bool, PoiseError> {
+        let result = BattleRecruitmentEntity::update_many()
+            .col_expr(battle_recruitment::Column::RemindedOffsetMinutes, Expr::value(offset_minutes))
+            .filter(battle_recruitment::Column::Id.eq(recruitment_id))
+            .filter(battle_recruitment::Column::StartedAt.is_null())
+            .filter(battle_recruitment::Column::CancelledAt.is_null())
+            .filter(Condition::any()
+                .add(battle_recruitment::Column::RemindedOffsetMinutes.is_null())
+                .add(battle_recruitment::Column::RemindedOffsetMinutes.gt(offset_minutes)))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to record the reminder: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn mark_started(
+        &self,
+        recruitment_id: i32,
+        started_at: DateTime<Utc>,
+    ) -> Result<bool, PoiseError> {
+        let result = BattleRecruitmentEntity::update_many()
+            .col_expr(battle_recruitment::Column::StartedAt, Expr::value(started_at))
+            .filter(battle_recruitment::Column::Id.eq(recruitment_id))
+            .filter(battle_recruitment::Column::StartedAt.is_null())
+            .filter(battle_recruitment::Column::CancelledAt.is_null())
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to record the start: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn mark_cancelled(
+        &self,
+        recruitment_id: i32,
+        cancelled_at: DateTime<Utc>,
+    ) -> Result<bool, PoiseError> {
+        let result = BattleRecruitmentEntity::update_many()
+            .col_expr(battle_recruitment::Column::CancelledAt, Expr::value(cancelled_at))
+            .filter(battle_recruitment::Column::Id.eq(recruitment_id))
+            .filter(battle_recruitment::Column::StartedAt.is_null())
+            .filter(battle_recruitment::Column::CancelledAt.is_null())
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to record the cancellation: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
 
 #[cfg(test)]
@@ -235,14 +559,19 @@ mod tests {
         let battle_type_id = 1;
         let expiry_date = Utc::now() + chrono::Duration::hours(1);
 
-        let create_result = repo.create(
+        let create_result = repo.create(NewBattleRecruitment {
             guild_id,
             channel_id,
             message_id,
             target_id,
             battle_type_id,
             expiry_date,
-        ).await;
+            capacity: 6,
+            notes: None,
+            created_by: None,
+            thread_id: None,
+            scheduled_event_id: None,
+        }).await;
 
         match create_result {
             Ok(recruitment) => {
@@ -251,6 +580,7 @@ mod tests {
                 assert_eq!(recruitment.message_id, message_id);
                 assert_eq!(recruitment.target_id, target_id);
                 assert_eq!(recruitment.battle_type_id, battle_type_id);
+                assert_eq!(recruitment.capacity, 6);
 
                 // Test retrieving the created recruitment
                 let get_result = repo.get_by_message(guild_id, channel_id, message_id).await;
@@ -282,4 +612,115 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_battle_recruitment_schedule() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let expiry_date = Utc::now() + chrono::Duration::minutes(30);
+        let recruitment = repo.create(NewBattleRecruitment {
+            guild_id: 123456789,
+            channel_id: 987654321,
+            message_id: 555666778,
+            target_id: 1,
+            battle_type_id: 0,
+            expiry_date,
+            capacity: 6,
+            notes: None,
+            created_by: Some(42),
+            thread_id: Some(777),
+            scheduled_event_id: Some(888),
+        }).await.unwrap();
+        assert!(recruitment.is_created_by(42));
+        assert_eq!(recruitment.thread_id, Some(777));
+        assert_eq!(recruitment.scheduled_event_id, Some(888));
+        assert!(repo.get_by_thread(recruitment.guild_id, 777).await.unwrap().is_some());
+
+        assert!(repo.get_pending_start(expiry_date).await.unwrap().iter().any(|r| r.id == recruitment.id));
+        assert!(repo.mark_reminded(recruitment.id, 60).await.unwrap());
+        assert!(!repo.mark_reminded(recruitment.id, 60).await.unwrap(), "The same reminder should be recorded only once");
+        assert!(repo.mark_reminded(recruitment.id, 15).await.unwrap());
+
+        // 開催日時を変更するとリマインドを送り直す
+        let updated = repo.update_details(recruitment.id, RecruitmentDetails {
+            battle_type_id: 1,
+            expiry_date: expiry_date + chrono::Duration::hours(1),
+            capacity: 4,
+            notes: Some("フルオート可".to_string()),
+        }).await.unwrap();
+        assert_eq!(updated.capacity, 4);
+        assert_eq!(updated.reminded_offset_minutes, None);
+
+        let filter = |statuses: Vec<RecruitmentStatus>| RecruitmentFilter {
+            guild_id: 123456789,
+            channel_id: Some(987654321),
+            statuses,
+            from: Some(expiry_date),
+            until: None,
+        };
+        assert!(repo.search(&filter(vec![RecruitmentStatus::Open]), 0, 100).await.unwrap().iter().any(|r| r.id == recruitment.id));
+
+        assert!(repo.mark_started(recruitment.id, Utc::now()).await.unwrap());
+        assert!(!repo.mark_started(recruitment.id, Utc::now()).await.unwrap(), "A recruitment should start only once");
+        assert!(!repo.search(&filter(vec![RecruitmentStatus::Open, RecruitmentStatus::Full]), 0, 100).await.unwrap().iter().any(|r| r.id == recruitment.id));
+        assert!(repo.count(&filter(vec![RecruitmentStatus::Started])).await.unwrap() >= 1);
+        assert!(!repo.get_pending_start(expiry_date + chrono::Duration::hours(1)).await.unwrap().iter().any(|r| r.id == recruitment.id));
+
+        // 募集者を変更する
+        let transferred = repo.set_creator(recruitment.id, 43).await.unwrap();
+        assert!(transferred.is_created_by(43));
+        assert!(repo.get_by_creator(123456789, 43).await.unwrap().iter().any(|r| r.id == recruitment.id));
+        assert!(!repo.get_by_creator(123456789, 42).await.unwrap().iter().any(|r| r.id == recruitment.id));
+        assert!(!repo.mark_cancelled(recruitment.id, Utc::now()).await.unwrap(), "A started recruitment cannot be cancelled");
+    }
+
+    #[tokio::test]
+    async fn test_battle_recruitment_cancel() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let expiry_date = Utc::now() + chrono::Duration::minutes(30);
+        let recruitment = repo.create(NewBattleRecruitment {
+            guild_id: 123456789,
+            channel_id: 987654321,
+            message_id: 555666779,
+            target_id: 1,
+            battle_type_id: 0,
+            expiry_date,
+            capacity: 6,
+            notes: None,
+            created_by: Some(42),
+            thread_id: None,
+            scheduled_event_id: None,
+        }).await.unwrap();
+
+        assert!(repo.mark_cancelled(recruitment.id, Utc::now()).await.unwrap());
+        assert!(!repo.mark_cancelled(recruitment.id, Utc::now()).await.unwrap(), "A recruitment should be cancelled only once");
+        assert!(!repo.mark_started(recruitment.id, Utc::now()).await.unwrap(), "A cancelled recruitment should not start");
+        assert!(!repo.mark_reminded(recruitment.id, 15).await.unwrap(), "A cancelled recruitment should not be reminded");
+        assert!(!repo.get_pending_start(expiry_date).await.unwrap().iter().any(|r| r.id == recruitment.id));
+
+        let filter = |statuses: Vec<RecruitmentStatus>| RecruitmentFilter {
+            guild_id: 123456789,
+            channel_id: Some(987654321),
+            statuses,
+            from: Some(expiry_date),
+            until: Some(expiry_date + chrono::Duration::seconds(1)),
+        };
+        assert!(!repo.search(&filter(vec![RecruitmentStatus::Open, RecruitmentStatus::Full]), 0, 100).await.unwrap().iter().any(|r| r.id == recruitment.id));
+        assert!(repo.search(&filter(vec![RecruitmentStatus::Cancelled]), 0, 100).await.unwrap().iter().any(|r| r.id == recruitment.id));
+        let cancelled = repo.get_by_message(123456789, 987654321, 555666779).await.unwrap().unwrap();
+        assert_eq!(cancelled.status(), RecruitmentStatus::Cancelled);
+    }
 }
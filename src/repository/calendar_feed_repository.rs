@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, QueryFilter, DatabaseConnection, IntoActiveModel};
+use crate::types::PoiseError;
+use crate::models::calendar_feed::CalendarFeed;
+use crate::models::entities::{calendar_feed, calendar_feed::Entity as CalendarFeedEntity};
+
+#[async_trait]
+pub trait CalendarFeedRepository {
+    /// Get the feed of a member for a guild and scope
+    async fn get(&self, guild_id: i64, user_id: i64, scope: &str) -> Result<Option<CalendarFeed>, PoiseError>;
+
+    /// Get the feed with the given token
+    async fn get_by_token(&self, token: &str) -> Result<Option<CalendarFeed>, PoiseError>;
+
+    /// Create the feed of a member, or replace its token if it exists
+    async fn save(&self, guild_id: i64, user_id: i64, scope: &str, token: &str) -> Result<CalendarFeed, PoiseError>;
+}
+
+pub struct SeaOrmCalendarFeedRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmCalendarFeedRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+
+    async fn find(&self, guild_id: i64, user_id: i64, scope: &str) -> Result<Option<calendar_feed::Model>, PoiseError> {
+        CalendarFeedEntity::find()
+            .filter(calendar_feed::Column::GuildId.eq(guild_id))
+            .filter(calendar_feed::Column::UserId.eq(user_id))
+            .filter(calendar_feed::Column::Scope.eq(scope))
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get calendar feed: {}", e)))
+    }
+}
+
+#[async_trait]
+impl CalendarFeedRepository for SeaOrmCalendarFeedRepository {
+    async fn get(&self, guild_id: i64, user_id: i64, scope: &str) -> Result<Option<CalendarFeed>, PoiseError> {
+        Ok(self.find(guild_id, user_id, scope).await?.map(|model| model.into()))
+    }
+
+    async fn get_by_token(&self, token: &str) -> Result<Option<CalendarFeed>, PoiseError> {
+        let feed = CalendarFeedEntity::find()
+            .filter(calendar_feed::Column::Token.eq(token))
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get calendar feed: {}", e)))?;
+
+        Ok(feed.map(|model| model.into()))
+    }
+
+    async fn save(&self, guild_id: i64, user_id: i64, scope: &str, token: &str) -> Result<CalendarFeed, PoiseError> {
+        let model = match self.find(guild_id, user_id, scope).await? {
+            Some(existing) => {
+                let mut active_model = existing.into_active_model();
+                active_model.token = Set(token.to_string());
+                active_model.created_at = Set(chrono::Utc::now());
+                active_model.update(&self.conn).await
+            }
+            None => {
+                calendar_feed::ActiveModel {
+                    guild_id: Set(guild_id),
+                    user_id: Set(user_id),
+                    scope: Set(scope.to_string()),
+                    token: Set(token.to_string()),
+                    created_at: Set(chrono::Utc::now()),
+                    ..Default::default()
+                }
+                    .insert(&self.conn)
+                    .await
+            }
+        }
+            .map_err(|e| PoiseError::from(format!("Failed to save calendar feed: {}", e)))?;
+
+        Ok(model.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_calendar_feeds() {
+        if std::env::var("DATABASE_URL").is_err() {
+            println!("Skipping database test: DATABASE_URL not set");
+            return;
+        }
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => {
+                println!("Skipping database test: Failed to connect to database: {}", e);
+                return;
+            }
+        };
+        let repo = SeaOrmCalendarFeedRepository::new(conn);
+
+        let guild_id = 999_999_999_995;
+        let token = format!("test-{}", chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default());
+        let feed = repo.save(guild_id, 1, "joined", &token).await.unwrap();
+        assert_eq!(repo.get_by_token(&token).await.unwrap().map(|f| f.id), Some(feed.id));
+
+        // 作り直すと以前のトークンは使えなくなる
+        let renewed = repo.save(guild_id, 1, "joined", &format!("{}-renewed", token)).await.unwrap();
+        assert_eq!(renewed.id, feed.id);
+        assert!(repo.get_by_token(&token).await.unwrap().is_none());
+        assert_eq!(repo.get(guild_id, 1, "joined").await.unwrap().map(|f| f.token), Some(renewed.token));
+        assert!(repo.get(guild_id, 1, "guild").await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, QueryFilter, QueryOrder, QuerySelect, DatabaseConnection};
+use crate::types::PoiseError;
+use crate::models::environment_audit_log::{EnvironmentAuditAction, EnvironmentAuditLog};
+use crate::models::entities::{environment_audit_log, environment_audit_log::Entity as EnvironmentAuditLogEntity};
+
+#[async_trait]
+pub trait EnvironmentAuditRepository {
+    /// Record a change of an environment variable
+    async fn record(
+        &self,
+        key: &str,
+        action: EnvironmentAuditAction,
+        old_value: Option<&str>,
+        new_value: Option<&str>,
+        guild_id: Option<i64>,
+        user_id: i64,
+    ) -> Result<EnvironmentAuditLog, PoiseError>;
+
+    /// Get the latest changes of an environment variable, newest first
+    async fn get_by_key(&self, key: &str, limit: u64) -> Result<Vec<EnvironmentAuditLog>, PoiseError>;
+}
+
+pub struct SeaOrmEnvironmentAuditRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmEnvironmentAuditRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl EnvironmentAuditRepository for SeaOrmEnvironmentAuditRepository {
+    async fn record(
+        &self,
+        key: &str,
+        action: EnvironmentAuditAction,
+        old_value: Option<&str>,
+        new_value: Option<&str>,
+        guild_id: Option<i64>,
+        user_id: i64,
+    ) -> Result<EnvironmentAuditLog, PoiseError> {
+        let new_log = environment_audit_log::ActiveModel {
+            key: Set(key.to_string()),
+            action: Set(action.as_str().to_string()),
+            old_value: Set(old_value.map(|value| value.to_string())),
+            new_value: Set(new_value.map(|value| value.to_string())),
+            guild_id: Set(guild_id),
+            user_id: Set(user_id),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let result = new_log.insert(&self.conn).await
+            .map_err(|e| PoiseError::from(format!("Failed to record environment audit log: {}", e)))?;
+
+        Ok(result.into())
+    }
+
+    async fn get_by_key(&self, key: &str, limit: u64) -> Result<Vec<EnvironmentAuditLog>, PoiseError> {
+        let logs = EnvironmentAuditLogEntity::find()
+            .filter(environment_audit_log::Column::Key.eq(key))
+            .order_by_desc(environment_audit_log::Column::CreatedAt)
+            .order_by_desc(environment_audit_log::Column::Id)
+            .limit(limit)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get environment audit logs: {}", e)))?;
+
+        Ok(logs.into_iter().map(|log| log.into()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_test_repo() -> Result<SeaOrmEnvironmentAuditRepository, String> {
+        if std::env::var("DATABASE_URL").is_err() {
+            return Err("DATABASE_URL not set".to_string());
+        }
+
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => return Err(format!("Failed to connect to database: {}", e)),
+        };
+
+        Ok(SeaOrmEnvironmentAuditRepository::new(conn))
+    }
+
+    #[tokio::test]
+    async fn test_record_and_get_by_key() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let test_key = "TEST_AUDIT_KEY";
+        repo.record(test_key, EnvironmentAuditAction::Set, None, Some("1"), Some(1), 2).await
+            .expect("record should succeed");
+        repo.record(test_key, EnvironmentAuditAction::Delete, Some("1"), None, Some(1), 2).await
+            .expect("record should succeed");
+
+        let logs = repo.get_by_key(test_key, 2).await.unwrap();
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].action, "delete");
+        assert_eq!(logs[0].old_value.as_deref(), Some("1"));
+        assert_eq!(logs[1].action, "set");
+    }
+}
@@ -13,7 +13,10 @@ pub trait EnvironmentRepository {
     async fn get_by_key(&self, key: &str) -> Result<Option<Environment>, PoiseError>;
     
     /// Set environment variable
-    async fn set(&self, key: &str, value: &str) -> Result<Environment, PoiseError>;
+    async fn set(&self, key: &str, value: &str, is_secret: bool) -> Result<Environment, PoiseError>;
+
+    /// Delete environment variable. Returns false when the key did not exist
+    async fn delete(&self, key: &str) -> Result<bool, PoiseError>;
 }
 
 pub struct SeaOrmEnvironmentRepository {
@@ -38,6 +41,7 @@ impl EnvironmentRepository for SeaOrmEnvironmentRepository {
             id: env.id,
             key: env.key,
             value: env.value,
+            is_secret: env.is_secret,
             created_at: env.created_at,
             updated_at: env.updated_at,
         }).collect())
@@ -54,12 +58,13 @@ impl EnvironmentRepository for SeaOrmEnvironmentRepository {
             id: env.id,
             key: env.key,
             value: env.value,
+            is_secret: env.is_secret,
             created_at: env.created_at,
             updated_at: env.updated_at,
         }))
     }
 
-    async fn set(&self, key: &str, value: &str) -> Result<Environment, PoiseError> {
+    async fn set(&self, key: &str, value: &str, is_secret: bool) -> Result<Environment, PoiseError> {
         // First try to find existing environment variable
         let existing = EnvironmentEntity::find()
             .filter(environment::Column::Key.eq(key))
@@ -71,6 +76,7 @@ impl EnvironmentRepository for SeaOrmEnvironmentRepository {
             // Update existing environment variable
             let mut active_model: environment::ActiveModel = existing_env.into();
             active_model.value = Set(value.to_string());
+            active_model.is_secret = Set(is_secret);
             active_model.updated_at = Set(chrono::Utc::now());
             
             active_model.update(&self.conn).await
@@ -80,6 +86,7 @@ impl EnvironmentRepository for SeaOrmEnvironmentRepository {
             let new_env = environment::ActiveModel {
                 key: Set(key.to_string()),
                 value: Set(value.to_string()),
+                is_secret: Set(is_secret),
                 created_at: Set(chrono::Utc::now()),
                 updated_at: Set(chrono::Utc::now()),
                 ..Default::default()
@@ -93,10 +100,21 @@ impl EnvironmentRepository for SeaOrmEnvironmentRepository {
             id: result.id,
             key: result.key,
             value: result.value,
+            is_secret: result.is_secret,
             created_at: result.created_at,
             updated_at: result.updated_at,
         })
     }
+
+    async fn delete(&self, key: &str) -> Result<bool, PoiseError> {
+        let result = EnvironmentEntity::delete_many()
+            .filter(environment::Column::Key.eq(key))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete environment: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
 
 #[cfg(test)]
@@ -146,7 +164,7 @@ mod tests {
         match get_result {
             Ok(None) => {
                 // Try to set the environment variable
-                let set_result = repo.set(test_key, "test_value").await;
+                let set_result = repo.set(test_key, "test_value", false).await;
                 match set_result {
                     Ok(env) => {
                         assert_eq!(env.key, test_key);
@@ -158,6 +176,7 @@ mod tests {
                             Ok(Some(retrieved_env)) => {
                                 assert_eq!(retrieved_env.key, test_key);
                                 assert_eq!(retrieved_env.value, "test_value");
+                                assert!(!retrieved_env.is_secret);
                             },
                             _ => println!("Failed to retrieve set environment"),
                         }
@@ -176,4 +195,23 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_delete_environment() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let test_key = "TEST_DELETE_KEY";
+        repo.set(test_key, "secret_value", true).await.expect("set should succeed");
+        assert!(repo.get_by_key(test_key).await.unwrap().unwrap().is_secret);
+
+        assert!(repo.delete(test_key).await.unwrap());
+        assert!(repo.get_by_key(test_key).await.unwrap().is_none());
+        assert!(!repo.delete(test_key).await.unwrap());
+    }
+}
@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, QueryFilter, QueryOrder, DatabaseConnection};
+use crate::types::PoiseError;
+use crate::models::guild_permission::GuildPermission;
+use crate::models::entities::{guild_permission, guild_permission::Entity as GuildPermissionEntity};
+
+#[async_trait]
+pub trait GuildPermissionRepository {
+    /// Get all capabilities granted in a guild
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<GuildPermission>, PoiseError>;
+
+    /// Grant a capability to a role or a user. Exactly one of role_id and user_id must be set
+    async fn grant(&self, guild_id: i64, capability: &str, role_id: Option<i64>, user_id: Option<i64>) -> Result<GuildPermission, PoiseError>;
+
+    /// Revoke a capability from a role or a user. Returns false when it was not granted
+    async fn revoke(&self, guild_id: i64, capability: &str, role_id: Option<i64>, user_id: Option<i64>) -> Result<bool, PoiseError>;
+}
+
+pub struct SeaOrmGuildPermissionRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmGuildPermissionRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl GuildPermissionRepository for SeaOrmGuildPermissionRepository {
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<GuildPermission>, PoiseError> {
+        let permissions = GuildPermissionEntity::find()
+            .filter(guild_permission::Column::GuildId.eq(guild_id))
+            .order_by_asc(guild_permission::Column::Capability)
+            .order_by_asc(guild_permission::Column::Id)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get guild permissions: {}", e)))?;
+
+        Ok(permissions.into_iter().map(|p| p.into()).collect())
+    }
+
+    async fn grant(&self, guild_id: i64, capability: &str, role_id: Option<i64>, user_id: Option<i64>) -> Result<GuildPermission, PoiseError> {
+        let new_permission = guild_permission::ActiveModel {
+            guild_id: Set(guild_id),
+            capability: Set(capability.to_string()),
+            role_id: Set(role_id),
+            user_id: Set(user_id),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let result = new_permission.insert(&self.conn).await
+            .map_err(|e| PoiseError::from(format!("Failed to grant guild permission: {}", e)))?;
+
+        Ok(result.into())
+    }
+
+    async fn revoke(&self, guild_id: i64, capability: &str, role_id: Option<i64>, user_id: Option<i64>) -> Result<bool, PoiseError> {
+        let mut query = GuildPermissionEntity::delete_many()
+            .filter(guild_permission::Column::GuildId.eq(guild_id))
+            .filter(guild_permission::Column::Capability.eq(capability));
+        query = match role_id {
+            Some(role_id) => query.filter(guild_permission::Column::RoleId.eq(role_id)),
+            None => query.filter(guild_permission::Column::RoleId.is_null()),
+        };
+        query = match user_id {
+            Some(user_id) => query.filter(guild_permission::Column::UserId.eq(user_id)),
+            None => query.filter(guild_permission::Column::UserId.is_null()),
+        };
+
+        let result = query.exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to revoke guild permission: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_test_repo() -> Result<SeaOrmGuildPermissionRepository, String> {
+        if std::env::var("DATABASE_URL").is_err() {
+            return Err("DATABASE_URL not set".to_string());
+        }
+
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => return Err(format!("Failed to connect to database: {}", e)),
+        };
+
+        Ok(SeaOrmGuildPermissionRepository::new(conn))
+    }
+
+    #[tokio::test]
+    async fn test_grant_and_revoke() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let guild_id = 999_999_999_999;
+        let role_id = Some(111);
+        let _ = repo.revoke(guild_id, "manage_quests", role_id, None).await;
+
+        match repo.grant(guild_id, "manage_quests", role_id, None).await {
+            Ok(permission) => {
+                assert_eq!(permission.role_id, role_id);
+                assert!(permission.user_id.is_none());
+
+                let permissions = repo.get_by_guild(guild_id).await.unwrap_or_default();
+                assert!(permissions.iter().any(|p| p.capability == "manage_quests" && p.role_id == role_id));
+
+                assert!(repo.revoke(guild_id, "manage_quests", role_id, None).await.unwrap_or(false));
+                assert!(!repo.revoke(guild_id, "manage_quests", role_id, None).await.unwrap_or(true));
+            },
+            Err(e) => {
+                println!("Grant guild permission returned error (maybe expected): {}", e);
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, QueryFilter, DatabaseConnection};
+use crate::types::PoiseError;
+use crate::models::guild_settings::GuildSettings;
+use crate::models::entities::{guild_settings, guild_settings::Entity as GuildSettingsEntity};
+
+#[async_trait]
+pub trait GuildSettingsRepository {
+    /// Get the settings of a guild. Returns None when nothing has been configured yet
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Option<GuildSettings>, PoiseError>;
+
+    /// Create or update the settings of a guild
+    async fn save(&self, settings: &GuildSettings) -> Result<GuildSettings, PoiseError>;
+
+    /// Delete the settings of a guild. Returns false when nothing was configured
+    async fn delete(&self, guild_id: i64) -> Result<bool, PoiseError>;
+}
+
+pub struct SeaOrmGuildSettingsRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmGuildSettingsRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl GuildSettingsRepository for SeaOrmGuildSettingsRepository {
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Option<GuildSettings>, PoiseError> {
+        let settings = GuildSettingsEntity::find()
+            .filter(guild_settings::Column::GuildId.eq(guild_id))
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get guild settings: {}", e)))?;
+
+        Ok(settings.map(|s| s.into()))
+    }
+
+    async fn save(&self, settings: &GuildSettings) -> Result<GuildSettings, PoiseError> {
+        let existing = GuildSettingsEntity::find()
+            .filter(guild_settings::Column::GuildId.eq(settings.guild_id))
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to check the existing guild settings: {}", e)))?;
+
+        let result = if let Some(existing) = existing {
+            // Update existing settings
+            let mut active_model: guild_settings::ActiveModel = existing.into();
+            active_model.recruitment_channel_id = Set(settings.recruitment_channel_id);
+            active_model.default_start_time = Set(settings.default_start_time.clone());
+            active_model.admin_role_name = Set(settings.admin_role_name.clone());
+            active_model.locale = Set(settings.locale.clone());
+            active_model.timezone = Set(settings.timezone.clone());
+            active_model.reminder_offsets = Set(settings.reminder_offsets.clone());
+            active_model.recruitment_threads = Set(settings.recruitment_threads);
+            active_model.scheduled_events = Set(settings.scheduled_events);
+            active_model.updated_at = Set(chrono::Utc::now());
+
+            active_model.update(&self.conn).await
+                .map_err(|e| PoiseError::from(format!("Failed to update guild settings: {}", e)))?
+        } else {
+            // Create new settings
+            let new_settings = guild_settings::ActiveModel {
+                guild_id: Set(settings.guild_id),
+                recruitment_channel_id: Set(settings.recruitment_channel_id),
+                default_start_time: Set(settings.default_start_time.clone()),
+                admin_role_name: Set(settings.admin_role_name.clone()),
+                locale: Set(settings.locale.clone()),
+                timezone: Set(settings.timezone.clone()),
+                reminder_offsets: Set(settings.reminder_offsets.clone()),
+                recruitment_threads: Set(settings.recruitment_threads),
+                scheduled_events: Set(settings.scheduled_events),
+                created_at: Set(chrono::Utc::now()),
+                updated_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            };
+
+            new_settings.insert(&self.conn).await
+                .map_err(|e| PoiseError::from(format!("Failed to create guild settings: {}", e)))?
+        };
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, guild_id: i64) -> Result<bool, PoiseError> {
+        let result = GuildSettingsEntity::delete_many()
+            .filter(guild_settings::Column::GuildId.eq(guild_id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete guild settings: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_test_repo() -> Result<SeaOrmGuildSettingsRepository, String> {
+        if std::env::var("DATABASE_URL").is_err() {
+            return Err("DATABASE_URL not set".to_string());
+        }
+
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => return Err(format!("Failed to connect to database: {}", e)),
+        };
+
+        Ok(SeaOrmGuildSettingsRepository::new(conn))
+    }
+
+    #[tokio::test]
+    async fn test_guild_settings_save_and_delete() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let guild_id = 999_999_999_999;
+        let _ = repo.delete(guild_id).await;
+
+        let mut settings = GuildSettings::empty(guild_id);
+        settings.admin_role_name = Some("raid_admin".to_string());
+        match repo.save(&settings).await {
+            Ok(saved) => {
+                assert_eq!(saved.admin_role_name.as_deref(), Some("raid_admin"));
+
+                settings.admin_role_name = None;
+                settings.locale = Some("en".to_string());
+                let updated = repo.save(&settings).await.unwrap();
+                assert_eq!(updated.id, saved.id);
+                assert!(updated.admin_role_name.is_none());
+                assert_eq!(updated.locale.as_deref(), Some("en"));
+
+                assert!(repo.delete(guild_id).await.unwrap_or(false));
+                assert!(repo.get_by_guild(guild_id).await.unwrap().is_none());
+            },
+            Err(e) => {
+                println!("Save guild settings returned error (maybe expected): {}", e);
+            }
+        }
+    }
+}
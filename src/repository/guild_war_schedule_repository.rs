@@ -0,0 +1,196 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, Condition, QueryFilter, QueryOrder, DatabaseConnection, TransactionTrait};
+use sea_orm::sea_query::Expr;
+use crate::types::PoiseError;
+use crate::models::guild_war_schedule::{GuildWarNotice, GuildWarSchedule};
+use crate::models::entities::{guild_war_schedule, guild_war_schedule::Entity as GuildWarScheduleEntity};
+
+/// Values of a round of an imported schedule
+pub struct NewGuildWarSchedule {
+    pub round: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait GuildWarScheduleRepository {
+    /// Get the rounds of a guild in chronological order
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<GuildWarSchedule>, PoiseError>;
+
+    /// Replace every round of a guild with the given ones
+    async fn replace(&self, guild_id: i64, channel_id: i64, role_id: Option<i64>, created_by: i64, rounds: Vec<NewGuildWarSchedule>) -> Result<Vec<GuildWarSchedule>, PoiseError>;
+
+    /// Delete every round of a guild. Returns the number of deleted rounds
+    async fn delete_by_guild(&self, guild_id: i64) -> Result<u64, PoiseError>;
+
+    /// Get the rounds, in all guilds, whose start or end between `since` and `until` has not been announced
+    async fn get_pending(&self, since: DateTime<Utc>, until: DateTime<Utc>) -> Result<Vec<GuildWarSchedule>, PoiseError>;
+
+    /// Record that the start or end of a round has been announced.
+    /// Returns false when it had already been recorded, so that a notice is posted only once
+    async fn mark_notified(&self, id: i32, notice: GuildWarNotice, notified_at: DateTime<Utc>) -> Result<bool, PoiseError>;
+}
+
+pub struct SeaOrmGuildWarScheduleRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmGuildWarScheduleRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl GuildWarScheduleRepository for SeaOrmGuildWarScheduleRepository {
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<GuildWarSchedule>, PoiseError> {
+        let rounds = GuildWarScheduleEntity::find()
+            .filter(guild_war_schedule::Column::GuildId.eq(guild_id))
+            .order_by_asc(guild_war_schedule::Column::StartsAt)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get guild war schedule: {}", e)))?;
+
+        Ok(rounds.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn replace(&self, guild_id: i64, channel_id: i64, role_id: Option<i64>, created_by: i64, rounds: Vec<NewGuildWarSchedule>) -> Result<Vec<GuildWarSchedule>, PoiseError> {
+        let txn = self.conn.begin().await
+            .map_err(|e| PoiseError::from(format!("Failed to begin transaction: {}", e)))?;
+
+        GuildWarScheduleEntity::delete_many()
+            .filter(guild_war_schedule::Column::GuildId.eq(guild_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete guild war schedule: {}", e)))?;
+
+        let mut saved = Vec::with_capacity(rounds.len());
+        for round in rounds {
+            let model = guild_war_schedule::ActiveModel {
+                guild_id: Set(guild_id),
+                round: Set(round.round),
+                starts_at: Set(round.starts_at),
+                ends_at: Set(round.ends_at),
+                channel_id: Set(channel_id),
+                role_id: Set(role_id),
+                start_notified_at: Set(None),
+                end_notified_at: Set(None),
+                created_by: Set(created_by),
+                created_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            }
+                .insert(&txn)
+                .await
+                .map_err(|e| PoiseError::from(format!("Failed to add guild war round: {}", e)))?;
+            saved.push(model.into());
+        }
+
+        txn.commit().await
+            .map_err(|e| PoiseError::from(format!("Failed to commit transaction: {}", e)))?;
+        Ok(saved)
+    }
+
+    async fn delete_by_guild(&self, guild_id: i64) -> Result<u64, PoiseError> {
+        let result = GuildWarScheduleEntity::delete_many()
+            .filter(guild_war_schedule::Column::GuildId.eq(guild_id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete guild war schedule: {}", e)))?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn get_pending(&self, since: DateTime<Utc>, until: DateTime<Utc>) -> Result<Vec<GuildWarSchedule>, PoiseError> {
+        let rounds = GuildWarScheduleEntity::find()
+            .filter(Condition::any()
+                .add(Condition::all()
+                    .add(guild_war_schedule::Column::StartNotifiedAt.is_null())
+                    .add(guild_war_schedule::Column::StartsAt.between(since, until)))
+                .add(Condition::all()
+                    .add(guild_war_schedule::Column::EndNotifiedAt.is_null())
+                    .add(guild_war_schedule::Column::EndsAt.between(since, until))))
+            .order_by_asc(guild_war_schedule::Column::StartsAt)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get pending guild war rounds: {}", e)))?;
+
+        Ok(rounds.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn mark_notified(&self, id: i32, notice: GuildWarNotice, notified_at: DateTime<Utc>) -> Result<bool, PoiseError> {
+        let column = match notice {
+            GuildWarNotice::Start => guild_war_schedule::Column::StartNotifiedAt,
+            GuildWarNotice::End => guild_war_schedule::Column::EndNotifiedAt,
+        };
+        // 条件付きの更新にして、複数のプロセスが同じお知らせを投稿しないようにする
+        let result = GuildWarScheduleEntity::update_many()
+            .col_expr(column, Expr::value(Some(notified_at)))
+            .filter(guild_war_schedule::Column::Id.eq(id))
+            .filter(column.is_null())
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to record guild war notice: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    async fn setup_test_repo() -> Result<SeaOrmGuildWarScheduleRepository, String> {
+        if std::env::var("DATABASE_URL").is_err() {
+            return Err("DATABASE_URL not set".to_string());
+        }
+
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => return Err(format!("Failed to connect to database: {}", e)),
+        };
+
+        Ok(SeaOrmGuildWarScheduleRepository::new(conn))
+    }
+
+    #[tokio::test]
+    async fn test_guild_war_schedule_operations() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let guild_id = 999_999_999_999;
+        let now = Utc::now();
+        let round = |key: &str, starts_at: DateTime<Utc>| NewGuildWarSchedule {
+            round: key.to_string(),
+            starts_at,
+            ends_at: starts_at + Duration::hours(1),
+        };
+
+        repo.replace(guild_id, 1, None, 1, vec![round("preliminaries", now - Duration::hours(3))]).await.unwrap();
+        let saved = repo.replace(guild_id, 1, Some(2), 1, vec![
+            round("finals_1", now + Duration::hours(2)),
+            round("interlude", now - Duration::minutes(5)),
+        ]).await.unwrap();
+        assert_eq!(saved.len(), 2);
+
+        let rounds = repo.get_by_guild(guild_id).await.unwrap();
+        let keys: Vec<&str> = rounds.iter().map(|r| r.round.as_str()).collect();
+        assert_eq!(keys, ["interlude", "finals_1"], "Importing should replace the previous rounds");
+
+        let pending = repo.get_pending(now - Duration::minutes(30), now).await.unwrap();
+        let interlude = pending.iter().find(|r| r.guild_id == guild_id).unwrap();
+        assert_eq!(interlude.round, "interlude");
+        assert!(repo.mark_notified(interlude.id, GuildWarNotice::Start, now).await.unwrap());
+        assert!(!repo.mark_notified(interlude.id, GuildWarNotice::Start, now).await.unwrap(), "The same notice should be posted only once");
+        assert!(!repo.get_pending(now - Duration::minutes(30), now).await.unwrap().iter().any(|r| r.guild_id == guild_id));
+
+        assert_eq!(repo.delete_by_guild(guild_id).await.unwrap(), 2);
+        assert!(repo.get_by_guild(guild_id).await.unwrap().is_empty());
+    }
+}
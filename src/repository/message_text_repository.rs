@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use sea_orm::{EntityTrait, ColumnTrait, QueryFilter, DatabaseConnection};
+use sea_orm::{ActiveModelTrait, EntityTrait, ColumnTrait, QueryFilter, DatabaseConnection, Set};
 use crate::types::PoiseError;
 use crate::models::message_text::MessageText;
 use crate::models::entities::{message_text, message_text::Entity as MessageTextEntity};
@@ -8,6 +8,15 @@ use crate::models::entities::{message_text, message_text::Entity as MessageTextE
 pub trait MessageTextRepository {
     /// Get message text by guild ID and message ID
     async fn get_by_guild_and_message(&self, guild_id: i64, message_id: &str) -> Result<Option<MessageText>, PoiseError>;
+
+    /// Get all message texts of a guild
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<MessageText>, PoiseError>;
+
+    /// Create or update a message text of a guild
+    async fn set(&self, guild_id: i64, message_id: &str, message_jp: &str, message_en: Option<&str>) -> Result<MessageText, PoiseError>;
+
+    /// Delete a message text of a guild. Returns false when it did not exist
+    async fn delete(&self, guild_id: i64, message_id: &str) -> Result<bool, PoiseError>;
 }
 
 pub struct SeaOrmMessageTextRepository {
@@ -40,6 +49,61 @@ impl MessageTextRepository for SeaOrmMessageTextRepository {
             updated_at: mt.updated_at,
         }))
     }
+
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<MessageText>, PoiseError> {
+        let message_texts = MessageTextEntity::find()
+            .filter(message_text::Column::GuildId.eq(guild_id))
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get message texts: {}", e)))?;
+
+        Ok(message_texts.into_iter().map(|mt| mt.into()).collect())
+    }
+
+    async fn set(&self, guild_id: i64, message_id: &str, message_jp: &str, message_en: Option<&str>) -> Result<MessageText, PoiseError> {
+        let existing = MessageTextEntity::find()
+            .filter(message_text::Column::GuildId.eq(guild_id))
+            .filter(message_text::Column::MessageId.eq(message_id))
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to check the existing message text: {}", e)))?;
+
+        let result = if let Some(existing) = existing {
+            let mut active_model: message_text::ActiveModel = existing.into();
+            active_model.message_jp = Set(message_jp.to_string());
+            active_model.message_en = Set(message_en.map(|message| message.to_string()));
+            active_model.updated_at = Set(chrono::Utc::now());
+
+            active_model.update(&self.conn).await
+                .map_err(|e| PoiseError::from(format!("Failed to update message text: {}", e)))?
+        } else {
+            let new_message_text = message_text::ActiveModel {
+                guild_id: Set(guild_id),
+                message_id: Set(message_id.to_string()),
+                message_jp: Set(message_jp.to_string()),
+                message_en: Set(message_en.map(|message| message.to_string())),
+                created_at: Set(chrono::Utc::now()),
+                updated_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            };
+
+            new_message_text.insert(&self.conn).await
+                .map_err(|e| PoiseError::from(format!("Failed to create message text: {}", e)))?
+        };
+
+        Ok(result.into())
+    }
+
+    async fn delete(&self, guild_id: i64, message_id: &str) -> Result<bool, PoiseError> {
+        let result = MessageTextEntity::delete_many()
+            .filter(message_text::Column::GuildId.eq(guild_id))
+            .filter(message_text::Column::MessageId.eq(message_id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete message text: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
 
 #[cfg(test)]
@@ -86,4 +150,50 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_get_by_guild() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        match repo.get_by_guild(123456789).await {
+            Ok(message_texts) => {
+                for message_text in message_texts {
+                    assert_eq!(message_text.guild_id, 123456789, "Guild ID should match");
+                }
+            },
+            Err(e) => {
+                println!("Get message texts returned error: {}", e);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_and_delete() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let message_id = "test.message_text.set";
+        let created = repo.set(123456789, message_id, "テスト", None).await.unwrap();
+        assert_eq!(created.message_jp, "テスト");
+        assert_eq!(created.message_en, None);
+
+        let updated = repo.set(123456789, message_id, "テスト2", Some("Test")).await.unwrap();
+        assert_eq!(updated.id, created.id, "Should update the existing row");
+        assert_eq!(updated.message_en.as_deref(), Some("Test"));
+
+        assert!(repo.delete(123456789, message_id).await.unwrap());
+        assert!(!repo.delete(123456789, message_id).await.unwrap());
+        assert!(repo.get_by_guild_and_message(123456789, message_id).await.unwrap().is_none());
+    }
+}
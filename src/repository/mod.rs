@@ -2,6 +2,15 @@ pub mod battle_recruitment_repository;
 pub mod quest_repository;
 pub mod message_text_repository;
 pub mod environment_repository;
+pub mod environment_audit_repository;
+pub mod guild_settings_repository;
+pub mod guild_permission_repository;
+pub mod recruitment_rule_repository;
+pub mod recruitment_template_repository;
+pub mod recruitment_participant_repository;
+pub mod recruitment_raid_id_repository;
+pub mod calendar_feed_repository;
+pub mod guild_war_schedule_repository;
 
 use tracing::info;
 
@@ -10,12 +19,30 @@ use battle_recruitment_repository::{BattleRecruitmentRepository, SeaOrmBattleRec
 use quest_repository::{QuestRepository, SeaOrmQuestRepository};
 use message_text_repository::{MessageTextRepository, SeaOrmMessageTextRepository};
 use environment_repository::{EnvironmentRepository, SeaOrmEnvironmentRepository};
+use environment_audit_repository::{EnvironmentAuditRepository, SeaOrmEnvironmentAuditRepository};
+use guild_settings_repository::{GuildSettingsRepository, SeaOrmGuildSettingsRepository};
+use guild_permission_repository::{GuildPermissionRepository, SeaOrmGuildPermissionRepository};
+use recruitment_rule_repository::{RecruitmentRuleRepository, SeaOrmRecruitmentRuleRepository};
+use recruitment_template_repository::{RecruitmentTemplateRepository, SeaOrmRecruitmentTemplateRepository};
+use recruitment_participant_repository::{RecruitmentParticipantRepository, SeaOrmRecruitmentParticipantRepository};
+use recruitment_raid_id_repository::{RecruitmentRaidIdRepository, SeaOrmRecruitmentRaidIdRepository};
+use calendar_feed_repository::{CalendarFeedRepository, SeaOrmCalendarFeedRepository};
+use guild_war_schedule_repository::{GuildWarScheduleRepository, SeaOrmGuildWarScheduleRepository};
 
 pub struct Database {
     pub quest: Box<dyn QuestRepository + Send + Sync>,
     pub battle_recruitment: Box<dyn BattleRecruitmentRepository + Send + Sync>,
     pub message_text: Box<dyn MessageTextRepository + Send + Sync>,
     pub environment: Box<dyn EnvironmentRepository + Send + Sync>,
+    pub environment_audit: Box<dyn EnvironmentAuditRepository + Send + Sync>,
+    pub guild_settings: Box<dyn GuildSettingsRepository + Send + Sync>,
+    pub guild_permission: Box<dyn GuildPermissionRepository + Send + Sync>,
+    pub recruitment_rule: Box<dyn RecruitmentRuleRepository + Send + Sync>,
+    pub recruitment_template: Box<dyn RecruitmentTemplateRepository + Send + Sync>,
+    pub recruitment_participant: Box<dyn RecruitmentParticipantRepository + Send + Sync>,
+    pub recruitment_raid_id: Box<dyn RecruitmentRaidIdRepository + Send + Sync>,
+    pub calendar_feed: Box<dyn CalendarFeedRepository + Send + Sync>,
+    pub guild_war_schedule: Box<dyn GuildWarScheduleRepository + Send + Sync>,
 }
 
 impl Database {
@@ -33,7 +60,16 @@ impl Database {
             quest: Box::new(SeaOrmQuestRepository::new(conn.clone())),
             battle_recruitment: Box::new(SeaOrmBattleRecruitmentRepository::new(conn.clone())),
             message_text: Box::new(SeaOrmMessageTextRepository::new(conn.clone())),
-            environment: Box::new(SeaOrmEnvironmentRepository::new(conn)),
+            environment: Box::new(SeaOrmEnvironmentRepository::new(conn.clone())),
+            environment_audit: Box::new(SeaOrmEnvironmentAuditRepository::new(conn.clone())),
+            guild_settings: Box::new(SeaOrmGuildSettingsRepository::new(conn.clone())),
+            guild_permission: Box::new(SeaOrmGuildPermissionRepository::new(conn.clone())),
+            recruitment_rule: Box::new(SeaOrmRecruitmentRuleRepository::new(conn.clone())),
+            recruitment_template: Box::new(SeaOrmRecruitmentTemplateRepository::new(conn.clone())),
+            recruitment_participant: Box::new(SeaOrmRecruitmentParticipantRepository::new(conn.clone())),
+            recruitment_raid_id: Box::new(SeaOrmRecruitmentRaidIdRepository::new(conn.clone())),
+            calendar_feed: Box::new(SeaOrmCalendarFeedRepository::new(conn.clone())),
+            guild_war_schedule: Box::new(SeaOrmGuildWarScheduleRepository::new(conn)),
         })
     }
 }
@@ -1,22 +1,61 @@
+use std::collections::HashMap;
 use async_trait::async_trait;
-use sea_orm::{EntityTrait, ColumnTrait, QueryFilter, DatabaseConnection};
+use sea_orm::{ActiveModelTrait, EntityTrait, ColumnTrait, Condition, ConnectionTrait, QueryFilter, Set, DatabaseConnection, TransactionTrait};
 use crate::types::PoiseError;
 use crate::models::quest::{Quest, QuestAlias};
 use crate::models::entities::{quest, quest::Entity as QuestEntity, quest_alias, quest_alias::Entity as QuestAliasEntity};
+use crate::utils::text_normalizer::{edit_distance, normalize_alias};
 
+/// A write of a catalog import
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogWrite {
+    CreateQuest { target_id: i32, quest_name: String, default_battle_type: i32 },
+    UpdateQuest { target_id: i32, quest_name: String, default_battle_type: i32 },
+    AddAlias { target_id: i32, alias: String },
+}
+
+/// Quests and aliases are either global (`guild_id` is NULL) or belong to a single guild.
+///
+/// Read methods take the guild the request comes from and resolve guild entries first,
+/// falling back to global entries. Passing `None` reads the global catalog only.
+/// Write methods only touch the given scope (`None` is the global catalog).
 #[async_trait]
 pub trait QuestRepository {
-    /// Get all quests
-    async fn get_all(&self) -> Result<Vec<Quest>, PoiseError>;
+    /// Get all quests visible from the guild. Guild quests override global quests with the same target ID
+    async fn get_all(&self, guild_id: Option<i64>) -> Result<Vec<Quest>, PoiseError>;
     
-    /// Get all quest aliases
-    async fn get_aliases(&self) -> Result<Vec<QuestAlias>, PoiseError>;
+    /// Get all quest aliases visible from the guild. Guild aliases override global aliases with the same text
+    async fn get_aliases(&self, guild_id: Option<i64>) -> Result<Vec<QuestAlias>, PoiseError>;
     
-    /// Get quest by alias
-    async fn get_by_alias(&self, alias: &str) -> Result<Option<Quest>, PoiseError>;
+    /// Get quest by alias. Falls back to a kana- and width-insensitive match on aliases and quest names
+    async fn get_by_alias(&self, guild_id: Option<i64>, alias: &str) -> Result<Option<Quest>, PoiseError>;
+
+    /// Get quests whose aliases or names are closest to the given text, ranked by edit distance
+    async fn get_similar(&self, guild_id: Option<i64>, alias: &str, limit: usize) -> Result<Vec<Quest>, PoiseError>;
     
     /// Get quest by target ID
-    async fn get_by_target_id(&self, target_id: i32) -> Result<Option<Quest>, PoiseError>;
+    async fn get_by_target_id(&self, guild_id: Option<i64>, target_id: i32) -> Result<Option<Quest>, PoiseError>;
+
+    /// Get aliases that point to the given target ID
+    async fn get_aliases_by_target_id(&self, guild_id: Option<i64>, target_id: i32) -> Result<Vec<QuestAlias>, PoiseError>;
+
+    /// Create a new quest in the given scope
+    async fn create(&self, guild_id: Option<i64>, target_id: i32, quest_name: &str, default_battle_type: i32) -> Result<Quest, PoiseError>;
+
+    /// Update quest name and/or default battle type. Returns None when the quest does not exist in the scope
+    async fn update(&self, guild_id: Option<i64>, target_id: i32, quest_name: Option<&str>, default_battle_type: Option<i32>) -> Result<Option<Quest>, PoiseError>;
+
+    /// Delete a quest together with its aliases in the scope. Returns false when the quest does not exist
+    async fn delete(&self, guild_id: Option<i64>, target_id: i32) -> Result<bool, PoiseError>;
+
+    /// Add an alias to a quest in the given scope
+    async fn add_alias(&self, guild_id: Option<i64>, target_id: i32, alias: &str) -> Result<QuestAlias, PoiseError>;
+
+    /// Remove an alias from the scope. Returns false when the alias does not exist
+    async fn remove_alias(&self, guild_id: Option<i64>, alias: &str) -> Result<bool, PoiseError>;
+
+    /// Apply the writes of a catalog import in a single transaction, so that nothing is written when one fails
+    async fn import_catalog(&self, guild_id: Option<i64>, writes: &[CatalogWrite]) -> Result<(), PoiseError>;
 }
 
 pub struct SeaOrmQuestRepository {
@@ -27,85 +66,277 @@ impl SeaOrmQuestRepository {
     pub fn new(conn: DatabaseConnection) -> Self {
         Self { conn }
     }
+
+    /// Rows of exactly the given scope
+    fn scope_condition<C: ColumnTrait>(column: C, guild_id: Option<i64>) -> Condition {
+        match guild_id {
+            Some(guild_id) => Condition::all().add(column.eq(guild_id)),
+            None => Condition::all().add(column.is_null()),
+        }
+    }
+
+    async fn insert_quest<C: ConnectionTrait>(conn: &C, guild_id: Option<i64>, target_id: i32, quest_name: &str, default_battle_type: i32) -> Result<Quest, PoiseError> {
+        let new_quest = quest::ActiveModel {
+            guild_id: Set(guild_id),
+            target_id: Set(target_id),
+            quest_name: Set(quest_name.to_string()),
+            default_battle_type: Set(default_battle_type),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let result = new_quest.insert(conn).await
+            .map_err(|e| PoiseError::from(format!("Failed to create quest {}: {}", target_id, e)))?;
+
+        Ok(result.into())
+    }
+
+    async fn update_quest<C: ConnectionTrait>(conn: &C, guild_id: Option<i64>, target_id: i32, quest_name: Option<&str>, default_battle_type: Option<i32>) -> Result<Option<Quest>, PoiseError> {
+        let existing = QuestEntity::find()
+            .filter(quest::Column::TargetId.eq(target_id))
+            .filter(Self::scope_condition(quest::Column::GuildId, guild_id))
+            .one(conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to find quest by target_id: {}", e)))?;
+
+        let Some(existing) = existing else {
+            return Ok(None);
+        };
+
+        let mut active_model: quest::ActiveModel = existing.into();
+        if let Some(quest_name) = quest_name {
+            active_model.quest_name = Set(quest_name.to_string());
+        }
+        if let Some(default_battle_type) = default_battle_type {
+            active_model.default_battle_type = Set(default_battle_type);
+        }
+        active_model.updated_at = Set(chrono::Utc::now());
+
+        let result = active_model.update(conn).await
+            .map_err(|e| PoiseError::from(format!("Failed to update quest {}: {}", target_id, e)))?;
+
+        Ok(Some(result.into()))
+    }
+
+    async fn insert_alias<C: ConnectionTrait>(conn: &C, guild_id: Option<i64>, target_id: i32, alias: &str) -> Result<QuestAlias, PoiseError> {
+        let new_alias = quest_alias::ActiveModel {
+            guild_id: Set(guild_id),
+            target_id: Set(target_id),
+            alias: Set(alias.to_string()),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let result = new_alias.insert(conn).await
+            .map_err(|e| PoiseError::from(format!("Failed to create quest alias {}: {}", alias, e)))?;
+
+        Ok(result.into())
+    }
+
+    /// Rows visible from the given guild (the guild's own rows and global rows)
+    fn visible_condition<C: ColumnTrait>(column: C, guild_id: Option<i64>) -> Condition {
+        match guild_id {
+            Some(guild_id) => Condition::any().add(column.eq(guild_id)).add(column.is_null()),
+            None => Condition::all().add(column.is_null()),
+        }
+    }
 }
 
 #[async_trait]
 impl QuestRepository for SeaOrmQuestRepository {
-    async fn get_all(&self) -> Result<Vec<Quest>, PoiseError> {
+    async fn get_all(&self, guild_id: Option<i64>) -> Result<Vec<Quest>, PoiseError> {
         let quests = QuestEntity::find()
+            .filter(Self::visible_condition(quest::Column::GuildId, guild_id))
             .all(&self.conn)
             .await
             .map_err(|e| PoiseError::from(format!("Failed to get quests: {}", e)))?;
 
-        Ok(quests.into_iter().map(|q| Quest {
-            id: q.id,
-            target_id: q.target_id,
-            quest_name: q.quest_name,
-            default_battle_type: q.default_battle_type,
-            created_at: q.created_at,
-            updated_at: q.updated_at,
-        }).collect())
+        // Guild quests take precedence over global quests with the same target_id
+        let mut resolved: HashMap<i32, Quest> = HashMap::new();
+        for quest in quests.into_iter().map(Quest::from) {
+            if quest.guild_id.is_some() || !resolved.contains_key(&quest.target_id) {
+                resolved.insert(quest.target_id, quest);
+            }
+        }
+
+        Ok(resolved.into_values().collect())
     }
 
-    async fn get_aliases(&self) -> Result<Vec<QuestAlias>, PoiseError> {
+    async fn get_aliases(&self, guild_id: Option<i64>) -> Result<Vec<QuestAlias>, PoiseError> {
         let aliases = QuestAliasEntity::find()
+            .filter(Self::visible_condition(quest_alias::Column::GuildId, guild_id))
             .all(&self.conn)
             .await
             .map_err(|e| PoiseError::from(format!("Failed to get quest aliases: {}", e)))?;
 
-        Ok(aliases.into_iter().map(|a| QuestAlias {
-            id: a.id,
-            target_id: a.target_id,
-            alias: a.alias,
-            created_at: a.created_at,
-            updated_at: a.updated_at,
-        }).collect())
+        // Guild aliases take precedence over global aliases with the same text
+        let mut resolved: HashMap<String, QuestAlias> = HashMap::new();
+        for alias in aliases.into_iter().map(QuestAlias::from) {
+            if alias.guild_id.is_some() || !resolved.contains_key(&alias.alias) {
+                resolved.insert(alias.alias.clone(), alias);
+            }
+        }
+
+        Ok(resolved.into_values().collect())
     }
 
-    async fn get_by_alias(&self, alias: &str) -> Result<Option<Quest>, PoiseError> {
-        // First find the alias to get the target_id
-        let quest_alias = QuestAliasEntity::find()
+    async fn get_by_alias(&self, guild_id: Option<i64>, alias: &str) -> Result<Option<Quest>, PoiseError> {
+        // First find the alias to get the target_id, preferring the guild's own alias
+        let quest_aliases = QuestAliasEntity::find()
             .filter(quest_alias::Column::Alias.eq(alias))
-            .one(&self.conn)
+            .filter(Self::visible_condition(quest_alias::Column::GuildId, guild_id))
+            .all(&self.conn)
             .await
             .map_err(|e| PoiseError::from(format!("Failed to find quest alias: {}", e)))?;
 
-        if let Some(alias_record) = quest_alias {
+        let alias_record = quest_aliases.iter()
+            .find(|a| a.guild_id.is_some())
+            .or_else(|| quest_aliases.first());
+        if let Some(alias_record) = alias_record {
             // Then find the quest by target_id
-            let quest = QuestEntity::find()
-                .filter(quest::Column::TargetId.eq(alias_record.target_id))
-                .one(&self.conn)
-                .await
-                .map_err(|e| PoiseError::from(format!("Failed to find quest by target_id: {}", e)))?;
-
-            Ok(quest.map(|q| Quest {
-                id: q.id,
-                target_id: q.target_id,
-                quest_name: q.quest_name,
-                default_battle_type: q.default_battle_type,
-                created_at: q.created_at,
-                updated_at: q.updated_at,
-            }))
-        } else {
-            Ok(None)
-        }
-    }
-
-    async fn get_by_target_id(&self, target_id: i32) -> Result<Option<Quest>, PoiseError> {
-        let quest = QuestEntity::find()
+            return self.get_by_target_id(guild_id, alias_record.target_id).await;
+        }
+
+        // Fall back to comparing normalized aliases and quest names
+        let normalized = normalize_alias(alias);
+        if normalized.is_empty() {
+            return Ok(None);
+        }
+
+        let mut aliases = self.get_aliases(guild_id).await?;
+        aliases.sort_by_key(|a| a.guild_id.is_none());
+        if let Some(alias_record) = aliases.iter().find(|a| normalize_alias(&a.alias) == normalized) {
+            return self.get_by_target_id(guild_id, alias_record.target_id).await;
+        }
+
+        let mut quests = self.get_all(guild_id).await?;
+        quests.sort_by_key(|q| q.guild_id.is_none());
+        Ok(quests.into_iter().find(|q| normalize_alias(&q.quest_name) == normalized))
+    }
+
+    async fn get_similar(&self, guild_id: Option<i64>, alias: &str, limit: usize) -> Result<Vec<Quest>, PoiseError> {
+        let normalized = normalize_alias(alias);
+        if normalized.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let quests = self.get_all(guild_id).await?;
+        let aliases = self.get_aliases(guild_id).await?;
+
+        let mut ranked: Vec<(usize, Quest)> = quests.into_iter().filter_map(|quest| {
+            let distance = aliases.iter()
+                .filter(|a| a.target_id == quest.target_id)
+                .map(|a| a.alias.as_str())
+                .chain(std::iter::once(quest.quest_name.as_str()))
+                .filter_map(|candidate| {
+                    let candidate = normalize_alias(candidate);
+                    let distance = edit_distance(&normalized, &candidate);
+                    // Ignore candidates that share less than half of their characters with the input
+                    let max_distance = normalized.chars().count().max(candidate.chars().count()) / 2;
+                    (distance <= max_distance).then_some(distance)
+                })
+                .min()?;
+            Some((distance, quest))
+        }).collect();
+
+        ranked.sort_by(|(a_distance, a), (b_distance, b)| a_distance.cmp(b_distance).then_with(|| a.target_id.cmp(&b.target_id)));
+        Ok(ranked.into_iter().take(limit).map(|(_, quest)| quest).collect())
+    }
+
+    async fn get_by_target_id(&self, guild_id: Option<i64>, target_id: i32) -> Result<Option<Quest>, PoiseError> {
+        let mut quests = QuestEntity::find()
             .filter(quest::Column::TargetId.eq(target_id))
-            .one(&self.conn)
+            .filter(Self::visible_condition(quest::Column::GuildId, guild_id))
+            .all(&self.conn)
             .await
             .map_err(|e| PoiseError::from(format!("Failed to find quest by target_id: {}", e)))?;
 
-        Ok(quest.map(|q| Quest {
-            id: q.id,
-            target_id: q.target_id,
-            quest_name: q.quest_name,
-            default_battle_type: q.default_battle_type,
-            created_at: q.created_at,
-            updated_at: q.updated_at,
-        }))
+        // Prefer the guild's own quest over the global one
+        quests.sort_by_key(|q| q.guild_id.is_none());
+        Ok(quests.into_iter().next().map(Quest::from))
+    }
+
+    async fn get_aliases_by_target_id(&self, guild_id: Option<i64>, target_id: i32) -> Result<Vec<QuestAlias>, PoiseError> {
+        let mut aliases: Vec<QuestAlias> = self.get_aliases(guild_id).await?
+            .into_iter()
+            .filter(|a| a.target_id == target_id)
+            .collect();
+
+        aliases.sort_by(|a, b| a.alias.cmp(&b.alias));
+        Ok(aliases)
+    }
+
+    async fn create(&self, guild_id: Option<i64>, target_id: i32, quest_name: &str, default_battle_type: i32) -> Result<Quest, PoiseError> {
+        Self::insert_quest(&self.conn, guild_id, target_id, quest_name, default_battle_type).await
+    }
+
+    async fn update(&self, guild_id: Option<i64>, target_id: i32, quest_name: Option<&str>, default_battle_type: Option<i32>) -> Result<Option<Quest>, PoiseError> {
+        Self::update_quest(&self.conn, guild_id, target_id, quest_name, default_battle_type).await
+    }
+
+    async fn delete(&self, guild_id: Option<i64>, target_id: i32) -> Result<bool, PoiseError> {
+        let txn = self.conn.begin().await
+            .map_err(|e| PoiseError::from(format!("Failed to begin transaction: {}", e)))?;
+
+        QuestAliasEntity::delete_many()
+            .filter(quest_alias::Column::TargetId.eq(target_id))
+            .filter(Self::scope_condition(quest_alias::Column::GuildId, guild_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete quest aliases: {}", e)))?;
+
+        let result = QuestEntity::delete_many()
+            .filter(quest::Column::TargetId.eq(target_id))
+            .filter(Self::scope_condition(quest::Column::GuildId, guild_id))
+            .exec(&txn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete quest: {}", e)))?;
+
+        txn.commit().await
+            .map_err(|e| PoiseError::from(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn add_alias(&self, guild_id: Option<i64>, target_id: i32, alias: &str) -> Result<QuestAlias, PoiseError> {
+        Self::insert_alias(&self.conn, guild_id, target_id, alias).await
+    }
+
+    async fn remove_alias(&self, guild_id: Option<i64>, alias: &str) -> Result<bool, PoiseError> {
+        let result = QuestAliasEntity::delete_many()
+            .filter(quest_alias::Column::Alias.eq(alias))
+            .filter(Self::scope_condition(quest_alias::Column::GuildId, guild_id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete quest alias: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn import_catalog(&self, guild_id: Option<i64>, writes: &[CatalogWrite]) -> Result<(), PoiseError> {
+        let txn = self.conn.begin().await
+            .map_err(|e| PoiseError::from(format!("Failed to begin transaction: {}", e)))?;
+
+        // エラーの場合は txn が破棄され、ロールバックされる
+        for write in writes {
+            match write {
+                CatalogWrite::CreateQuest { target_id, quest_name, default_battle_type } => {
+                    Self::insert_quest(&txn, guild_id, *target_id, quest_name, *default_battle_type).await?;
+                },
+                CatalogWrite::UpdateQuest { target_id, quest_name, default_battle_type } => {
+                    Self::update_quest(&txn, guild_id, *target_id, Some(quest_name), Some(*default_battle_type)).await?;
+                },
+                CatalogWrite::AddAlias { target_id, alias } => {
+                    Self::insert_alias(&txn, guild_id, *target_id, alias).await?;
+                },
+            }
+        }
+
+        txn.commit().await
+            .map_err(|e| PoiseError::from(format!("Failed to commit transaction: {}", e)))?;
+        Ok(())
     }
 }
 
@@ -137,7 +368,7 @@ mod tests {
             }
         };
 
-        let result = repo.get_all().await;
+        let result = repo.get_all(None).await;
 
         // Test that the method doesn't crash and returns a result
         match result {
@@ -166,7 +397,7 @@ mod tests {
             }
         };
 
-        let result = repo.get_aliases().await;
+        let result = repo.get_aliases(None).await;
 
         match result {
             Ok(aliases) => {
@@ -193,7 +424,7 @@ mod tests {
         };
 
         // Test with a non-existent alias
-        let result = repo.get_by_alias("non_existent_alias").await;
+        let result = repo.get_by_alias(None, "non_existent_alias").await;
         match result {
             Ok(None) => {
                 // Expected result for non-existent alias
@@ -219,7 +450,7 @@ mod tests {
         };
 
         // Test with a non-existent target ID
-        let result = repo.get_by_target_id(999999).await;
+        let result = repo.get_by_target_id(None, 999999).await;
         match result {
             Ok(None) => {
                 // Expected result for non-existent target ID
@@ -234,4 +465,138 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_quest_write_operations() {
+        let repo = match setup_test_db().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let target_id = 999998;
+        let alias = "test_quest_write_operations_alias";
+
+        // Clean up leftovers from a previous run
+        let _ = repo.delete(None, target_id).await;
+
+        match repo.create(None, target_id, "テストクエスト", 1).await {
+            Ok(quest) => {
+                assert_eq!(quest.target_id, target_id);
+                assert_eq!(quest.quest_name, "テストクエスト");
+
+                let updated = repo.update(None, target_id, Some("テストクエスト改"), Some(2)).await;
+                if let Ok(Some(updated)) = updated {
+                    assert_eq!(updated.quest_name, "テストクエスト改");
+                    assert_eq!(updated.default_battle_type, 2);
+                }
+
+                if repo.add_alias(None, target_id, alias).await.is_ok() {
+                    let aliases = repo.get_aliases_by_target_id(None, target_id).await.unwrap_or_default();
+                    assert!(aliases.iter().any(|a| a.alias == alias));
+                    assert!(repo.remove_alias(None, alias).await.unwrap_or(false));
+                }
+
+                assert!(repo.delete(None, target_id).await.unwrap_or(false));
+            },
+            Err(e) => {
+                println!("Create quest returned error (maybe expected): {}", e);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_catalog_is_atomic() {
+        let repo = match setup_test_db().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let guild_id = Some(999_999_999_999);
+        let target_id = 999997;
+        let _ = repo.delete(guild_id, target_id).await;
+
+        // 2件目の追加が重複で失敗するため、1件目も保存されない
+        let create = CatalogWrite::CreateQuest { target_id, quest_name: "テストクエスト".to_string(), default_battle_type: 0 };
+        assert!(repo.import_catalog(guild_id, &[create.clone(), create.clone()]).await.is_err());
+        assert!(repo.get_by_target_id(guild_id, target_id).await.unwrap().is_none_or(|quest| quest.guild_id != guild_id));
+
+        repo.import_catalog(guild_id, &[create]).await.unwrap();
+        assert!(repo.delete(guild_id, target_id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_quest_by_normalized_alias() {
+        let repo = match setup_test_db().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let target_id = 999997;
+        let _ = repo.delete(None, target_id).await;
+
+        if repo.create(None, target_id, "テストクエストノーマライズ", 1).await.is_err() {
+            println!("Create quest returned error (maybe expected)");
+            return;
+        }
+
+        if repo.add_alias(None, target_id, "テスト別名カナ").await.is_ok() {
+            for input in ["てすと別名かな", "ﾃｽﾄ別名ｶﾅ"] {
+                let quest = repo.get_by_alias(None, input).await.ok().flatten();
+                assert_eq!(quest.map(|q| q.target_id), Some(target_id), "input: {}", input);
+            }
+
+            let similar = repo.get_similar(None, "テスト別名カ", 3).await.unwrap_or_default();
+            assert!(similar.iter().any(|q| q.target_id == target_id));
+        }
+
+        let _ = repo.delete(None, target_id).await;
+    }
+
+    #[tokio::test]
+    async fn test_guild_quest_overrides_global() {
+        let repo = match setup_test_db().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let target_id = 999996;
+        let guild_id = Some(999_999_999_999);
+        let other_guild_id = Some(888_888_888_888);
+        let alias = "test_guild_override_alias";
+        let _ = repo.delete(guild_id, target_id).await;
+        let _ = repo.delete(None, target_id).await;
+
+        if repo.create(None, target_id, "グローバルクエスト", 1).await.is_err()
+            || repo.create(guild_id, target_id, "ギルドクエスト", 2).await.is_err() {
+            println!("Create quest returned error (maybe expected)");
+            return;
+        }
+
+        let quest = repo.get_by_target_id(guild_id, target_id).await.ok().flatten();
+        assert_eq!(quest.map(|q| q.quest_name), Some("ギルドクエスト".to_string()));
+        let quest = repo.get_by_target_id(other_guild_id, target_id).await.ok().flatten();
+        assert_eq!(quest.map(|q| q.quest_name), Some("グローバルクエスト".to_string()));
+
+        if repo.add_alias(guild_id, target_id, alias).await.is_ok() {
+            assert!(repo.get_by_alias(guild_id, alias).await.ok().flatten().is_some());
+            assert!(repo.get_by_alias(other_guild_id, alias).await.ok().flatten().is_none());
+        }
+
+        assert!(repo.delete(guild_id, target_id).await.unwrap_or(false));
+        let quest = repo.get_by_target_id(guild_id, target_id).await.ok().flatten();
+        assert_eq!(quest.map(|q| q.quest_name), Some("グローバルクエスト".to_string()));
+        let _ = repo.delete(None, target_id).await;
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, QueryFilter, DatabaseConnection, TransactionTrait};
+use crate::types::PoiseError;
+use crate::models::recruitment_participant::RecruitmentParticipant;
+use crate::models::entities::{recruitment_participant, recruitment_participant::Entity as RecruitmentParticipantEntity};
+
+#[async_trait]
+pub trait RecruitmentParticipantRepository {
+    /// Replace the participants of a recruitment with the given (user ID, element ID) pairs.
+    /// Participants who stay keep their join time
+    async fn replace(&self, recruitment_id: i32, guild_id: i64, participants: &[(i64, i32)]) -> Result<(), PoiseError>;
+
+    /// Get every recruitment a member joined in a guild
+    async fn get_by_user(&self, guild_id: i64, user_id: i64) -> Result<Vec<RecruitmentParticipant>, PoiseError>;
+}
+
+pub struct SeaOrmRecruitmentParticipantRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmRecruitmentParticipantRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl RecruitmentParticipantRepository for SeaOrmRecruitmentParticipantRepository {
+    async fn replace(&self, recruitment_id: i32, guild_id: i64, participants: &[(i64, i32)]) -> Result<(), PoiseError> {
+        let txn = self.conn.begin().await
+            .map_err(|e| PoiseError::from(format!("Failed to begin transaction: {}", e)))?;
+
+        let existing = RecruitmentParticipantEntity::find()
+            .filter(recruitment_participant::Column::RecruitmentId.eq(recruitment_id))
+            .all(&txn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get participants: {}", e)))?;
+
+        let wanted: HashSet<(i64, i32)> = participants.iter().copied().collect();
+        let left: Vec<i32> = existing.iter()
+            .filter(|p| !wanted.contains(&(p.user_id, p.element_id)))
+            .map(|p| p.id)
+            .collect();
+        if !left.is_empty() {
+            RecruitmentParticipantEntity::delete_many()
+                .filter(recruitment_participant::Column::Id.is_in(left))
+                .exec(&txn)
+                .await
+                .map_err(|e| PoiseError::from(format!("Failed to delete participants: {}", e)))?;
+        }
+
+        let current: HashSet<(i64, i32)> = existing.iter().map(|p| (p.user_id, p.element_id)).collect();
+        for (user_id, element_id) in wanted.difference(&current) {
+            recruitment_participant::ActiveModel {
+                recruitment_id: Set(recruitment_id),
+                guild_id: Set(guild_id),
+                user_id: Set(*user_id),
+                element_id: Set(*element_id),
+                joined_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            }
+                .insert(&txn)
+                .await
+                .map_err(|e| PoiseError::from(format!("Failed to add participant: {}", e)))?;
+        }
+
+        txn.commit().await
+            .map_err(|e| PoiseError::from(format!("Failed to commit transaction: {}", e)))?;
+        Ok(())
+    }
+
+    async fn get_by_user(&self, guild_id: i64, user_id: i64) -> Result<Vec<RecruitmentParticipant>, PoiseError> {
+        let participants = RecruitmentParticipantEntity::find()
+            .filter(recruitment_participant::Column::GuildId.eq(guild_id))
+            .filter(recruitment_participant::Column::UserId.eq(user_id))
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get joined recruitments: {}", e)))?;
+
+        Ok(participants.into_iter().map(|p| p.into()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::battle_recruitment_repository::{BattleRecruitmentRepository, NewBattleRecruitment, SeaOrmBattleRecruitmentRepository};
+
+    #[tokio::test]
+    async fn test_replace_participants() {
+        if std::env::var("DATABASE_URL").is_err() {
+            println!("Skipping database test: DATABASE_URL not set");
+            return;
+        }
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => {
+                println!("Skipping database test: Failed to connect to database: {}", e);
+                return;
+            }
+        };
+        let recruitments = SeaOrmBattleRecruitmentRepository::new(conn.clone());
+        let repo = SeaOrmRecruitmentParticipantRepository::new(conn);
+
+        let guild_id = 999_999_999_997;
+        let recruitment = recruitments.create(NewBattleRecruitment {
+            guild_id,
+            channel_id: 1,
+            message_id: 555666779,
+            target_id: 1,
+            battle_type_id: 1,
+            expiry_date: chrono::Utc::now() + chrono::Duration::hours(1),
+            capacity: 6,
+            notes: None,
+            created_by: None,
+            thread_id: None,
+            scheduled_event_id: None,
+        }).await.unwrap();
+
+        repo.replace(recruitment.id, guild_id, &[(1, 2), (2, 3)]).await.unwrap();
+        let first = repo.get_by_user(guild_id, 1).await.unwrap();
+        let first = first.iter().find(|p| p.recruitment_id == recruitment.id && p.element_id == 2).unwrap();
+        assert!(repo.get_by_user(guild_id, 2).await.unwrap().iter().any(|p| p.recruitment_id == recruitment.id));
+
+        // 残った参加者は参加日時を保つ
+        repo.replace(recruitment.id, guild_id, &[(1, 2), (1, 6)]).await.unwrap();
+        let second: Vec<_> = repo.get_by_user(guild_id, 1).await.unwrap().into_iter()
+            .filter(|p| p.recruitment_id == recruitment.id)
+            .collect();
+        assert_eq!(second.len(), 2);
+        assert_eq!(second.iter().find(|p| p.element_id == 2).unwrap().id, first.id);
+        assert!(repo.get_by_user(guild_id, 2).await.unwrap().iter().all(|p| p.recruitment_id != recruitment.id));
+    }
+}
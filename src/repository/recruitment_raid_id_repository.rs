@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, QueryFilter, QueryOrder, DatabaseConnection};
+use crate::types::PoiseError;
+use crate::models::recruitment_raid_id::RecruitmentRaidId;
+use crate::models::entities::{recruitment_raid_id, recruitment_raid_id::Entity as RecruitmentRaidIdEntity};
+
+/// Values of a battle ID shared with a recruitment
+pub struct NewRecruitmentRaidId {
+    pub recruitment_id: i32,
+    pub guild_id: i64,
+    pub raid_id: String,
+    pub posted_by: i64,
+}
+
+#[async_trait]
+pub trait RecruitmentRaidIdRepository {
+    /// Record a battle ID shared with a recruitment
+    async fn create(&self, raid_id: NewRecruitmentRaidId) -> Result<RecruitmentRaidId, PoiseError>;
+
+    /// Get the battle ID shared last with a recruitment
+    async fn get_latest(&self, recruitment_id: i32) -> Result<Option<RecruitmentRaidId>, PoiseError>;
+}
+
+pub struct SeaOrmRecruitmentRaidIdRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmRecruitmentRaidIdRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl RecruitmentRaidIdRepository for SeaOrmRecruitmentRaidIdRepository {
+    async fn create(&self, raid_id: NewRecruitmentRaidId) -> Result<RecruitmentRaidId, PoiseError> {
+        let model = recruitment_raid_id::ActiveModel {
+            recruitment_id: Set(raid_id.recruitment_id),
+            guild_id: Set(raid_id.guild_id),
+            raid_id: Set(raid_id.raid_id),
+            posted_by: Set(raid_id.posted_by),
+            posted_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+            .insert(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to record raid ID: {}", e)))?;
+
+        Ok(model.into())
+    }
+
+    async fn get_latest(&self, recruitment_id: i32) -> Result<Option<RecruitmentRaidId>, PoiseError> {
+        let raid_id = RecruitmentRaidIdEntity::find()
+            .filter(recruitment_raid_id::Column::RecruitmentId.eq(recruitment_id))
+            .order_by_desc(recruitment_raid_id::Column::PostedAt)
+            .order_by_desc(recruitment_raid_id::Column::Id)
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get raid ID: {}", e)))?;
+
+        Ok(raid_id.map(|model| model.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::battle_recruitment_repository::{BattleRecruitmentRepository, NewBattleRecruitment, SeaOrmBattleRecruitmentRepository};
+
+    #[tokio::test]
+    async fn test_raid_ids() {
+        if std::env::var("DATABASE_URL").is_err() {
+            println!("Skipping database test: DATABASE_URL not set");
+            return;
+        }
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => {
+                println!("Skipping database test: Failed to connect to database: {}", e);
+                return;
+            }
+        };
+        let recruitments = SeaOrmBattleRecruitmentRepository::new(conn.clone());
+        let repo = SeaOrmRecruitmentRaidIdRepository::new(conn);
+
+        let guild_id = 999_999_999_997;
+        let recruitment = recruitments.create(NewBattleRecruitment {
+            guild_id,
+            channel_id: 1,
+            message_id: 555666780,
+            target_id: 1,
+            battle_type_id: 1,
+            expiry_date: chrono::Utc::now() + chrono::Duration::hours(1),
+            capacity: 6,
+            notes: None,
+            created_by: Some(1),
+            thread_id: None,
+            scheduled_event_id: None,
+        }).await.unwrap();
+        assert!(repo.get_latest(recruitment.id).await.unwrap().is_none());
+
+        for raid_id in ["A1B2C3D4", "0F1E2D3C"] {
+            repo.create(NewRecruitmentRaidId {
+                recruitment_id: recruitment.id,
+                guild_id,
+                raid_id: raid_id.to_string(),
+                posted_by: 1,
+            }).await.unwrap();
+        }
+        assert_eq!(repo.get_latest(recruitment.id).await.unwrap().unwrap().raid_id, "0F1E2D3C");
+    }
+}
@@ -0,0 +1,192 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, Condition, QueryFilter, QueryOrder, DatabaseConnection};
+use sea_orm::sea_query::Expr;
+use crate::types::PoiseError;
+use crate::models::recruitment_rule::RecruitmentRule;
+use crate::models::entities::{recruitment_rule, recruitment_rule::Entity as RecruitmentRuleEntity};
+
+/// Values of a new recruitment rule
+pub struct NewRecruitmentRule {
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub quest_alias: String,
+    pub battle_type_id: i32,
+    pub weekday: i16,
+    pub start_time: String,
+    pub lead_time_minutes: i32,
+    pub created_by: i64,
+}
+
+#[async_trait]
+pub trait RecruitmentRuleRepository {
+    /// Get all rules of a guild
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<RecruitmentRule>, PoiseError>;
+
+    /// Get the rules that are not paused, in all guilds
+    async fn get_active(&self) -> Result<Vec<RecruitmentRule>, PoiseError>;
+
+    /// Create a rule
+    async fn create(&self, rule: NewRecruitmentRule) -> Result<RecruitmentRule, PoiseError>;
+
+    /// Pause or resume a rule of a guild. Returns false when the rule does not exist
+    async fn set_paused(&self, guild_id: i64, id: i32, paused: bool) -> Result<bool, PoiseError>;
+
+    /// Delete a rule of a guild. Returns false when the rule does not exist
+    async fn delete(&self, guild_id: i64, id: i32) -> Result<bool, PoiseError>;
+
+    /// Record that the event has been posted.
+    /// Returns false when it had already been recorded, so that an event is posted only once
+    async fn mark_posted(&self, id: i32, event_time: DateTime<Utc>) -> Result<bool, PoiseError>;
+}
+
+pub struct SeaOrmRecruitmentRuleRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmRecruitmentRuleRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl RecruitmentRuleRepository for SeaOrmRecruitmentRuleRepository {
+    async fn get_by_guild(&self, guild_id: i64) -> Result<Vec<RecruitmentRule>, PoiseError> {
+        let rules = RecruitmentRuleEntity::find()
+            .filter(recruitment_rule::Column::GuildId.eq(guild_id))
+            .order_by_asc(recruitment_rule::Column::Id)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get recruitment rules: {}", e)))?;
+
+        Ok(rules.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn get_active(&self) -> Result<Vec<RecruitmentRule>, PoiseError> {
+        let rules = RecruitmentRuleEntity::find()
+            .filter(recruitment_rule::Column::Paused.eq(false))
+            .order_by_asc(recruitment_rule::Column::Id)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get active recruitment rules: {}", e)))?;
+
+        Ok(rules.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn create(&self, rule: NewRecruitmentRule) -> Result<RecruitmentRule, PoiseError> {
+        let new_rule = recruitment_rule::ActiveModel {
+            guild_id: Set(rule.guild_id),
+            channel_id: Set(rule.channel_id),
+            quest_alias: Set(rule.quest_alias),
+            battle_type_id: Set(rule.battle_type_id),
+            weekday: Set(rule.weekday),
+            start_time: Set(rule.start_time),
+            lead_time_minutes: Set(rule.lead_time_minutes),
+            paused: Set(false),
+            last_posted_for: Set(None),
+            created_by: Set(rule.created_by),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        };
+
+        let result = new_rule.insert(&self.conn).await
+            .map_err(|e| PoiseError::from(format!("Failed to create recruitment rule: {}", e)))?;
+
+        Ok(result.into())
+    }
+
+    async fn set_paused(&self, guild_id: i64, id: i32, paused: bool) -> Result<bool, PoiseError> {
+        let result = RecruitmentRuleEntity::update_many()
+            .col_expr(recruitment_rule::Column::Paused, Expr::value(paused))
+            .col_expr(recruitment_rule::Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+            .filter(recruitment_rule::Column::GuildId.eq(guild_id))
+            .filter(recruitment_rule::Column::Id.eq(id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to update recruitment rule: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn delete(&self, guild_id: i64, id: i32) -> Result<bool, PoiseError> {
+        let result = RecruitmentRuleEntity::delete_many()
+            .filter(recruitment_rule::Column::GuildId.eq(guild_id))
+            .filter(recruitment_rule::Column::Id.eq(id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete recruitment rule: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn mark_posted(&self, id: i32, event_time: DateTime<Utc>) -> Result<bool, PoiseError> {
+        // 条件付きの更新にして、複数のプロセスが同じ回を投稿しないようにする
+        let result = RecruitmentRuleEntity::update_many()
+            .col_expr(recruitment_rule::Column::LastPostedFor, Expr::value(Some(event_time)))
+            .col_expr(recruitment_rule::Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+            .filter(recruitment_rule::Column::Id.eq(id))
+            .filter(Condition::any()
+                .add(recruitment_rule::Column::LastPostedFor.is_null())
+                .add(recruitment_rule::Column::LastPostedFor.ne(event_time)))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to mark recruitment rule as posted: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_test_repo() -> Result<SeaOrmRecruitmentRuleRepository, String> {
+        if std::env::var("DATABASE_URL").is_err() {
+            return Err("DATABASE_URL not set".to_string());
+        }
+
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => return Err(format!("Failed to connect to database: {}", e)),
+        };
+
+        Ok(SeaOrmRecruitmentRuleRepository::new(conn))
+    }
+
+    #[tokio::test]
+    async fn test_recruitment_rule_operations() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let guild_id = 999_999_999_999;
+        let rule = repo.create(NewRecruitmentRule {
+            guild_id,
+            channel_id: 1,
+            quest_alias: "ルシHL".to_string(),
+            battle_type_id: 0,
+            weekday: 5,
+            start_time: "21:00".to_string(),
+            lead_time_minutes: 60,
+            created_by: 1,
+        }).await.unwrap();
+        assert!(!rule.paused);
+
+        let event_time = Utc::now();
+        assert!(repo.mark_posted(rule.id, event_time).await.unwrap());
+        assert!(!repo.mark_posted(rule.id, event_time).await.unwrap(), "The same event should be posted only once");
+
+        assert!(repo.set_paused(guild_id, rule.id, true).await.unwrap());
+        assert!(!repo.get_active().await.unwrap().iter().any(|r| r.id == rule.id));
+        assert!(!repo.set_paused(guild_id + 1, rule.id, false).await.unwrap(), "Other guilds' rules should not change");
+
+        assert!(repo.delete(guild_id, rule.id).await.unwrap());
+        assert!(!repo.get_by_guild(guild_id).await.unwrap().iter().any(|r| r.id == rule.id));
+    }
+}
@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Set, EntityTrait, ColumnTrait, Condition, QueryFilter, QueryOrder, DatabaseConnection};
+use sea_orm::sea_query::Expr;
+use crate::types::PoiseError;
+use crate::models::recruitment_template::RecruitmentTemplate;
+use crate::models::entities::{recruitment_template, recruitment_template::Entity as RecruitmentTemplateEntity};
+
+/// Values of a recruitment template to save
+pub struct NewRecruitmentTemplate {
+    pub guild_id: i64,
+    pub owner_id: i64,
+    pub name: String,
+    pub quest_alias: String,
+    pub battle_type_id: i32,
+    pub capacity: i32,
+    pub notes: Option<String>,
+    pub channel_id: Option<i64>,
+}
+
+#[async_trait]
+pub trait RecruitmentTemplateRepository {
+    /// Get the templates a member can use: their own and the published ones
+    async fn get_visible(&self, guild_id: i64, user_id: i64) -> Result<Vec<RecruitmentTemplate>, PoiseError>;
+
+    /// Save a template of a member, replacing the one with the same name
+    async fn save(&self, template: NewRecruitmentTemplate) -> Result<RecruitmentTemplate, PoiseError>;
+
+    /// Publish a template to the guild or make it private again
+    async fn set_public(&self, id: i32, is_public: bool) -> Result<(), PoiseError>;
+
+    /// Delete a template of a member. Returns false when it does not exist
+    async fn delete(&self, guild_id: i64, owner_id: i64, name: &str) -> Result<bool, PoiseError>;
+}
+
+pub struct SeaOrmRecruitmentTemplateRepository {
+    conn: DatabaseConnection,
+}
+
+impl SeaOrmRecruitmentTemplateRepository {
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl RecruitmentTemplateRepository for SeaOrmRecruitmentTemplateRepository {
+    async fn get_visible(&self, guild_id: i64, user_id: i64) -> Result<Vec<RecruitmentTemplate>, PoiseError> {
+        let templates = RecruitmentTemplateEntity::find()
+            .filter(recruitment_template::Column::GuildId.eq(guild_id))
+            .filter(Condition::any()
+                .add(recruitment_template::Column::OwnerId.eq(user_id))
+                .add(recruitment_template::Column::IsPublic.eq(true)))
+            .order_by_asc(recruitment_template::Column::Name)
+            .all(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to get recruitment templates: {}", e)))?;
+
+        Ok(templates.into_iter().map(|t| t.into()).collect())
+    }
+
+    async fn save(&self, template: NewRecruitmentTemplate) -> Result<RecruitmentTemplate, PoiseError> {
+        let existing = RecruitmentTemplateEntity::find()
+            .filter(recruitment_template::Column::GuildId.eq(template.guild_id))
+            .filter(recruitment_template::Column::OwnerId.eq(template.owner_id))
+            .filter(recruitment_template::Column::Name.eq(template.name.as_str()))
+            .one(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to check the existing recruitment template: {}", e)))?;
+
+        let is_update = existing.is_some();
+        let mut active_model: recruitment_template::ActiveModel = match existing {
+            Some(existing) => existing.into(),
+            None => recruitment_template::ActiveModel {
+                guild_id: Set(template.guild_id),
+                owner_id: Set(template.owner_id),
+                name: Set(template.name),
+                is_public: Set(false),
+                created_at: Set(chrono::Utc::now()),
+                ..Default::default()
+            },
+        };
+        active_model.quest_alias = Set(template.quest_alias);
+        active_model.battle_type_id = Set(template.battle_type_id);
+        active_model.capacity = Set(template.capacity);
+        active_model.notes = Set(template.notes);
+        active_model.channel_id = Set(template.channel_id);
+        active_model.updated_at = Set(chrono::Utc::now());
+
+        let result = if is_update {
+            active_model.update(&self.conn).await
+        } else {
+            active_model.insert(&self.conn).await
+        };
+        let model = result
+            .map_err(|e| PoiseError::from(format!("Failed to save recruitment template: {}", e)))?;
+
+        Ok(model.into())
+    }
+
+    async fn set_public(&self, id: i32, is_public: bool) -> Result<(), PoiseError> {
+        RecruitmentTemplateEntity::update_many()
+            .col_expr(recruitment_template::Column::IsPublic, Expr::value(is_public))
+            .col_expr(recruitment_template::Column::UpdatedAt, Expr::value(chrono::Utc::now()))
+            .filter(recruitment_template::Column::Id.eq(id))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to update recruitment template: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, guild_id: i64, owner_id: i64, name: &str) -> Result<bool, PoiseError> {
+        let result = RecruitmentTemplateEntity::delete_many()
+            .filter(recruitment_template::Column::GuildId.eq(guild_id))
+            .filter(recruitment_template::Column::OwnerId.eq(owner_id))
+            .filter(recruitment_template::Column::Name.eq(name))
+            .exec(&self.conn)
+            .await
+            .map_err(|e| PoiseError::from(format!("Failed to delete recruitment template: {}", e)))?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_test_repo() -> Result<SeaOrmRecruitmentTemplateRepository, String> {
+        if std::env::var("DATABASE_URL").is_err() {
+            return Err("DATABASE_URL not set".to_string());
+        }
+
+        let conn = match crate::models::database::Database::new().await {
+            Ok(db) => db.conn,
+            Err(e) => return Err(format!("Failed to connect to database: {}", e)),
+        };
+
+        Ok(SeaOrmRecruitmentTemplateRepository::new(conn))
+    }
+
+    fn new_template(owner_id: i64, capacity: i32) -> NewRecruitmentTemplate {
+        NewRecruitmentTemplate {
+            guild_id: 999_999_999_998,
+            owner_id,
+            name: "土曜ルシ".to_string(),
+            quest_alias: "ルシHL".to_string(),
+            battle_type_id: 0,
+            capacity,
+            notes: Some("フルオート可".to_string()),
+            channel_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recruitment_template_operations() {
+        let repo = match setup_test_repo().await {
+            Ok(repo) => repo,
+            Err(e) => {
+                println!("Skipping database test: {}", e);
+                return;
+            }
+        };
+
+        let guild_id = 999_999_999_998;
+        let template = repo.save(new_template(1, 6)).await.unwrap();
+        let saved_again = repo.save(new_template(1, 30)).await.unwrap();
+        assert_eq!(saved_again.id, template.id, "Saving the same name should replace the template");
+        assert_eq!(saved_again.capacity, 30);
+
+        assert!(!repo.get_visible(guild_id, 2).await.unwrap().iter().any(|t| t.id == template.id));
+        repo.set_public(template.id, true).await.unwrap();
+        assert!(repo.get_visible(guild_id, 2).await.unwrap().iter().any(|t| t.id == template.id), "Published templates should be visible to everyone");
+
+        assert!(!repo.delete(guild_id, 2, "土曜ルシ").await.unwrap(), "Only the owner should delete a template");
+        assert!(repo.delete(guild_id, 1, "土曜ルシ").await.unwrap());
+    }
+}
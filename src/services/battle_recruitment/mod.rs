@@ -4,4 +4,48 @@ pub mod reaction_handler;
 mod participants;
 mod recruit_message;
 mod message;
-mod params;
+pub(crate) mod params;
+pub mod template;
+
+use poise::serenity_prelude::all::{ChannelId, Context, CreateEmbed, CreateMessage, Message, MessageId};
+use tracing::warn;
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::services::message::Messages;
+use crate::utils::discord_helper::reply_to_message;
+
+/// 募集の定員（クエストごとの定員は未対応）
+pub const DEFAULT_CAPACITY: usize = 6;
+
+/// 募集人数の上限
+pub const MAX_CAPACITY: usize = 30;
+
+/// 募集メッセージに添える補足の上限
+pub const MAX_NOTES_LENGTH: usize = 200;
+
+/// 募集スレッドの名前の上限（Discordの制限は100文字）
+pub const MAX_THREAD_NAME_LENGTH: usize = 100;
+
+/// Discordのイベントやカレンダーに登録する募集の長さ（分）
+pub const EVENT_DURATION_MINUTES: i64 = 60;
+
+/// 参加者の埋め込みに募集者を表示する（募集者の記録がない募集はそのまま）
+pub(crate) fn with_creator(embed: CreateEmbed, messages: &Messages, created_by: Option<i64>) -> CreateEmbed {
+    match created_by {
+        Some(user_id) => embed.field(messages.get("battle_recruitment.creator"), format!("<@{}>", user_id), true),
+        None => embed,
+    }
+}
+
+/// 募集への通知（リマインド、開始の呼び出し、変更など）を送信する
+/// 募集スレッドがある場合はスレッドに、ない場合や送信できない場合は募集メッセージへの返信で送る
+pub(crate) async fn notify(ctx: &Context, recruitment: &BattleRecruitment, content: String) -> Result<Message, String> {
+    if let Some(thread_id) = recruitment.thread_id {
+        match ChannelId::new(thread_id as u64).send_message(&ctx.http, CreateMessage::new().content(content.clone())).await {
+            Ok(message) => return Ok(message),
+            // スレッドが削除された場合など
+            Err(e) => warn!("Failed to post to the recruitment thread {}: {:?}", thread_id, e),
+        }
+    }
+
+    reply_to_message(ctx, ChannelId::new(recruitment.channel_id as u64), MessageId::new(recruitment.message_id as u64), content).await
+}
@@ -1,4 +1,22 @@
+use chrono::{DateTime, FixedOffset};
 use crate::models::quest::Quest;
+use crate::types::BattleType;
+
+/// 募集作成の入力（クエストは別名で指定する）
+pub(crate) struct CreateParameter<'a> {
+    pub guild_id: u64,
+    pub channel_id: u64,
+    pub quest_alias: &'a str,
+    pub battle_type: BattleType,
+    /// 省略時は7日後
+    pub event_date: Option<DateTime<FixedOffset>>,
+    /// 募集人数（この人数が集まると完了）
+    pub capacity: usize,
+    /// 募集メッセージに添える補足
+    pub notes: Option<&'a str>,
+    /// 募集者（定期募集はルールを作成したメンバー）
+    pub created_by: u64,
+}
 
 pub(crate) struct NewParameter {
     pub guild_id: i64,
@@ -8,6 +26,13 @@ pub(crate) struct NewParameter {
     pub target_id: i32,
     pub battle_type_id: i32,
     pub expiry_date: chrono::DateTime<chrono::Utc>,
+    pub capacity: usize,
+    pub notes: Option<String>,
+    pub created_by: i64,
+    /// 募集メッセージに作成したスレッド
+    pub thread_id: Option<i64>,
+    /// 募集を登録したDiscordのイベント
+    pub scheduled_event_id: Option<i64>,
 }
 
 pub(crate) struct UpdateParameter {
@@ -20,6 +45,21 @@ pub(crate) struct UpdateParameter {
     pub expiry_date: chrono::DateTime<chrono::Utc>,
 }
 
+/// 募集の変更内容（指定しなかった項目は変更しない）
+pub(crate) struct EditParameter<'a> {
+    pub guild_id: u64,
+    pub channel_id: u64,
+    pub message_id: u64,
+    pub event_date: Option<DateTime<FixedOffset>>,
+    pub battle_type: Option<BattleType>,
+    pub capacity: Option<usize>,
+    /// [`CLEAR_NOTES`] を指定すると補足を削除する
+    pub notes: Option<&'a str>,
+}
+
+/// 補足を削除する時に指定する値
+pub(crate) const CLEAR_NOTES: &str = "-";
+
 pub(crate) struct PaticipantsParameter {
     pub guild_id: i64,
     pub channel_id: i64,
@@ -5,6 +5,9 @@ use tracing::{error, info};
 use crate::utils::discord_helper::{get_reaction_users, update_embed_with_participants, get_unique_reaction_users};
 use crate::repository::Database;
 use crate::models::battle_recruitment::BattleRecruitment;
+use crate::services::message::Messages;
+use crate::services::battle_recruitment::template::{self, RecruitmentVariables};
+use crate::services::settings::SettingsService;
 
 pub struct ReactionHandler {
     db: Arc<Database>,
@@ -37,6 +40,11 @@ impl ReactionHandler {
                 return Err(format!("Database error: {}", e));
             }
         };
+
+        // A cancelled recruitment keeps its cancelled message
+        if recruitment.cancelled_at.is_some() {
+            return Ok(());
+        }
         
         // Get the message
         let message = match channel_id.message(&ctx.http, message_id).await {
@@ -72,7 +80,7 @@ impl ReactionHandler {
         reactions: std::collections::HashMap<String, Vec<User>>,
     ) -> Result<(), String> {
         // Get the quest
-        let quest = match self.db.quest.get_by_target_id(recruitment.target_id).await {
+        let quest = match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
             Ok(Some(quest)) => quest,
             Ok(None) => {
                 error!("Quest not found for target_id: {}", recruitment.target_id);
@@ -87,32 +95,20 @@ impl ReactionHandler {
         // Count unique users across all reactions
         let unique_users = get_unique_reaction_users(ctx, message).await?;
         
-        // Check if recruitment is complete (assuming recruit_count is 6 for now)
-        // In a real implementation, you'd get this from the quest data
-        let recruit_count = 6; // Default value
-        
-        if unique_users.len() >= recruit_count as usize {
-            // Get the completion message text
-            let message_text = match self.db.message_text.get_by_guild_and_message(
-                recruitment.guild_id,
-                "MSG00032",
-            ).await {
-                Ok(Some(msg)) => msg.message_jp,
-                Ok(None) => "募集が完了しました！".to_string(),
-                Err(e) => {
-                    error!("Error fetching message text: {:?}", e);
-                    "募集が完了しました！".to_string()
-                }
-            };
+        // Check if recruitment is complete
+        if unique_users.len() >= recruitment.capacity as usize {
+            // Render the completion template (MSG00032 in message_texts)
+            let messages = Messages::for_guild(&self.db, recruitment.guild_id).await;
             
             // Create mentions for all participants
             let mentions = unique_users.iter()
                 .map(|id| format!("<@{}>", id))
-                .collect::<Vec<_>>()
-                .join(" ");
+                .collect::<Vec<_>>();
             
             // Send completion message
-            let content = format!("{}\n{}", mentions, message_text);
+            let timezone = SettingsService::new(self.db.clone()).timezone(recruitment.guild_id).await;
+            let content = RecruitmentVariables::for_recruitment(recruitment, &quest.quest_name, &mentions, timezone)
+                .render(&messages, template::COMPLETED);
             let reply = match message.channel_id.say(&ctx.http, content).await {
                 Ok(msg) => msg,
                 Err(e) => {
@@ -1,74 +1,89 @@
-use tracing::{info, warn};
+use std::sync::Arc;
+use chrono::Utc;
+use poise::serenity_prelude::all::{Context, Message, ChannelId, MessageId, EditMessage, CreateEmbed};
+use tracing::{error, info};
 
-/// CancelRecruitmentService - 募集キャンセル処理を行うサービス
-/// 現在は仕様検討中のため、警告表示と正常終了パターンをエミュレートします
-pub struct CancelRecruitmentService;
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::services::battle_recruitment::{notify, with_creator};
+use crate::services::battle_recruitment::template::{self, RecruitmentVariables};
+use crate::services::message::Messages;
+use crate::utils::discord_helper::get_participant_mentions;
+
+/// CancelRecruitmentService - 開始前の募集をキャンセルし、参加者に知らせるサービス
+/// エラーは `messages`（サーバーの設定言語）で返す
+pub struct CancelRecruitmentService {
+    db: Arc<Database>,
+}
 
 impl CancelRecruitmentService {
-    pub fn new() -> Self {
-        Self
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
     }
 
-    /// DBから募集情報を取得
-    pub async fn get_recruitment_from_db(&self, guild_id: u64, channel_id: u64, message_id: u64) -> Result<(), String> {
-        warn!("CancelRecruitmentService::get_recruitment_from_db - 仕様検討中です");
-        info!("DB募集情報取得をエミュレート: guild_id={}, channel_id={}, message_id={}", 
-              guild_id, channel_id, message_id);
-        Ok(())
+    /// 募集のクエスト名（クエストが削除されている場合は空）
+    pub async fn get_quest_name(&self, recruitment: &BattleRecruitment) -> String {
+        match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
+            Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+            Err(e) => {
+                error!("Error fetching quest: {:?}", e);
+                String::new()
+            }
+        }
     }
 
-    /// リアクションから参加者一覧取得
-    pub async fn get_participants_from_reactions(&self, message_id: u64) -> Result<Vec<String>, String> {
-        warn!("CancelRecruitmentService::get_participants_from_reactions - 仕様検討中です");
-        info!("リアクション参加者取得をエミュレート: message_id={}", message_id);
-        
-        // エミュレート用の参加者リスト
-        let mock_participants = vec![
-            "<@123456789>".to_string(),
-            "<@987654321>".to_string(),
-        ];
-        Ok(mock_participants)
+    /// リアクションから参加者一覧取得（メンション）
+    pub async fn get_participants_from_reactions(&self, ctx: &Context, recruitment: &BattleRecruitment) -> Result<Vec<String>, String> {
+        get_participant_mentions(ctx, ChannelId::new(recruitment.channel_id as u64), MessageId::new(recruitment.message_id as u64)).await
     }
 
-    /// 募集メッセージをキャンセル済みメッセージに変えるためのメッセージ作成
-    pub async fn create_cancelled_message(&self, original_content: &str) -> Result<String, String> {
-        warn!("CancelRecruitmentService::create_cancelled_message - 仕様検討中です");
-        info!("キャンセル済みメッセージ作成をエミュレート");
-        
-        let cancelled_message = format!("【キャンセル済み】\n{}\n\nこの募集はキャンセルされました。", original_content);
-        Ok(cancelled_message)
+    /// 募集をキャンセル済み状態に更新
+    /// 先に開始またはキャンセルされていた場合は false
+    pub async fn mark_recruitment_as_cancelled(&self, messages: &Messages, recruitment_id: i32) -> Result<bool, String> {
+        match self.db.battle_recruitment.mark_cancelled(recruitment_id, Utc::now()).await {
+            Ok(cancelled) => {
+                if cancelled {
+                    info!("Recruitment cancelled: id={}", recruitment_id);
+                }
+                Ok(cancelled)
+            },
+            Err(e) => {
+                error!("Error marking recruitment as cancelled: {:?}", e);
+                Err(messages.get("battle_recruitment.database_error"))
+            }
+        }
     }
 
-    /// キャンセル通知メッセージ作成（参加者にメンションを含む）
-    pub async fn create_cancel_notification(&self, participants: &[String]) -> Result<String, String> {
-        warn!("CancelRecruitmentService::create_cancel_notification - 仕様検討中です");
-        info!("キャンセル通知メッセージ作成をエミュレート");
-        
-        let participant_mentions = if participants.is_empty() {
-            "参加者はいませんでした".to_string()
-        } else {
-            participants.join(" ")
-        };
-        
-        let notification = format!(
-            "この募集はキャンセルされました。\n参加予定だった方: {}",
-            participant_mentions
-        );
-        Ok(notification)
+    /// 募集メッセージをキャンセル済みの表示に変える
+    pub async fn update_cancelled_message(&self, ctx: &Context, messages: &Messages, recruitment: &BattleRecruitment) -> Result<(), String> {
+        let embed = CreateEmbed::new()
+            .title(messages.get("battle_recruitment.recruitment_cancelled"))
+            .color(0x808080)
+            .timestamp(Utc::now());
+        let embed = with_creator(embed, messages, recruitment.created_by);
+        let edit_builder = EditMessage::new()
+            .content(messages.get("battle_recruitment.cancelled_content"))
+            .embed(embed);
+
+        ChannelId::new(recruitment.channel_id as u64).edit_message(&ctx.http, MessageId::new(recruitment.message_id as u64), edit_builder).await
+            .map(|_| ())
+            .map_err(|e| {
+                error!("Failed to update cancelled recruitment message: {:?}", e);
+                messages.get("battle_recruitment.update_failed")
+            })
     }
 
-    /// 元の募集メッセージに返信する形でメッセージを送信
-    pub async fn send_cancel_reply(&self, channel_id: u64, original_message_id: u64, content: &str) -> Result<(), String> {
-        warn!("CancelRecruitmentService::send_cancel_reply - 仕様検討中です");
-        info!("キャンセル返信送信をエミュレート: channel_id={}, message_id={}", 
-              channel_id, original_message_id);
-        info!("送信内容: {}", content);
-        Ok(())
+    /// キャンセル通知メッセージ作成（参加者にメンションを含む）
+    pub async fn create_cancel_notification(&self, messages: &Messages, variables: &RecruitmentVariables<'_>) -> Result<String, String> {
+        Ok(variables.render(messages, template::CANCELLED))
     }
-}
 
-impl Default for CancelRecruitmentService {
-    fn default() -> Self {
-        Self::new()
+    /// 募集スレッド、または元の募集メッセージに返信する形でメッセージを送信
+    pub async fn send_cancel_reply(&self, ctx: &Context, messages: &Messages, recruitment: &BattleRecruitment, content: String) -> Result<Message, String> {
+        notify(ctx, recruitment, content).await
+            .map_err(|e| {
+                error!("Error sending cancel message: {}", e);
+                messages.get("battle_recruitment.send_failed")
+            })
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,207 @@
+use std::collections::{BTreeSet, HashMap};
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+use poise::serenity_prelude::all::{Context, ChannelId, MessageId, ReactionType};
+use tracing::error;
+
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::services::message::Messages;
+use crate::types::{BattleType, RecruitmentStatus};
+use crate::utils::discord_helper::get_reaction_users;
+
+/// 過去の募集を表示する件数
+pub const PAST_LIMIT: usize = 10;
+
+/// メンバーが作成または参加した募集1件
+pub struct JoinedRecruitment {
+    pub recruitment: BattleRecruitment,
+    pub quest_name: String,
+    /// リアクションした属性（参加していない場合は空）
+    pub elements: Vec<BattleType>,
+    /// メンバーが募集者か
+    pub created: bool,
+}
+
+/// メンバーが作成または参加した募集（開始前と開始済み）
+pub struct JoinedRecruitments {
+    /// 開始前の募集（開催日時の早い順）
+    pub upcoming: Vec<JoinedRecruitment>,
+    /// 開始済みかキャンセルした募集（開催日時の新しい順、最大 PAST_LIMIT 件）
+    pub past: Vec<JoinedRecruitment>,
+}
+
+impl JoinedRecruitments {
+    /// 開始前と開始済み（キャンセルした募集を含む）に分ける
+    pub fn split(mut items: Vec<JoinedRecruitment>) -> Self {
+        items.sort_by_key(|item| (item.recruitment.expiry_date, item.recruitment.id));
+        let (mut past, upcoming): (Vec<_>, Vec<_>) = items.into_iter()
+            .partition(|item| matches!(item.recruitment.status(), RecruitmentStatus::Started | RecruitmentStatus::Cancelled));
+        past.reverse();
+        past.truncate(PAST_LIMIT);
+        Self { upcoming, past }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.upcoming.is_empty() && self.past.is_empty()
+    }
+}
+
+/// JoinedRecruitmentService - 募集の参加者を記録し、メンバーごとに参加した募集を取得するサービス
+/// 参加者は募集メッセージのリアクションから記録する
+pub struct JoinedRecruitmentService {
+    db: Arc<Database>,
+}
+
+impl JoinedRecruitmentService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// 募集メッセージのリアクションから参加者の記録を更新し、更新した募集を返す
+    /// 募集ではないメッセージは何もせずに None
+    pub async fn sync(&self, ctx: &Context, guild_id: u64, channel_id: u64, message_id: u64) -> Result<Option<BattleRecruitment>, String> {
+        let recruitment = match self.db.battle_recruitment.get_by_message(guild_id as i64, channel_id as i64, message_id as i64).await {
+            Ok(Some(recruitment)) => recruitment,
+            Ok(None) => return Ok(None),
+            Err(e) => return Err(format!("Database error: {}", e)),
+        };
+
+        let message = ChannelId::new(channel_id).message(&ctx.http, MessageId::new(message_id)).await
+            .map_err(|e| format!("Failed to fetch recruitment message: {}", e))?;
+        let reactions = get_reaction_users(ctx, &message).await?;
+
+        let participants: Vec<(i64, i32)> = reactions.into_iter()
+            .filter_map(|(emoji, users)| {
+                BattleType::from_reaction(&ReactionType::Unicode(emoji))
+                    .map(|element| (element, users))
+            })
+            .flat_map(|(element, users)| {
+                users.into_iter().map(move |user| (user.id.get() as i64, element as i32))
+            })
+            .collect();
+
+        self.db.recruitment_participant.replace(recruitment.id, recruitment.guild_id, &participants).await
+            .map_err(|e| format!("Database error: {}", e))?;
+        Ok(Some(recruitment))
+    }
+
+    /// メンバーが作成または参加した募集
+    /// エラーは `messages`（実行したユーザーの言語）で返す
+    pub async fn list(&self, messages: &Messages, guild_id: i64, user_id: i64) -> Result<JoinedRecruitments, String> {
+        let participants = self.db.recruitment_participant.get_by_user(guild_id, user_id).await
+            .map_err(|e| {
+                error!("Error fetching joined recruitments: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+
+        let mut elements: HashMap<i32, BTreeSet<i32>> = HashMap::new();
+        for participant in &participants {
+            elements.entry(participant.recruitment_id).or_default().insert(participant.element_id);
+        }
+
+        let mut recruitments = self.db.battle_recruitment.get_by_creator(guild_id, user_id).await
+            .map_err(|e| {
+                error!("Error fetching created recruitments: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+        let joined_ids: Vec<i32> = elements.keys()
+            .filter(|id| !recruitments.iter().any(|recruitment| recruitment.id == **id))
+            .copied()
+            .collect();
+        if !joined_ids.is_empty() {
+            let joined = self.db.battle_recruitment.get_by_ids(joined_ids).await
+                .map_err(|e| {
+                    error!("Error fetching recruitments: {:?}", e);
+                    messages.get("battle_recruitment.database_error")
+                })?;
+            recruitments.extend(joined);
+        }
+
+        let mut quest_names: HashMap<i32, String> = HashMap::new();
+        let mut items = Vec::with_capacity(recruitments.len());
+        for recruitment in recruitments {
+            if let Entry::Vacant(entry) = quest_names.entry(recruitment.target_id) {
+                let quest_name = match self.db.quest.get_by_target_id(Some(guild_id), recruitment.target_id).await {
+                    Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+                    Err(e) => {
+                        error!("Error fetching quest: {:?}", e);
+                        String::new()
+                    }
+                };
+                entry.insert(quest_name);
+            }
+
+            let elements = elements.remove(&recruitment.id).unwrap_or_default().into_iter()
+                .filter_map(BattleType::from_value)
+                .collect();
+            items.push(JoinedRecruitment {
+                quest_name: quest_names[&recruitment.target_id].clone(),
+                created: recruitment.is_created_by(user_id as u64),
+                recruitment,
+                elements,
+            });
+        }
+
+        Ok(JoinedRecruitments::split(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, Utc};
+
+    fn joined(id: i32, expiry_date: DateTime<Utc>, started: bool) -> JoinedRecruitment {
+        JoinedRecruitment {
+            recruitment: BattleRecruitment {
+                id,
+                guild_id: 1,
+                channel_id: 1,
+                message_id: id as i64,
+                target_id: 1,
+                battle_type_id: 0,
+                expiry_date,
+                recruit_end_message_id: None,
+                capacity: 6,
+                notes: None,
+                reminded_offset_minutes: None,
+                started_at: started.then(Utc::now),
+                cancelled_at: None,
+                created_by: None,
+                thread_id: None,
+                scheduled_event_id: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            quest_name: String::new(),
+            elements: vec![BattleType::Fire],
+            created: false,
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        let now = Utc::now();
+        let mut items = vec![
+            joined(1, now + Duration::hours(2), false),
+            joined(2, now + Duration::hours(1), false),
+        ];
+        items.extend((0..PAST_LIMIT as i32 + 2).map(|i| joined(10 + i, now - Duration::hours(i as i64 + 1), true)));
+
+        let split = JoinedRecruitments::split(items);
+        assert_eq!(split.upcoming.iter().map(|item| item.recruitment.id).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(split.past.len(), PAST_LIMIT);
+        // 開始済みは新しい順
+        assert_eq!(split.past[0].recruitment.id, 10);
+        assert!(!split.is_empty());
+        assert!(JoinedRecruitments::split(Vec::new()).is_empty());
+
+        // キャンセルした募集は開始前に含めない
+        let mut cancelled = joined(3, now + Duration::hours(3), false);
+        cancelled.recruitment.cancelled_at = Some(now);
+        let split = JoinedRecruitments::split(vec![cancelled]);
+        assert!(split.upcoming.is_empty());
+        assert_eq!(split.past.len(), 1);
+    }
+}
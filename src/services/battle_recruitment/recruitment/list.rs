@@ -0,0 +1,157 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::all::{Context, ChannelId, GuildId, MessageId};
+use tracing::error;
+
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::repository::battle_recruitment_repository::RecruitmentFilter;
+use crate::services::message::Messages;
+use crate::types::RecruitmentStatus;
+use crate::utils::discord_helper::{get_unique_reaction_users, make_message_url};
+
+/// 1ページに表示する募集の件数
+pub const PAGE_SIZE: u64 = 10;
+
+/// 開始済みの募集を表示する場合に遡る日数の既定
+pub const DEFAULT_STARTED_DAYS: u32 = 7;
+
+/// 一覧に表示する募集1件
+pub struct RecruitmentSummary {
+    pub recruitment: BattleRecruitment,
+    pub quest_name: String,
+    /// 参加者数（募集メッセージが削除されている場合は None）
+    pub participant_count: Option<usize>,
+    /// 募集メッセージへのリンク（削除されている場合は None）
+    pub url: Option<String>,
+}
+
+/// 一覧の1ページ分
+pub struct RecruitmentPage {
+    pub items: Vec<RecruitmentSummary>,
+    /// 0から始まるページ番号
+    pub page: u64,
+    /// 条件に一致する募集の件数
+    pub total: u64,
+}
+
+impl RecruitmentPage {
+    pub fn page_count(&self) -> u64 {
+        self.total.div_ceil(PAGE_SIZE).max(1)
+    }
+}
+
+/// ListRecruitmentService - サーバーの募集を一覧表示するサービス
+/// エラーは `messages`（実行したユーザーの言語）で返す
+pub struct ListRecruitmentService {
+    db: Arc<Database>,
+}
+
+impl ListRecruitmentService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// 一覧の条件
+    /// 状態を指定しない場合は開始前（募集中と満員）の募集を表示する
+    /// `days` は開始前の募集では今後の日数、開始済みの募集では遡る日数（既定は7日）
+    pub fn filter(guild_id: i64, channel_id: Option<i64>, status: Option<RecruitmentStatus>, days: Option<u32>, now: DateTime<Utc>) -> RecruitmentFilter {
+        let (statuses, from, until) = match status {
+            Some(RecruitmentStatus::Started) => {
+                let days = days.unwrap_or(DEFAULT_STARTED_DAYS);
+                (vec![RecruitmentStatus::Started], Some(now - Duration::days(days as i64)), None)
+            },
+            status => {
+                let statuses = status.map(|status| vec![status])
+                    .unwrap_or_else(|| vec![RecruitmentStatus::Open, RecruitmentStatus::Full]);
+                (statuses, None, days.map(|days| now + Duration::days(days as i64)))
+            },
+        };
+        RecruitmentFilter { guild_id, channel_id, statuses, from, until }
+    }
+
+    /// 条件に一致する募集を開催日時の順に1ページ分取得する
+    /// 範囲外のページは最後のページになる
+    pub async fn page(&self, ctx: &Context, messages: &Messages, filter: &RecruitmentFilter, page: u64) -> Result<RecruitmentPage, String> {
+        let total = self.db.battle_recruitment.count(filter).await
+            .map_err(|e| {
+                error!("Error counting recruitments: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+        let page = page.min(total.div_ceil(PAGE_SIZE).saturating_sub(1));
+
+        let recruitments = self.db.battle_recruitment.search(filter, page * PAGE_SIZE, PAGE_SIZE).await
+            .map_err(|e| {
+                error!("Error searching recruitments: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+
+        let mut items = Vec::with_capacity(recruitments.len());
+        for recruitment in recruitments {
+            items.push(self.summary(ctx, recruitment).await);
+        }
+        Ok(RecruitmentPage { items, page, total })
+    }
+
+    /// クエスト名と、募集メッセージのリアクションから参加者数とリンクを取得する
+    async fn summary(&self, ctx: &Context, recruitment: BattleRecruitment) -> RecruitmentSummary {
+        let quest_name = match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
+            Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+            Err(e) => {
+                error!("Error fetching quest: {:?}", e);
+                String::new()
+            }
+        };
+
+        let channel_id = ChannelId::new(recruitment.channel_id as u64);
+        let (participant_count, url) = match channel_id.message(&ctx.http, MessageId::new(recruitment.message_id as u64)).await {
+            Ok(mut message) => {
+                // HTTPで取得したメッセージにはサーバーIDが含まれない
+                message.guild_id = Some(GuildId::new(recruitment.guild_id as u64));
+                let participant_count = get_unique_reaction_users(ctx, &message).await
+                    .map(|users| users.len())
+                    .ok();
+                (participant_count, Some(make_message_url(&message).await))
+            },
+            Err(e) => {
+                error!("Error fetching recruitment message {}: {:?}", recruitment.message_id, e);
+                (None, None)
+            }
+        };
+
+        RecruitmentSummary { recruitment, quest_name, participant_count, url }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let now = Utc::now();
+
+        let upcoming = ListRecruitmentService::filter(1, None, None, None, now);
+        assert_eq!(upcoming.statuses, vec![RecruitmentStatus::Open, RecruitmentStatus::Full]);
+        assert_eq!((upcoming.from, upcoming.until), (None, None));
+
+        let open = ListRecruitmentService::filter(1, Some(2), Some(RecruitmentStatus::Open), Some(3), now);
+        assert_eq!(open.statuses, vec![RecruitmentStatus::Open]);
+        assert_eq!(open.channel_id, Some(2));
+        assert_eq!(open.until, Some(now + Duration::days(3)));
+
+        let started = ListRecruitmentService::filter(1, None, Some(RecruitmentStatus::Started), None, now);
+        assert_eq!(started.statuses, vec![RecruitmentStatus::Started]);
+        assert_eq!(started.from, Some(now - Duration::days(DEFAULT_STARTED_DAYS as i64)));
+        assert_eq!(started.until, None);
+    }
+
+    #[test]
+    fn test_page_count() {
+        let page = |total| RecruitmentPage { items: Vec::new(), page: 0, total };
+
+        assert_eq!(page(0).page_count(), 1);
+        assert_eq!(page(PAGE_SIZE).page_count(), 1);
+        assert_eq!(page(PAGE_SIZE + 1).page_count(), 2);
+    }
+}
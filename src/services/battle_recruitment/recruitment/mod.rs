@@ -3,10 +3,20 @@ pub mod participants;
 pub mod update;
 pub mod cancel;
 pub mod start;
+pub mod reminder;
+pub mod list;
+pub mod joined;
+pub mod raid_id;
+pub mod scheduled_event;
 
 // Re-export services for easier access
 pub use new::NewRecruitmentService;
 pub use participants::ParticipantsService;
 pub use cancel::CancelRecruitmentService;
 pub use update::UpdateRecruitmentService;
-pub use start::StartRecruitmentService;
\ No newline at end of file
+pub use start::StartRecruitmentService;
+pub use reminder::ReminderService;
+pub use list::ListRecruitmentService;
+pub use joined::JoinedRecruitmentService;
+pub use raid_id::RaidIdService;
+pub use scheduled_event::ScheduledEventService;
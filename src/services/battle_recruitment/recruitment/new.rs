@@ -1,12 +1,23 @@
 use std::sync::Arc;
-use chrono::{DateTime, Local, Duration};
-use poise::serenity_prelude::all::{Context, CreateEmbed, CreateMessage, Message, ChannelId};
-use tracing::{error, info};
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use poise::serenity_prelude::all::{Context, CreateEmbed, CreateMessage, CreateThread, AutoArchiveDuration, Message, ChannelId};
+use tracing::{error, info, warn};
 
 use crate::repository::Database;
+use crate::models::guild_settings::GuildSettings;
 use crate::models::quest::Quest;
+use crate::services::message::Messages;
+use crate::repository::battle_recruitment_repository::NewBattleRecruitment;
+use crate::services::battle_recruitment::params::{CreateParameter, NewParameter};
+use crate::services::battle_recruitment::recruitment::scheduled_event::{NewEvent, ScheduledEventService};
+use crate::services::battle_recruitment::template::{self, RecruitmentVariables, EVENT_TIME_FORMAT};
+use crate::services::battle_recruitment::{with_creator, MAX_THREAD_NAME_LENGTH};
+use crate::services::settings::SettingsService;
 use crate::types::BattleType;
 
+/// クエストが見つからない場合に提示する候補の最大数
+const QUEST_SUGGESTION_LIMIT: usize = 3;
+
 pub struct NewRecruitmentService {
     db: Arc<Database>,
 }
@@ -18,57 +29,107 @@ impl NewRecruitmentService {
 
     /// 新規募集を作成する
     /// Python版のbase_battle_recruiment_cog.py の recruitment() メソッドに相当
-    pub async fn create_recruitment(
+    /// エラーは `messages`（実行したユーザーの言語）、募集メッセージはサーバーの設定言語で作成する
+    pub(crate) async fn create_recruitment(
         &self,
         ctx: &Context,
-        channel_id: u64,
-        guild_id: u64,
-        quest_alias: &str,
-        battle_type: BattleType,
-        event_date: Option<DateTime<Local>>,
+        messages: &Messages,
+        params: CreateParameter<'_>,
     ) -> Result<Message, String> {
+        let CreateParameter { guild_id, channel_id, quest_alias, battle_type, event_date, capacity, notes, created_by } = params;
+
         // 1. クエストを取得
-        let quest = self.get_quest_by_alias(quest_alias).await?;
+        let quest = self.get_quest_by_alias(messages, guild_id as i64, quest_alias).await?;
         
         // 2. イベント日時を決定（指定されていない場合はデフォルト）
-        let expiry_date = event_date.unwrap_or_else(|| {
-            Local::now() + Duration::days(7)
-        });
+        // 募集メッセージの日時はサーバーのタイムゾーンで表示する
+        let settings = SettingsService::new(self.db.clone()).get(guild_id as i64).await
+            .unwrap_or_else(|e| {
+                error!("Error fetching guild settings: {}", e);
+                GuildSettings::empty(guild_id as i64)
+            });
+        let timezone = settings.timezone();
+        let expiry_date = event_date
+            .map(|date| date.with_timezone(&timezone))
+            .unwrap_or_else(|| Utc::now().with_timezone(&timezone) + Duration::days(7));
+        if expiry_date <= Utc::now() {
+            return Err(messages.get("battle_recruitment.create_past_date"));
+        }
 
         // 3. 募集メッセージを作成・送信
-        let message = self.send_recruitment_message(
-            ctx,
-            channel_id,
-            &quest.quest_name,
-            battle_type.clone(),
-            expiry_date,
-        ).await?;
+        let variables = RecruitmentVariables {
+            quest_name: &quest.quest_name,
+            battle_type,
+            event_time: Some(expiry_date),
+            participants: &[],
+            capacity,
+            notes,
+        };
+        let message = self.send_recruitment_message(ctx, messages, channel_id, &variables, created_by).await?;
 
         // 4. リアクションを追加
-        self.add_reactions(ctx, &message, battle_type.clone()).await?;
-
-        // 5. データベースに登録
-        self.register_recruitment(
-            guild_id as i64,
-            channel_id as i64,
-            message.id.get() as i64,
-            quest.target_id,
-            battle_type,
-            expiry_date,
-        ).await?;
+        self.add_reactions(ctx, &message, battle_type).await?;
+
+        // 5. サーバーの設定で有効な場合は募集スレッドを作成し、Discordのイベントに登録
+        let thread_id = if settings.recruitment_threads() {
+            self.create_thread(ctx, messages, &message, &quest.quest_name, expiry_date).await
+        } else {
+            None
+        };
+        let scheduled_event_id = if settings.scheduled_events() {
+            ScheduledEventService::new(self.db.clone()).create(ctx, messages, &NewEvent {
+                guild_id: guild_id as i64,
+                quest_name: &quest.quest_name,
+                event_date: expiry_date.with_timezone(&Utc),
+                notes,
+                message_url: format!("https://discord.com/channels/{}/{}/{}", guild_id, channel_id, message.id),
+            }).await
+        } else {
+            None
+        };
+
+        // 6. データベースに登録
+        self.register_recruitment(NewParameter {
+            guild_id: guild_id as i64,
+            channel_id: channel_id as i64,
+            message_id: message.id.get() as i64,
+            target_id: quest.target_id,
+            quest,
+            battle_type_id: battle_type as i32,
+            expiry_date: expiry_date.with_timezone(&Utc),
+            capacity,
+            notes: notes.map(str::to_string),
+            created_by: created_by as i64,
+            thread_id,
+            scheduled_event_id,
+        }).await.map_err(|_| messages.get("battle_recruitment.register_failed"))?;
 
-        info!("Successfully created recruitment for quest: {}", quest.quest_name);
         Ok(message)
     }
 
-    /// クエストエイリアスからクエスト情報を取得
-    async fn get_quest_by_alias(&self, alias: &str) -> Result<Quest, String> {
-        match self.db.quest.get_by_alias(alias).await {
+    /// クエストエイリアスからクエスト情報を取得（サーバー独自の別名を優先する）
+    async fn get_quest_by_alias(&self, messages: &Messages, guild_id: i64, alias: &str) -> Result<Quest, String> {
+        match self.db.quest.get_by_alias(Some(guild_id), alias).await {
             Ok(Some(quest)) => Ok(quest),
-            Ok(None) => Err(format!("Quest not found for alias: {}", alias)),
+            Ok(None) => {
+                let suggestions = self.db.quest.get_similar(Some(guild_id), alias, QUEST_SUGGESTION_LIMIT).await
+                    .unwrap_or_else(|e| {
+                        error!("Database error when getting similar quests: {:?}", e);
+                        Vec::new()
+                    });
+                if suggestions.is_empty() {
+                    Err(messages.get_with_params("battle_recruitment.quest_not_found", &[("alias", alias)]))
+                } else {
+                    let names: Vec<&str> = suggestions.iter().map(|q| q.quest_name.as_str()).collect();
+                    Err(messages.get_with_params("battle_recruitment.quest_not_found_suggestions", &[
+                        ("alias", alias),
+                        ("suggestions", &names.join(", ")),
+                    ]))
+                }
+            },
             Err(e) => {
                 error!("Database error when getting quest by alias: {:?}", e);
-                Err(format!("Database error: {}", e))
+                Err(messages.get("battle_recruitment.database_error"))
             }
         }
     }
@@ -78,28 +139,22 @@ impl NewRecruitmentService {
     async fn send_recruitment_message(
         &self,
         ctx: &Context,
+        messages: &Messages,
         channel_id: u64,
-        quest_name: &str,
-        battle_type: BattleType,
-        event_date: DateTime<Local>,
+        variables: &RecruitmentVariables<'_>,
+        created_by: u64,
     ) -> Result<Message, String> {
-        // メッセージテキストを作成
-        let mut message_text = format!("{}の参加者を募集します。", quest_name);
-        
-        if battle_type == BattleType::AllElement {
-            message_text.push_str("\n参加属性を選んでください");
-        }
+        let post_messages = messages.in_guild_language();
 
-        message_text.push_str(&format!(
-            "\n開催日時：{}",
-            event_date.format("%m/%d %H:%M")
-        ));
+        // メッセージテキストを作成（サーバー独自のテンプレートがあればそれを使う）
+        let message_text = variables.render(&post_messages, template::BODY);
 
         // 埋め込みメッセージを作成
         let embed = CreateEmbed::new()
-            .title("参加者一覧")
-            .description("現在参加者はいません。")
+            .title(post_messages.get("battle_recruitment.participants_title"))
+            .description(post_messages.get("battle_recruitment.no_participants"))
             .color(0x0099ff);
+        let embed = with_creator(embed, &post_messages, Some(created_by as i64));
 
         // メッセージを送信
         let builder = CreateMessage::new()
@@ -110,7 +165,7 @@ impl NewRecruitmentService {
             Ok(message) => Ok(message),
             Err(e) => {
                 error!("Error sending recruitment message: {:?}", e);
-                Err(format!("Failed to send message: {}", e))
+                Err(messages.get("battle_recruitment.send_failed"))
             }
         }
     }
@@ -133,27 +188,52 @@ impl NewRecruitmentService {
         Ok(())
     }
 
+    /// 募集メッセージにスレッドを作成する
+    /// スレッドを作成できなくても募集は続けるため、エラーはログのみ
+    async fn create_thread(
+        &self,
+        ctx: &Context,
+        messages: &Messages,
+        message: &Message,
+        quest_name: &str,
+        event_date: DateTime<FixedOffset>,
+    ) -> Option<i64> {
+        let name: String = messages.in_guild_language().get_with_params("battle_recruitment.thread_name", &[
+            ("quest_name", quest_name),
+            ("event_time", &event_date.format(EVENT_TIME_FORMAT).to_string()),
+        ]).chars().take(MAX_THREAD_NAME_LENGTH).collect();
+        let builder = CreateThread::new(name).auto_archive_duration(AutoArchiveDuration::OneWeek);
+
+        match message.channel_id.create_thread_from_message(&ctx.http, message.id, builder).await {
+            Ok(thread) => {
+                info!("Recruitment thread created: thread_id={}", thread.id);
+                Some(thread.id.get() as i64)
+            }
+            Err(e) => {
+                warn!("Failed to create recruitment thread: {:?}", e);
+                None
+            }
+        }
+    }
+
     /// 募集情報をデータベースに登録
     /// Python版の _regist() に相当
-    async fn register_recruitment(
-        &self,
-        guild_id: i64,
-        channel_id: i64,
-        message_id: i64,
-        target_id: i32,
-        battle_type: BattleType,
-        expiry_date: DateTime<Local>,
-    ) -> Result<(), String> {
-        match self.db.battle_recruitment.create(
-            guild_id,
-            channel_id,
-            message_id,
-            target_id,
-            battle_type as i32,
-            expiry_date.with_timezone(&chrono::Utc),
-        ).await {
+    async fn register_recruitment(&self, params: NewParameter) -> Result<(), String> {
+        match self.db.battle_recruitment.create(NewBattleRecruitment {
+            guild_id: params.guild_id,
+            channel_id: params.channel_id,
+            message_id: params.message_id,
+            target_id: params.target_id,
+            battle_type_id: params.battle_type_id,
+            expiry_date: params.expiry_date,
+            capacity: params.capacity as i32,
+            notes: params.notes,
+            created_by: Some(params.created_by),
+            thread_id: params.thread_id,
+            scheduled_event_id: params.scheduled_event_id,
+        }).await {
             Ok(_) => {
-                info!("Successfully registered recruitment in database");
+                info!("Successfully created recruitment for quest: {}", params.quest.quest_name);
                 Ok(())
             }
             Err(e) => {
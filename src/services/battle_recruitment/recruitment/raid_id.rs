@@ -0,0 +1,213 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::all::{Context, ChannelId, CreateMessage, GuildId, MessageId, UserId};
+use tracing::{error, info, warn};
+
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::repository::recruitment_raid_id_repository::NewRecruitmentRaidId;
+use crate::services::message::Messages;
+use crate::services::battle_recruitment::notify;
+use crate::utils::discord_helper::{get_unique_reaction_users, make_message_url};
+
+/// 募集を省略した場合に対象とする募集の開催日時の範囲（前後の時間）
+pub const CURRENT_RECRUITMENT_HOURS: i64 = 12;
+
+/// RaidIdService - 参戦IDを募集の参加者に共有するサービス
+/// 共有した参戦IDは募集ごとに記録する
+/// エラーは `messages`（実行したユーザーの言語）、通知はサーバーの設定言語で作成する
+pub struct RaidIdService {
+    db: Arc<Database>,
+}
+
+impl RaidIdService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// チャンネルでメンバーが作成または参加した募集のうち、開催日時が最も近いもの（キャンセルした募集を除く）
+    pub async fn current_recruitment(&self, messages: &Messages, guild_id: i64, channel_id: i64, user_id: i64, now: DateTime<Utc>) -> Result<BattleRecruitment, String> {
+        let database_error = |e| {
+            error!("Error fetching recruitments: {:?}", e);
+            messages.get("battle_recruitment.database_error")
+        };
+
+        let mut recruitments = self.db.battle_recruitment.get_by_creator(guild_id, user_id).await
+            .map_err(database_error)?;
+        let joined_ids: Vec<i32> = self.db.recruitment_participant.get_by_user(guild_id, user_id).await
+            .map_err(database_error)?
+            .into_iter()
+            .map(|participant| participant.recruitment_id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if !joined_ids.is_empty() {
+            recruitments.extend(self.db.battle_recruitment.get_by_ids(joined_ids).await.map_err(database_error)?);
+        }
+
+        pick_current(recruitments, channel_id, now)
+            .ok_or_else(|| messages.get("raid_id.no_recruitment"))
+    }
+
+    /// 参戦IDを記録し、共有したメンバー以外の参加者と募集者に通知する
+    /// `direct_message` の場合はDMで送り、DMを受け取れないメンバーは募集スレッドか募集への返信でメンションする
+    /// 通知したメンバーの数を返す
+    pub async fn share(&self, ctx: &Context, messages: &Messages, recruitment: &BattleRecruitment, posted_by: UserId, raid_id: &str, direct_message: bool) -> Result<usize, String> {
+        self.share_if_participant(ctx, messages, recruitment, posted_by, raid_id, direct_message).await?
+            .ok_or_else(|| messages.get("raid_id.not_participant"))
+    }
+
+    /// `share` と同じだが、共有したメンバーが参加者でも募集者でもない場合はエラーにせず None を返す
+    pub async fn share_if_participant(&self, ctx: &Context, messages: &Messages, recruitment: &BattleRecruitment, posted_by: UserId, raid_id: &str, direct_message: bool) -> Result<Option<usize>, String> {
+        // 1. 共有するメンバーが参加者か確認
+        let channel_id = ChannelId::new(recruitment.channel_id as u64);
+        let message_id = MessageId::new(recruitment.message_id as u64);
+        let mut message = channel_id.message(&ctx.http, message_id).await
+            .map_err(|e| {
+                error!("Error fetching recruitment message: {:?}", e);
+                messages.get("battle_recruitment.not_found")
+            })?;
+        // HTTPで取得したメッセージにはサーバーIDが含まれない
+        message.guild_id = Some(GuildId::new(recruitment.guild_id as u64));
+        let mut participants = get_unique_reaction_users(ctx, &message).await
+            .map_err(|e| {
+                error!("Error getting participants: {}", e);
+                messages.get("battle_recruitment.update_failed")
+            })?;
+        if !participants.contains(&posted_by) && !recruitment.is_created_by(posted_by.get()) {
+            return Ok(None);
+        }
+
+        // 2. 同じ参戦IDが続けて共有された場合は通知しない
+        let latest = self.db.recruitment_raid_id.get_latest(recruitment.id).await
+            .map_err(|e| {
+                error!("Error fetching raid ID: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+        if latest.is_some_and(|latest| latest.raid_id == raid_id) {
+            return Err(messages.get_with_params("raid_id.already_shared", &[("raid_id", raid_id)]));
+        }
+
+        // 3. 参戦IDを記録
+        self.db.recruitment_raid_id.create(NewRecruitmentRaidId {
+            recruitment_id: recruitment.id,
+            guild_id: recruitment.guild_id,
+            raid_id: raid_id.to_string(),
+            posted_by: posted_by.get() as i64,
+        }).await
+            .map_err(|e| {
+                error!("Error recording raid ID: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+
+        // 4. 共有したメンバー以外に通知
+        if let Some(created_by) = recruitment.created_by {
+            participants.insert(UserId::new(created_by as u64));
+        }
+        participants.remove(&posted_by);
+        let mut recipients: Vec<UserId> = participants.into_iter().collect();
+        recipients.sort();
+        if recipients.is_empty() {
+            return Ok(Some(0));
+        }
+
+        let post_messages = messages.in_guild_language();
+        let quest_name = match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
+            Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+            Err(e) => {
+                error!("Error fetching quest: {:?}", e);
+                String::new()
+            }
+        };
+        let poster = format!("<@{}>", posted_by.get());
+        let params = [
+            ("quest_name", quest_name.as_str()),
+            ("raid_id", raid_id),
+            ("member", poster.as_str()),
+        ];
+
+        let mut mentioned = recipients.clone();
+        if direct_message {
+            let url = make_message_url(&message).await;
+            let content = post_messages.get_with_params("raid_id.direct_message", &[params.as_slice(), &[("url", url.as_str())]].concat());
+            mentioned.clear();
+            for user_id in &recipients {
+                if let Err(e) = user_id.direct_message(&ctx.http, CreateMessage::new().content(content.clone())).await {
+                    warn!("Failed to send raid ID to {}: {:?}", user_id, e);
+                    mentioned.push(*user_id);
+                }
+            }
+        }
+
+        if !mentioned.is_empty() {
+            let mentions: Vec<String> = mentioned.iter().map(|user_id| format!("<@{}>", user_id.get())).collect();
+            let content = post_messages.get_with_params("raid_id.notice", &[params.as_slice(), &[("participants", mentions.join(" ").as_str())]].concat());
+            notify(ctx, recruitment, content).await
+                .map_err(|e| {
+                    error!("Error sending raid ID: {}", e);
+                    messages.get("battle_recruitment.send_failed")
+                })?;
+        }
+
+        info!("Raid ID shared: recruitment_id={}, recipients={}", recruitment.id, recipients.len());
+        Ok(Some(recipients.len()))
+    }
+}
+
+/// チャンネルの募集のうち、開催日時が前後 CURRENT_RECRUITMENT_HOURS 時間以内で最も近いもの
+fn pick_current(recruitments: Vec<BattleRecruitment>, channel_id: i64, now: DateTime<Utc>) -> Option<BattleRecruitment> {
+    let window = Duration::hours(CURRENT_RECRUITMENT_HOURS);
+    recruitments.into_iter()
+        .filter(|recruitment| recruitment.channel_id == channel_id && recruitment.cancelled_at.is_none())
+        .filter(|recruitment| (recruitment.expiry_date - now).abs() <= window)
+        .min_by_key(|recruitment| ((recruitment.expiry_date - now).abs(), recruitment.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recruitment(id: i32, channel_id: i64, expiry_date: DateTime<Utc>) -> BattleRecruitment {
+        BattleRecruitment {
+            id,
+            guild_id: 1,
+            channel_id,
+            message_id: id as i64,
+            target_id: 1,
+            battle_type_id: 0,
+            expiry_date,
+            recruit_end_message_id: None,
+            capacity: 6,
+            notes: None,
+            reminded_offset_minutes: None,
+            started_at: None,
+            cancelled_at: None,
+            created_by: None,
+            thread_id: None,
+            scheduled_event_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_pick_current() {
+        let now = Utc::now();
+        let recruitments = vec![
+            recruitment(1, 10, now + Duration::hours(3)),
+            recruitment(2, 10, now - Duration::minutes(30)),
+            recruitment(3, 20, now),
+            recruitment(4, 10, now - Duration::hours(CURRENT_RECRUITMENT_HOURS + 1)),
+        ];
+
+        assert_eq!(pick_current(recruitments.clone(), 10, now).map(|r| r.id), Some(2));
+        assert_eq!(pick_current(recruitments.clone(), 20, now).map(|r| r.id), Some(3));
+        assert_eq!(pick_current(recruitments, 30, now).map(|r| r.id), None);
+        assert_eq!(pick_current(vec![recruitment(4, 10, now - Duration::days(1))], 10, now).map(|r| r.id), None);
+
+        let mut cancelled = recruitment(5, 10, now);
+        cancelled.cancelled_at = Some(now);
+        assert_eq!(pick_current(vec![cancelled], 10, now).map(|r| r.id), None);
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::all::{Context, Message, ChannelId, MessageId};
+use tracing::{error, info};
+
+use crate::models::battle_recruitment::{BattleRecruitment, ScheduledAction};
+use crate::repository::Database;
+use crate::services::battle_recruitment::notify;
+use crate::services::battle_recruitment::template::{self, RecruitmentVariables};
+use crate::services::message::Messages;
+use crate::services::settings::SettingsService;
+use crate::utils::discord_helper::get_participant_mentions;
+
+/// リマインドを確認する範囲（リマインドの設定の上限）
+const REMINDER_LOOKAHEAD_DAYS: i64 = 7;
+
+/// ReminderService - 開始前のリマインドと開始時刻の判定を行うサービス
+/// リマインドの時刻はサーバー設定の reminder_offsets、開始時刻は募集の開催日時から決まるため、
+/// 開催日時を変更した募集は次の確認から新しい日時で送信される
+pub struct ReminderService {
+    db: Arc<Database>,
+}
+
+impl ReminderService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// 予定された処理がある募集と処理内容
+    pub async fn due(&self, now: DateTime<Utc>) -> Result<Vec<(BattleRecruitment, ScheduledAction)>, String> {
+        let recruitments = self.db.battle_recruitment.get_pending_start(now + Duration::days(REMINDER_LOOKAHEAD_DAYS)).await
+            .map_err(|e| {
+                error!("Error getting pending recruitments: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        // サーバー設定は確認のたびに1回だけ取得する
+        let settings = SettingsService::new(self.db.clone());
+        let mut offsets: HashMap<i64, Vec<Duration>> = HashMap::new();
+        let mut due = Vec::new();
+        for recruitment in recruitments {
+            if let Entry::Vacant(entry) = offsets.entry(recruitment.guild_id) {
+                let guild_offsets = settings.get(recruitment.guild_id).await
+                    .map(|settings| settings.reminder_offsets())
+                    .unwrap_or_default();
+                entry.insert(guild_offsets);
+            }
+
+            if let Some(action) = recruitment.scheduled_action(&offsets[&recruitment.guild_id], now) {
+                due.push((recruitment, action));
+            }
+        }
+        Ok(due)
+    }
+
+    /// 参加者にリマインドを送信する
+    /// 他のプロセスが先に送信した場合は送信せずに None
+    pub async fn remind(&self, ctx: &Context, recruitment: &BattleRecruitment, offset_minutes: i32) -> Result<Option<Message>, String> {
+        let claimed = self.db.battle_recruitment.mark_reminded(recruitment.id, offset_minutes).await
+            .map_err(|e| format!("Database error: {}", e))?;
+        if !claimed {
+            return Ok(None);
+        }
+
+        let messages = Messages::for_guild(&self.db, recruitment.guild_id).await;
+        let quest_name = match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
+            Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+            Err(e) => {
+                error!("Error fetching quest: {:?}", e);
+                String::new()
+            }
+        };
+
+        let channel_id = ChannelId::new(recruitment.channel_id as u64);
+        let message_id = MessageId::new(recruitment.message_id as u64);
+        let participants = get_participant_mentions(ctx, channel_id, message_id).await?;
+        // 参加者がいない募集には送信しない
+        if participants.is_empty() {
+            return Ok(None);
+        }
+
+        let timezone = SettingsService::new(self.db.clone()).timezone(recruitment.guild_id).await;
+        let content = RecruitmentVariables::for_recruitment(recruitment, &quest_name, &participants, timezone)
+            .render(&messages, template::REMINDER);
+        let message = notify(ctx, recruitment, content).await?;
+
+        info!("Reminder sent: recruitment_id={}, offset_minutes={}", recruitment.id, offset_minutes);
+        Ok(Some(message))
+    }
+
+    /// 開始時刻を過ぎすぎた募集を、呼び出しを送信せずに開始済みにする
+    pub async fn expire(&self, recruitment: &BattleRecruitment) -> Result<(), String> {
+        self.db.battle_recruitment.mark_started(recruitment.id, Utc::now()).await
+            .map_err(|e| format!("Database error: {}", e))?;
+        Ok(())
+    }
+}
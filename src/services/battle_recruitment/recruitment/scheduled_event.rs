@@ -0,0 +1,133 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::all::{Context, CreateScheduledEvent, EditScheduledEvent, GuildId, ScheduledEventId, ScheduledEventStatus, ScheduledEventType};
+use tracing::{error, info, warn};
+
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::services::battle_recruitment::EVENT_DURATION_MINUTES;
+use crate::services::message::Messages;
+
+/// イベント名の上限（Discordの制限は100文字）
+const MAX_EVENT_NAME_LENGTH: usize = 100;
+
+/// イベントの説明の上限（Discordの制限は1000文字）
+const MAX_EVENT_DESCRIPTION_LENGTH: usize = 1000;
+
+/// ScheduledEventService - 募集をDiscordのイベントとして登録し、変更・開始・キャンセルを反映するサービス
+/// イベントは募集のおまけのため、Discordへの反映に失敗してもエラーとせずログのみ
+pub struct ScheduledEventService {
+    db: Arc<Database>,
+}
+
+impl ScheduledEventService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// 募集をDiscordのイベントとして登録し、イベントIDを返す
+    /// イベント名と説明はサーバーの設定言語で作成する
+    pub async fn create(&self, ctx: &Context, messages: &Messages, recruitment: &NewEvent<'_>) -> Option<i64> {
+        let post_messages = messages.in_guild_language();
+        let builder = CreateScheduledEvent::new(ScheduledEventType::External, event_name(&post_messages, recruitment.quest_name), recruitment.event_date)
+            .end_time(recruitment.event_date + Duration::minutes(EVENT_DURATION_MINUTES))
+            .location(&recruitment.message_url)
+            .description(event_description(&post_messages, &recruitment.message_url, recruitment.notes));
+
+        match GuildId::new(recruitment.guild_id as u64).create_scheduled_event(&ctx.http, builder).await {
+            Ok(event) => {
+                info!("Scheduled event created: event_id={}", event.id);
+                Some(event.id.get() as i64)
+            }
+            Err(e) => {
+                warn!("Failed to create scheduled event: {:?}", e);
+                None
+            }
+        }
+    }
+
+    /// 募集の開催日時と補足をイベントに反映する（開始済みのイベントは変更しない）
+    pub async fn sync(&self, ctx: &Context, recruitment: &BattleRecruitment) {
+        if recruitment.started_at.is_some() {
+            return;
+        }
+        let messages = Messages::for_guild(&self.db, recruitment.guild_id).await;
+        let message_url = recruitment.message_url();
+        let builder = EditScheduledEvent::new()
+            .start_time(recruitment.expiry_date)
+            .end_time(recruitment.expiry_date + Duration::minutes(EVENT_DURATION_MINUTES))
+            .description(event_description(&messages, &message_url, recruitment.notes.as_deref()));
+        self.edit(ctx, recruitment, builder).await;
+    }
+
+    /// 募集の開始に合わせてイベントを開始する（イベントに興味ありのメンバーにDiscordから通知される）
+    pub async fn start(&self, ctx: &Context, recruitment: &BattleRecruitment) {
+        self.edit(ctx, recruitment, EditScheduledEvent::new().status(ScheduledEventStatus::Active)).await;
+    }
+
+    /// 募集のキャンセルに合わせてイベントをキャンセルする（開始済みのイベントは終了する）
+    pub async fn cancel(&self, ctx: &Context, recruitment: &BattleRecruitment) {
+        let status = if recruitment.started_at.is_some() {
+            ScheduledEventStatus::Completed
+        } else {
+            ScheduledEventStatus::Canceled
+        };
+        self.edit(ctx, recruitment, EditScheduledEvent::new().status(status)).await;
+    }
+
+    async fn edit(&self, ctx: &Context, recruitment: &BattleRecruitment, builder: EditScheduledEvent<'_>) {
+        let Some(event_id) = recruitment.scheduled_event_id else {
+            return;
+        };
+        // 手動で削除されたイベントなど
+        if let Err(e) = GuildId::new(recruitment.guild_id as u64).edit_scheduled_event(&ctx.http, ScheduledEventId::new(event_id as u64), builder).await {
+            error!("Failed to update scheduled event {}: {:?}", event_id, e);
+        }
+    }
+}
+
+/// 登録する募集（募集メッセージの送信後、データベースへの登録前）
+pub struct NewEvent<'a> {
+    pub guild_id: i64,
+    pub quest_name: &'a str,
+    pub event_date: DateTime<Utc>,
+    pub notes: Option<&'a str>,
+    pub message_url: String,
+}
+
+fn event_name(messages: &Messages, quest_name: &str) -> String {
+    messages.get_with_params("battle_recruitment.event_name", &[("quest_name", quest_name)])
+        .chars()
+        .take(MAX_EVENT_NAME_LENGTH)
+        .collect()
+}
+
+fn event_description(messages: &Messages, message_url: &str, notes: Option<&str>) -> String {
+    let link = messages.get_with_params("battle_recruitment.event_description", &[("url", message_url)]);
+    join_description(&link, notes)
+}
+
+/// 募集メッセージへの案内と補足をつなげる（上限を超える場合は補足を切り詰める）
+fn join_description(link: &str, notes: Option<&str>) -> String {
+    let description = match notes {
+        Some(notes) => format!("{}\n{}", link, notes),
+        None => link.to_string(),
+    };
+    description.chars().take(MAX_EVENT_DESCRIPTION_LENGTH).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_description() {
+        assert_eq!(join_description("https://example.com", None), "https://example.com");
+        assert_eq!(join_description("https://example.com", Some("フルオート可")), "https://example.com\nフルオート可");
+
+        let notes = "あ".repeat(MAX_EVENT_DESCRIPTION_LENGTH);
+        let description = join_description("https://example.com", Some(&notes));
+        assert_eq!(description.chars().count(), MAX_EVENT_DESCRIPTION_LENGTH);
+        assert!(description.starts_with("https://example.com\n"));
+    }
+}
@@ -1,75 +1,82 @@
-use tracing::{info, warn};
+use std::sync::Arc;
+use chrono::Utc;
+use poise::serenity_prelude::all::{Context, Message, ChannelId, MessageId};
+use tracing::{error, info};
 
-/// StartRecruitmentService - 募集開始処理を行うサービス
-/// 現在は仕様検討中のため、警告表示と正常終了パターンをエミュレートします
-pub struct StartRecruitmentService;
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::services::battle_recruitment::notify;
+use crate::services::battle_recruitment::template::{self, RecruitmentVariables};
+use crate::services::message::Messages;
+use crate::utils::discord_helper::get_participant_mentions;
+
+/// StartRecruitmentService - 開始時刻になった募集や、すぐに開始する募集の参加者を呼び出すサービス
+/// エラーは `messages`（サーバーの設定言語）で返す
+pub struct StartRecruitmentService {
+    db: Arc<Database>,
+}
 
 impl StartRecruitmentService {
-    pub fn new() -> Self {
-        Self
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
     }
 
     /// DBから募集情報を取得
-    pub async fn get_recruitment_from_db(&self, guild_id: u64, channel_id: u64, message_id: u64) -> Result<(), String> {
-        warn!("StartRecruitmentService::get_recruitment_from_db - 仕様検討中です");
-        info!("DB募集情報取得をエミュレート: guild_id={}, channel_id={}, message_id={}", 
-              guild_id, channel_id, message_id);
-        Ok(())
+    pub async fn get_recruitment_from_db(&self, messages: &Messages, guild_id: u64, channel_id: u64, message_id: u64) -> Result<BattleRecruitment, String> {
+        match self.db.battle_recruitment.get_by_message(guild_id as i64, channel_id as i64, message_id as i64).await {
+            Ok(Some(recruitment)) => Ok(recruitment),
+            Ok(None) => Err(messages.get("battle_recruitment.not_found")),
+            Err(e) => {
+                error!("Error fetching recruitment: {:?}", e);
+                Err(messages.get("battle_recruitment.database_error"))
+            }
+        }
+    }
+
+    /// 募集のクエスト名（クエストが削除されている場合は空）
+    pub async fn get_quest_name(&self, recruitment: &BattleRecruitment) -> String {
+        match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
+            Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+            Err(e) => {
+                error!("Error fetching quest: {:?}", e);
+                String::new()
+            }
+        }
     }
 
-    /// リアクションから参加者一覧取得
-    pub async fn get_participants_from_reactions(&self, message_id: u64) -> Result<Vec<String>, String> {
-        warn!("StartRecruitmentService::get_participants_from_reactions - 仕様検討中です");
-        info!("リアクション参加者取得をエミュレート: message_id={}", message_id);
-        
-        // エミュレート用の参加者リスト
-        let mock_participants = vec![
-            "<@444444444>".to_string(),
-            "<@555555555>".to_string(),
-            "<@666666666>".to_string(),
-            "<@777777777>".to_string(),
-        ];
-        Ok(mock_participants)
+    /// リアクションから参加者一覧取得（メンション）
+    pub async fn get_participants_from_reactions(&self, ctx: &Context, channel_id: u64, message_id: u64) -> Result<Vec<String>, String> {
+        get_participant_mentions(ctx, ChannelId::new(channel_id), MessageId::new(message_id)).await
     }
 
     /// 開始メッセージを作成（参加者へのメンション含む）
-    pub async fn create_start_message(&self, quest_name: &str, participants: &[String]) -> Result<String, String> {
-        warn!("StartRecruitmentService::create_start_message - 仕様検討中です");
-        info!("開始メッセージ作成をエミュレート");
-        
-        let participant_mentions = if participants.is_empty() {
-            "参加者がいません".to_string()
-        } else {
-            participants.join(" ")
-        };
-        
-        let message = format!(
-            "🚀 **クエスト出発時間です！** 🚀\n\n{}\n\n参加者の皆さん: {}\n\nクエストを開始してください！",
-            quest_name,
-            participant_mentions
-        );
-        Ok(message)
+    pub async fn create_start_message(&self, messages: &Messages, variables: &RecruitmentVariables<'_>) -> Result<String, String> {
+        Ok(variables.render(messages, template::START))
     }
 
-    /// 元の募集メッセージに返信する形でメッセージを送信
-    pub async fn send_start_reply(&self, channel_id: u64, original_message_id: u64, content: &str) -> Result<(), String> {
-        warn!("StartRecruitmentService::send_start_reply - 仕様検討中です");
-        info!("開始返信送信をエミュレート: channel_id={}, message_id={}", 
-              channel_id, original_message_id);
-        info!("送信内容: {}", content);
-        Ok(())
+    /// 募集スレッド、または元の募集メッセージに返信する形でメッセージを送信
+    pub async fn send_start_reply(&self, ctx: &Context, messages: &Messages, recruitment: &BattleRecruitment, content: String) -> Result<Message, String> {
+        notify(ctx, recruitment, content).await
+            .map_err(|e| {
+                error!("Error sending start message: {}", e);
+                messages.get("battle_recruitment.send_failed")
+            })
     }
 
     /// 募集を開始済み状態に更新
-    pub async fn mark_recruitment_as_started(&self, recruitment_id: i64) -> Result<(), String> {
-        warn!("StartRecruitmentService::mark_recruitment_as_started - 仕様検討中です");
-        info!("募集開始済み状態更新をエミュレート: recruitment_id={}", recruitment_id);
-        Ok(())
+    /// 他のプロセスが先に開始した場合は false
+    pub async fn mark_recruitment_as_started(&self, messages: &Messages, recruitment_id: i32) -> Result<bool, String> {
+        match self.db.battle_recruitment.mark_started(recruitment_id, Utc::now()).await {
+            Ok(started) => {
+                if started {
+                    info!("Recruitment started: id={}", recruitment_id);
+                }
+                Ok(started)
+            },
+            Err(e) => {
+                error!("Error marking recruitment as started: {:?}", e);
+                Err(messages.get("battle_recruitment.database_error"))
+            }
+        }
     }
 }
-
-impl Default for StartRecruitmentService {
-    fn default() -> Self {
-        Self::new()
-    }
-}
\ No newline at end of file
@@ -1,11 +1,19 @@
 use std::sync::Arc;
-use chrono::{DateTime, Local};
-use poise::serenity_prelude::all::{Context, Message, ChannelId, EditMessage, CreateEmbed};
+use chrono::{DateTime, FixedOffset};
+use poise::serenity_prelude::all::{Context, Message, ChannelId, GuildId, MessageId, UserId, EditMessage, CreateEmbed};
 use tracing::{error, info, warn};
 
-use crate::repository::Database;
 use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::repository::battle_recruitment_repository::RecruitmentDetails;
+use crate::services::message::Messages;
+use crate::services::settings::SettingsService;
+use crate::services::battle_recruitment::params::{EditParameter, CLEAR_NOTES};
+use crate::services::battle_recruitment::recruitment::ScheduledEventService;
+use crate::services::battle_recruitment::template::{self, RecruitmentVariables};
+use crate::services::battle_recruitment::{notify, with_creator, MAX_CAPACITY, MAX_NOTES_LENGTH};
 use crate::types::BattleType;
+use crate::utils::discord_helper::{get_participant_mentions, get_unique_reaction_users};
 
 pub struct UpdateRecruitmentService {
     db: Arc<Database>,
@@ -26,6 +34,8 @@ impl UpdateRecruitmentService {
         new_content: Option<String>,
         new_embed: Option<CreateEmbed>,
     ) -> Result<(), String> {
+        let messages = Messages::for_guild(&self.db, guild_id as i64).await;
+
         // 募集が存在するかチェック
         let _recruitment = match self.db.battle_recruitment.get_by_message(
             guild_id as i64,
@@ -35,11 +45,11 @@ impl UpdateRecruitmentService {
             Ok(Some(recruitment)) => recruitment,
             Ok(None) => {
                 warn!("Recruitment not found for message: {}", message_id);
-                return Err("募集が見つかりませんでした。".to_string());
+                return Err(messages.get("battle_recruitment.not_found"));
             },
             Err(e) => {
                 error!("Error fetching recruitment: {:?}", e);
-                return Err("データベースエラーが発生しました。".to_string());
+                return Err(messages.get("battle_recruitment.database_error"));
             }
         };
 
@@ -62,7 +72,7 @@ impl UpdateRecruitmentService {
             },
             Err(e) => {
                 error!("Failed to update message: {:?}", e);
-                Err("メッセージの更新に失敗しました。".to_string())
+                Err(messages.get("battle_recruitment.update_failed"))
             }
         }
     }
@@ -71,41 +81,44 @@ impl UpdateRecruitmentService {
     pub async fn update_participants_embed(
         &self,
         ctx: &Context,
-        guild_id: u64,
-        channel_id: u64,
-        message_id: u64,
+        recruitment: &BattleRecruitment,
         participant_count: usize,
-        capacity: usize,
     ) -> Result<(), String> {
+        let messages = Messages::for_guild(&self.db, recruitment.guild_id).await;
+        let capacity = recruitment.capacity as usize;
+
         let embed = CreateEmbed::new()
-            .title("参加者一覧")
-            .description(format!(
-                "現在の参加者: {}/{}",
-                participant_count,
-                capacity
-            ))
+            .title(messages.get("battle_recruitment.participants_title"))
+            .description(messages.get_with_params("battle_recruitment.participant_count", &[
+                ("count", &participant_count.to_string()),
+                ("capacity", &capacity.to_string()),
+            ]))
             .color(if participant_count >= capacity { 0x00ff00 } else { 0x0099ff });
+        let embed = with_creator(embed, &messages, recruitment.created_by);
 
         self.update_recruitment_message(
             ctx,
-            guild_id,
-            channel_id,
-            message_id,
+            recruitment.guild_id as u64,
+            recruitment.channel_id as u64,
+            recruitment.message_id as u64,
             None,
             Some(embed),
         ).await
     }
 
-    /// 募集の開催日時を更新する
-    pub async fn update_recruitment_date(
+    /// 投稿済みの募集の開催日時・戦闘スタイル・募集人数・補足を変更する
+    /// 変更を保存して募集メッセージを描画し直し（募集人数を変更した場合は参加者数の表示も）、参加者がいれば変更を通知する
+    /// リマインドと開始時刻の呼び出しは保存した開催日時から送信される
+    /// エラーは `messages`（実行したユーザーの言語）、メッセージはサーバーの設定言語で作成する
+    pub(crate) async fn edit_recruitment(
         &self,
         ctx: &Context,
-        guild_id: u64,
-        channel_id: u64,
-        message_id: u64,
-        new_date: DateTime<Local>,
-    ) -> Result<(), String> {
-        // 募集情報を取得
+        messages: &Messages,
+        params: EditParameter<'_>,
+    ) -> Result<BattleRecruitment, String> {
+        let EditParameter { guild_id, channel_id, message_id, event_date, battle_type, capacity, notes } = params;
+
+        // 1. 募集情報を取得
         let recruitment = match self.db.battle_recruitment.get_by_message(
             guild_id as i64,
             channel_id as i64,
@@ -113,50 +126,182 @@ impl UpdateRecruitmentService {
         ).await {
             Ok(Some(recruitment)) => recruitment,
             Ok(None) => {
-                return Err("募集が見つかりませんでした。".to_string());
+                return Err(messages.get("battle_recruitment.not_found"));
             },
             Err(e) => {
                 error!("Error fetching recruitment: {:?}", e);
-                return Err("データベースエラーが発生しました。".to_string());
+                return Err(messages.get("battle_recruitment.database_error"));
             }
         };
 
-        // 注意: 実際の実装ではクエスト名を取得する必要がある
-        let quest_name = "クエスト"; // 簡易版実装
+        // 2. 変更内容を確認
+        if event_date.is_none() && battle_type.is_none() && capacity.is_none() && notes.is_none() {
+            return Err(messages.get("battle_recruitment.edit_nothing"));
+        }
+        let details = edited_details(messages, &recruitment, event_date, battle_type, capacity, notes)?;
+        if details.expiry_date != recruitment.expiry_date && details.expiry_date <= chrono::Utc::now() {
+            return Err(messages.get("battle_recruitment.edit_past_date"));
+        }
+        let battle_type_changed = details.battle_type_id != recruitment.battle_type_id;
+        let capacity_changed = details.capacity != recruitment.capacity;
 
-        // 新しいメッセージ内容を作成
-        let new_content = format!(
-            "{}の参加者を募集します。\n開催日時：{}",
-            quest_name,
-            new_date.format("%m/%d %H:%M")
-        );
+        // 3. 変更を保存（開催日時が変わった場合はリマインドと開始時刻の呼び出しを送り直す）
+        let recruitment = self.db.battle_recruitment.update_details(recruitment.id, details).await
+            .map_err(|e| {
+                error!("Error updating recruitment: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
 
-        self.update_recruitment_message(
-            ctx,
-            guild_id,
-            channel_id,
-            message_id,
-            Some(new_content),
-            None,
-        ).await?;
+        // 4. 実際のクエスト名で募集メッセージを描画し直す
+        let post_messages = messages.in_guild_language();
+        let quest_name = match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
+            Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+            Err(e) => {
+                error!("Error fetching quest: {:?}", e);
+                return Err(messages.get("battle_recruitment.database_error"));
+            }
+        };
+        let timezone = SettingsService::new(self.db.clone()).timezone(recruitment.guild_id).await;
+        let content = RecruitmentVariables::for_recruitment(&recruitment, &quest_name, &[], timezone)
+            .render(&post_messages, template::BODY);
+        let channel = ChannelId::new(channel_id);
+        let message = channel.edit_message(&ctx.http, message_id, EditMessage::new().content(content)).await
+            .map_err(|e| {
+                error!("Failed to update message: {:?}", e);
+                messages.get("battle_recruitment.update_failed")
+            })?;
+
+        // 5. 戦闘スタイルが変わった場合は足りないリアクションを追加（既存の参加者のリアクションは残す）
+        if battle_type_changed {
+            for reaction in BattleType::from_value(recruitment.battle_type_id).unwrap_or(BattleType::Default).reactions() {
+                if let Err(e) = message.react(&ctx.http, reaction).await {
+                    error!("Error adding reaction: {:?}", e);
+                }
+            }
+        }
+
+        // 6. 募集人数が変わった場合は参加者数の表示を更新
+        let participants = get_participant_mentions(ctx, channel, MessageId::new(message_id)).await
+            .unwrap_or_else(|e| {
+                error!("Error getting participants: {}", e);
+                Vec::new()
+            });
+        if capacity_changed && let Err(e) = self.update_participants_embed(ctx, &recruitment, participants.len()).await {
+            // 変更は保存済みなので、エラーとせずログのみ
+            error!("Failed to update the participants embed: {}", e);
+        }
+
+        // 7. 参加者に変更を通知
+        if !participants.is_empty() {
+            let notice = RecruitmentVariables::for_recruitment(&recruitment, &quest_name, &participants, timezone)
+                .render(&post_messages, template::UPDATED);
+            if let Err(e) = notify(ctx, &recruitment, notice).await {
+                // 変更は保存済みなので、エラーとせずログのみ
+                error!("Failed to notify participants of the change: {}", e);
+            }
+        }
+
+        // 8. Discordのイベントに変更を反映
+        ScheduledEventService::new(self.db.clone()).sync(ctx, &recruitment).await;
+
+        info!("Recruitment edited: id={}, message_id={}", recruitment.id, message_id);
+        Ok(recruitment)
+    }
+
+    /// 募集者を参加者の1人に変更する
+    /// 募集メッセージの募集者の表示を書き換え、募集に変更を通知する
+    /// エラーは `messages`（実行したユーザーの言語）、メッセージはサーバーの設定言語で作成する
+    pub(crate) async fn transfer_recruitment(
+        &self,
+        ctx: &Context,
+        messages: &Messages,
+        recruitment: &BattleRecruitment,
+        new_owner: UserId,
+    ) -> Result<BattleRecruitment, String> {
+        if recruitment.is_created_by(new_owner.get()) {
+            return Err(messages.get("battle_recruitment.transfer_same"));
+        }
+
+        // 1. 新しい募集者が参加者か確認
+        let channel = ChannelId::new(recruitment.channel_id as u64);
+        let mut message = channel.message(&ctx.http, MessageId::new(recruitment.message_id as u64)).await
+            .map_err(|e| {
+                error!("Error fetching recruitment message: {:?}", e);
+                messages.get("battle_recruitment.not_found")
+            })?;
+        // HTTPで取得したメッセージにはサーバーIDが含まれない
+        message.guild_id = Some(GuildId::new(recruitment.guild_id as u64));
+        let participants = get_unique_reaction_users(ctx, &message).await
+            .map_err(|e| {
+                error!("Error getting participants: {}", e);
+                messages.get("battle_recruitment.update_failed")
+            })?;
+        if !participants.contains(&new_owner) {
+            return Err(messages.get("battle_recruitment.transfer_not_participant"));
+        }
+
+        // 2. 変更を保存
+        let transferred = self.db.battle_recruitment.set_creator(recruitment.id, new_owner.get() as i64).await
+            .map_err(|e| {
+                error!("Error transferring recruitment: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+
+        // 3. 募集者の表示を書き換える（参加者の表示はそのまま）
+        let post_messages = messages.in_guild_language();
+        let creator_label = post_messages.get("battle_recruitment.creator");
+        if let Some(current) = message.embeds.first() {
+            let mut embed = CreateEmbed::new();
+            if let Some(title) = &current.title {
+                embed = embed.title(title);
+            }
+            if let Some(description) = &current.description {
+                embed = embed.description(description);
+            }
+            if let Some(colour) = current.colour {
+                embed = embed.color(colour);
+            }
+            for field in current.fields.iter().filter(|field| field.name != creator_label) {
+                embed = embed.field(&field.name, &field.value, field.inline);
+            }
+            let embed = with_creator(embed, &post_messages, transferred.created_by);
+            if let Err(e) = message.edit(&ctx.http, EditMessage::new().embed(embed)).await {
+                // 変更は保存済みなので、エラーとせずログのみ
+                error!("Failed to update the creator of the recruitment message: {:?}", e);
+            }
+        }
 
-        info!("Updated recruitment date for message: {}", message_id);
-        Ok(())
+        // 4. 募集に変更を通知
+        let from = recruitment.created_by
+            .map(|user_id| format!("<@{}>", user_id))
+            .unwrap_or_else(|| post_messages.get("battle_recruitment.nobody"));
+        let notice = post_messages.get_with_params("battle_recruitment.transfer_notice", &[
+            ("from", &from),
+            ("to", &format!("<@{}>", new_owner.get())),
+        ]);
+        if let Err(e) = notify(ctx, &transferred, notice).await {
+            error!("Failed to notify the transfer: {}", e);
+        }
+
+        info!("Recruitment transferred: id={}, created_by={}", transferred.id, new_owner);
+        Ok(transferred)
     }
 
     /// 募集メッセージにステータス更新を追加
     pub async fn add_status_update(
         &self,
         ctx: &Context,
+        guild_id: u64,
         channel_id: u64,
         original_message_id: u64,
         status: &str,
     ) -> Result<Message, String> {
-        let status_message = format!(
-            "募集更新 (元メッセージ: {}): {}",
-            original_message_id,
-            status
-        );
+        let messages = Messages::for_guild(&self.db, guild_id as i64).await;
+
+        let status_message = messages.get_with_params("battle_recruitment.status_update", &[
+            ("message_id", &original_message_id.to_string()),
+            ("status", status),
+        ]);
 
         match ChannelId::from(channel_id).say(&ctx.http, status_message).await {
             Ok(message) => {
@@ -165,7 +310,7 @@ impl UpdateRecruitmentService {
             },
             Err(e) => {
                 error!("Failed to send status update: {:?}", e);
-                Err("ステータス更新の送信に失敗しました。".to_string())
+                Err(messages.get("battle_recruitment.status_update_failed"))
             }
         }
     }
@@ -179,10 +324,34 @@ impl UpdateRecruitmentService {
         message_id: u64,
         participants: Vec<String>, // ユーザーメンション
     ) -> Result<(), String> {
+        let messages = Messages::for_guild(&self.db, guild_id as i64).await;
+
+        let recruitment = match self.db.battle_recruitment.get_by_message(
+            guild_id as i64,
+            channel_id as i64,
+            message_id as i64,
+        ).await {
+            Ok(Some(recruitment)) => recruitment,
+            Ok(None) => {
+                return Err(messages.get("battle_recruitment.not_found"));
+            },
+            Err(e) => {
+                error!("Error fetching recruitment: {:?}", e);
+                return Err(messages.get("battle_recruitment.database_error"));
+            }
+        };
+        let quest_name = match self.db.quest.get_by_target_id(Some(recruitment.guild_id), recruitment.target_id).await {
+            Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+            Err(e) => {
+                error!("Error fetching quest: {:?}", e);
+                String::new()
+            }
+        };
+
         let embed = CreateEmbed::new()
-            .title("募集完了")
-            .description("メンバーが揃いました！")
-            .field("参加者", participants.join("\n"), false)
+            .title(messages.get("battle_recruitment.completed_title"))
+            .description(messages.get("battle_recruitment.members_gathered"))
+            .field(messages.get("battle_recruitment.participants_field"), participants.join("\n"), false)
             .color(0x00ff00)
             .timestamp(chrono::Utc::now());
 
@@ -191,15 +360,14 @@ impl UpdateRecruitmentService {
             guild_id,
             channel_id,
             message_id,
-            Some("✅ 募集完了".to_string()),
+            Some(messages.get("battle_recruitment.completed_content")),
             Some(embed),
         ).await?;
 
         // 完了通知メッセージを送信
-        let completion_message = format!(
-            "{}\nメンバーが揃いました！",
-            participants.join(" ")
-        );
+        let timezone = SettingsService::new(self.db.clone()).timezone(recruitment.guild_id).await;
+        let completion_message = RecruitmentVariables::for_recruitment(&recruitment, &quest_name, &participants, timezone)
+            .render(&messages, template::COMPLETED);
 
         match ChannelId::from(channel_id).say(&ctx.http, completion_message).await {
             Ok(_) => {
@@ -219,19 +387,22 @@ impl UpdateRecruitmentService {
     pub async fn urgent_update(
         &self,
         ctx: &Context,
+        guild_id: u64,
         channel_id: u64,
         message_id: u64,
         urgent_message: &str,
     ) -> Result<(), String> {
+        let messages = Messages::for_guild(&self.db, guild_id as i64).await;
+
         let embed = CreateEmbed::new()
-            .title("🚨 重要な更新")
+            .title(messages.get("battle_recruitment.urgent_title"))
             .description(urgent_message)
             .color(0xff0000)
             .timestamp(chrono::Utc::now());
 
         // 元のメッセージに緊急マークを追加
         let edit_builder = EditMessage::new()
-            .content("🚨 重要更新あり 🚨")
+            .content(messages.get("battle_recruitment.urgent_content"))
             .embed(embed);
 
         match ChannelId::from(channel_id).edit_message(&ctx.http, message_id, edit_builder).await {
@@ -241,8 +412,103 @@ impl UpdateRecruitmentService {
             },
             Err(e) => {
                 error!("Failed to apply urgent update: {:?}", e);
-                Err("緊急更新の適用に失敗しました。".to_string())
+                Err(messages.get("battle_recruitment.urgent_update_failed"))
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// 変更後の値（指定しなかった項目は現在の値）
+/// 開始済みの募集は変更できない
+fn edited_details(
+    messages: &Messages,
+    recruitment: &BattleRecruitment,
+    event_date: Option<DateTime<FixedOffset>>,
+    battle_type: Option<BattleType>,
+    capacity: Option<usize>,
+    notes: Option<&str>,
+) -> Result<RecruitmentDetails, String> {
+    // 開始済みの募集を変更すると、リマインドと開始時刻の呼び出しが送り直されてしまう
+    if recruitment.started_at.is_some() {
+        return Err(messages.get("battle_recruitment.already_started"));
+    }
+
+    let capacity = capacity.unwrap_or(recruitment.capacity as usize);
+    if !(1..=MAX_CAPACITY).contains(&capacity) {
+        return Err(messages.get_with_params("battle_recruitment.invalid_capacity", &[("max", &MAX_CAPACITY.to_string())]));
+    }
+
+    let notes = match notes.map(str::trim) {
+        Some(CLEAR_NOTES) => None,
+        Some(notes) if !notes.is_empty() => Some(notes.to_string()),
+        _ => recruitment.notes.clone(),
+    };
+    if notes.as_deref().is_some_and(|notes| notes.chars().count() > MAX_NOTES_LENGTH) {
+        return Err(messages.get_with_params("battle_recruitment.notes_too_long", &[("max", &MAX_NOTES_LENGTH.to_string())]));
+    }
+
+    Ok(RecruitmentDetails {
+        battle_type_id: battle_type.map(|battle_type| battle_type as i32).unwrap_or(recruitment.battle_type_id),
+        expiry_date: event_date.map(|date| date.with_timezone(&chrono::Utc)).unwrap_or(recruitment.expiry_date),
+        capacity: capacity as i32,
+        notes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn recruitment() -> BattleRecruitment {
+        BattleRecruitment {
+            id: 1,
+            guild_id: 1,
+            channel_id: 1,
+            message_id: 1,
+            target_id: 1,
+            battle_type_id: BattleType::Default as i32,
+            expiry_date: Utc::now() + Duration::hours(1),
+            recruit_end_message_id: None,
+            capacity: 6,
+            notes: Some("フルオート可".to_string()),
+            reminded_offset_minutes: None,
+            started_at: None,
+            cancelled_at: None,
+            created_by: None,
+            thread_id: None,
+            scheduled_event_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_edited_details() {
+        let messages = Messages::bundled(Some("ja"));
+        let recruitment = recruitment();
+
+        // 指定しなかった項目は変更しない
+        let details = edited_details(&messages, &recruitment, None, Some(BattleType::Fire), None, None).unwrap();
+        assert_eq!(details.battle_type_id, BattleType::Fire as i32);
+        assert_eq!(details.expiry_date, recruitment.expiry_date);
+        assert_eq!(details.capacity, 6);
+        assert_eq!(details.notes.as_deref(), Some("フルオート可"));
+
+        let details = edited_details(&messages, &recruitment, None, None, Some(4), Some(CLEAR_NOTES)).unwrap();
+        assert_eq!(details.capacity, 4);
+        assert_eq!(details.notes, None);
+
+        assert!(edited_details(&messages, &recruitment, None, None, Some(0), None).is_err());
+        assert!(edited_details(&messages, &recruitment, None, None, Some(MAX_CAPACITY + 1), None).is_err());
+        assert!(edited_details(&messages, &recruitment, None, None, None, Some(&"あ".repeat(MAX_NOTES_LENGTH + 1))).is_err());
+
+        let mut started = recruitment.clone();
+        started.started_at = Some(Utc::now());
+        let future = (Utc::now() + Duration::days(1)).fixed_offset();
+        assert_eq!(
+            edited_details(&messages, &started, Some(future), None, None, None).err(),
+            Some(messages.get("battle_recruitment.already_started")),
+        );
+    }
+}
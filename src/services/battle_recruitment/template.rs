@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use chrono::{DateTime, FixedOffset};
+
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::services::message::Messages;
+use crate::types::BattleType;
+
+/// 募集メッセージ本文（message_texts の message_id）
+pub const BODY: &str = "battle_recruitment.template.body";
+/// 募集完了時の通知
+pub const COMPLETED: &str = "battle_recruitment.template.completed";
+/// 募集キャンセル時の通知
+pub const CANCELLED: &str = "battle_recruitment.template.cancelled";
+/// 開始時刻の呼び出し
+pub const START: &str = "battle_recruitment.template.start";
+/// 開始前のリマインド
+pub const REMINDER: &str = "battle_recruitment.template.reminder";
+/// 募集内容を変更した時の参加者への通知
+pub const UPDATED: &str = "battle_recruitment.template.updated";
+
+/// サーバーごとに変更できるテンプレート
+pub const TEMPLATE_KEYS: [&str; 6] = [BODY, COMPLETED, CANCELLED, START, REMINDER, UPDATED];
+
+/// テンプレートで `${name}` として使える変数
+pub const VARIABLES: [&str; 8] = [
+    "quest_name",
+    "battle_type",
+    "event_time",
+    "element_prompt",
+    "participants",
+    "count",
+    "capacity",
+    "notes",
+];
+
+/// 開催日時の表示形式（サーバーのタイムゾーンで表示する）
+pub(crate) const EVENT_TIME_FORMAT: &str = "%m/%d %H:%M";
+
+/// 募集1件分のテンプレート変数
+pub struct RecruitmentVariables<'a> {
+    pub quest_name: &'a str,
+    pub battle_type: BattleType,
+    /// サーバーのタイムゾーンの開催日時
+    pub event_time: Option<DateTime<FixedOffset>>,
+    /// 参加者のメンション
+    pub participants: &'a [String],
+    pub capacity: usize,
+    /// 募集テンプレートの補足
+    pub notes: Option<&'a str>,
+}
+
+impl<'a> RecruitmentVariables<'a> {
+    /// 登録済みの募集の変数（開催日時は `timezone` で表示する）
+    pub fn for_recruitment(recruitment: &'a BattleRecruitment, quest_name: &'a str, participants: &'a [String], timezone: FixedOffset) -> Self {
+        Self {
+            quest_name,
+            battle_type: BattleType::from_value(recruitment.battle_type_id).unwrap_or(BattleType::Default),
+            event_time: Some(recruitment.expiry_date.with_timezone(&timezone)),
+            participants,
+            capacity: recruitment.capacity as usize,
+            notes: recruitment.notes.as_deref(),
+        }
+    }
+
+    /// 変数名と値の一覧
+    /// `element_prompt` は全属性募集の場合のみ、改行を含む属性選択の案内になる
+    /// `notes` も補足がある場合のみ、改行に続けて補足になる
+    pub fn to_map(&self, messages: &Messages) -> HashMap<String, String> {
+        let element_prompt = if self.battle_type == BattleType::AllElement {
+            format!("\n{}", messages.get("battle_recruitment.choose_element"))
+        } else {
+            String::new()
+        };
+        let participants = if self.participants.is_empty() {
+            messages.get("battle_recruitment.nobody")
+        } else {
+            self.participants.join(" ")
+        };
+        let event_time = self.event_time
+            .map(|event_time| event_time.format(EVENT_TIME_FORMAT).to_string())
+            .unwrap_or_default();
+        let notes = self.notes
+            .map(|notes| format!("\n{}", notes))
+            .unwrap_or_default();
+
+        HashMap::from([
+            ("quest_name".to_string(), self.quest_name.to_string()),
+            ("battle_type".to_string(), self.battle_type.name().to_string()),
+            ("event_time".to_string(), event_time),
+            ("element_prompt".to_string(), element_prompt),
+            ("participants".to_string(), participants),
+            ("count".to_string(), self.participants.len().to_string()),
+            ("capacity".to_string(), self.capacity.to_string()),
+            ("notes".to_string(), notes),
+        ])
+    }
+
+    /// テンプレートを描画する
+    pub fn render(&self, messages: &Messages, key: &str) -> String {
+        messages.render(key, &self.to_map(messages))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::models::message_text::MessageText;
+    use crate::services::message::Language;
+
+    fn messages(guild_texts: Vec<(&str, &str)>) -> Messages {
+        let guild_texts = guild_texts.into_iter()
+            .map(|(message_id, message_jp)| (message_id.to_string(), MessageText {
+                id: 1,
+                guild_id: 1,
+                message_id: message_id.to_string(),
+                message_jp: message_jp.to_string(),
+                message_en: None,
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+            }))
+            .collect();
+        Messages::new(Arc::new(guild_texts), Language::Japanese)
+    }
+
+    fn variables(participants: &[String]) -> RecruitmentVariables<'_> {
+        RecruitmentVariables {
+            quest_name: "ルシHL",
+            battle_type: BattleType::AllElement,
+            // 12:00 UTC はサーバーのタイムゾーン（日本時間）で 21:00
+            event_time: Some(chrono::Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap()
+                .with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap())),
+            participants,
+            capacity: 6,
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_bundled_templates() {
+        let messages = messages(Vec::new());
+        let participants = vec!["<@1>".to_string(), "<@2>".to_string()];
+
+        assert_eq!(
+            variables(&[]).render(&messages, BODY),
+            "ルシHLの参加者を募集します。\n参加属性を選んでください\n開催日時：10/18 21:00"
+        );
+        assert_eq!(variables(&participants).render(&messages, COMPLETED), "<@1> <@2>\n募集が完了しました！");
+        assert!(RecruitmentVariables { notes: Some("フルオート可"), ..variables(&[]) }
+            .render(&messages, BODY)
+            .ends_with("開催日時：10/18 21:00\nフルオート可"));
+        assert!(variables(&[]).render(&messages, CANCELLED).contains("参加予定だった方: なし"));
+    }
+
+    #[test]
+    fn test_guild_template() {
+        let participants = vec!["<@1>".to_string()];
+        let messages = messages(vec![(BODY, "【${battle_type}】${quest_name} ${count}/${capacity} ${event_time}")]);
+
+        assert_eq!(variables(&participants).render(&messages, BODY), "【全属性】ルシHL 1/6 10/18 21:00");
+    }
+
+    #[test]
+    fn test_unknown_variable_uses_bundled_template() {
+        let messages = messages(vec![(START, "${quest_name} ${unknown}")]);
+
+        assert!(variables(&[]).render(&messages, START).starts_with("🚀"));
+    }
+
+    #[test]
+    fn test_bundled_templates_use_known_variables() {
+        let variables: HashMap<String, String> = VARIABLES.iter()
+            .map(|name| (name.to_string(), String::new()))
+            .collect();
+
+        for language in [Language::Japanese, Language::English] {
+            for key in TEMPLATE_KEYS {
+                let template = crate::services::message::message_service::bundled_message(key, language).unwrap();
+                assert!(crate::services::environment::environment::replace_variables(&template, &variables).is_ok(), "{}", key);
+            }
+        }
+    }
+}
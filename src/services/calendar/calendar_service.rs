@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::all::{GuildId, Http, UserId};
+use tracing::{error, info};
+
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::services::battle_recruitment::EVENT_DURATION_MINUTES;
+use crate::services::battle_recruitment::recruitment::{JoinedRecruitmentService, ListRecruitmentService};
+use crate::services::message::Messages;
+use crate::types::{BattleType, CalendarScope};
+use crate::utils::discord_helper::is_guild_member;
+use super::ics::{self, CalendarEvent};
+
+/// カレンダーに含める募集の範囲（今後の日数）
+pub const CALENDAR_DAYS: u32 = 60;
+
+/// カレンダーに含める募集の上限
+const CALENDAR_LIMIT: u64 = 200;
+
+/// CalendarService - 開始前の募集を iCalendar（.ics）にするサービス
+/// 予定の内容はサーバーの設定言語、エラーは `messages`（実行したユーザーの言語）で作成する
+pub struct CalendarService {
+    db: Arc<Database>,
+}
+
+impl CalendarService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// 開始前の募集のカレンダーと、含めた募集の件数
+    /// `Joined` の場合はメンバーが作成または参加した募集のみ
+    pub async fn render(&self, messages: &Messages, guild_id: i64, user_id: i64, scope: CalendarScope, now: DateTime<Utc>) -> Result<(String, usize), String> {
+        let recruitments = match scope {
+            CalendarScope::Guild => {
+                let filter = ListRecruitmentService::filter(guild_id, None, None, Some(CALENDAR_DAYS), now);
+                let recruitments = self.db.battle_recruitment.search(&filter, 0, CALENDAR_LIMIT).await
+                    .map_err(|e| {
+                        error!("Error fetching recruitments: {:?}", e);
+                        messages.get("battle_recruitment.database_error")
+                    })?;
+                self.with_quest_names(guild_id, recruitments).await
+            },
+            CalendarScope::Joined => {
+                JoinedRecruitmentService::new(self.db.clone()).list(messages, guild_id, user_id).await?
+                    .upcoming
+                    .into_iter()
+                    .map(|item| (item.recruitment, item.quest_name))
+                    .collect()
+            },
+        };
+
+        let post_messages = messages.in_guild_language();
+        let events: Vec<CalendarEvent> = recruitments.iter()
+            // 開催中の募集までを含める
+            .filter(|(recruitment, _)| recruitment.expiry_date + Duration::minutes(EVENT_DURATION_MINUTES) > now)
+            .map(|(recruitment, quest_name)| event(&post_messages, recruitment, quest_name))
+            .collect();
+        let name = post_messages.get(match scope {
+            CalendarScope::Guild => "calendar.name_guild",
+            CalendarScope::Joined => "calendar.name_joined",
+        });
+        Ok((ics::render(&name, &events), events.len()))
+    }
+
+    /// メンバーのカレンダーの配信URLのトークン（ない場合や `reset` の場合は作成する）
+    pub async fn feed_token(&self, messages: &Messages, guild_id: i64, user_id: i64, scope: CalendarScope, reset: bool) -> Result<String, String> {
+        let database_error = |e| {
+            error!("Error saving calendar feed: {:?}", e);
+            messages.get("battle_recruitment.database_error")
+        };
+
+        if !reset && let Some(feed) = self.db.calendar_feed.get(guild_id, user_id, scope.key()).await.map_err(database_error)? {
+            return Ok(feed.token);
+        }
+        let token = new_token()
+            .map_err(|e| {
+                error!("Error generating calendar feed token: {}", e);
+                messages.get("errors.internal_error")
+            })?;
+        let feed = self.db.calendar_feed.save(guild_id, user_id, scope.key(), &token).await
+            .map_err(database_error)?;
+        info!("Calendar feed created: guild_id={}, user_id={}, scope={}", guild_id, user_id, feed.scope);
+        Ok(feed.token)
+    }
+
+    /// 配信URLのトークンに対応するカレンダー（無効なトークンや、サーバーから退出したメンバーのトークンは None）
+    pub async fn render_feed(&self, http: &Http, token: &str, now: DateTime<Utc>) -> Result<Option<String>, String> {
+        let feed = self.db.calendar_feed.get_by_token(token).await
+            .map_err(|e| format!("Database error: {}", e))?;
+        let Some(feed) = feed else {
+            return Ok(None);
+        };
+        let Some(scope) = CalendarScope::from_key(&feed.scope) else {
+            return Ok(None);
+        };
+        if !is_guild_member(http, GuildId::new(feed.guild_id as u64), UserId::new(feed.user_id as u64)).await? {
+            return Ok(None);
+        }
+
+        let messages = Messages::for_guild(&self.db, feed.guild_id).await;
+        let (calendar, _) = self.render(&messages, feed.guild_id, feed.user_id, scope, now).await?;
+        Ok(Some(calendar))
+    }
+
+    /// 募集とクエスト名（クエストが削除されている場合は空）
+    async fn with_quest_names(&self, guild_id: i64, recruitments: Vec<BattleRecruitment>) -> Vec<(BattleRecruitment, String)> {
+        let mut quest_names: HashMap<i32, String> = HashMap::new();
+        let mut items = Vec::with_capacity(recruitments.len());
+        for recruitment in recruitments {
+            if let Entry::Vacant(entry) = quest_names.entry(recruitment.target_id) {
+                let quest_name = match self.db.quest.get_by_target_id(Some(guild_id), recruitment.target_id).await {
+                    Ok(quest) => quest.map(|quest| quest.quest_name).unwrap_or_default(),
+                    Err(e) => {
+                        error!("Error fetching quest: {:?}", e);
+                        String::new()
+                    }
+                };
+                entry.insert(quest_name);
+            }
+            let quest_name = quest_names[&recruitment.target_id].clone();
+            items.push((recruitment, quest_name));
+        }
+        items
+    }
+}
+
+/// 募集1件の予定
+fn event(messages: &Messages, recruitment: &BattleRecruitment, quest_name: &str) -> CalendarEvent {
+    let battle_type = BattleType::from_value(recruitment.battle_type_id).unwrap_or(BattleType::Default);
+    let url = recruitment.message_url();
+    let mut description = messages.get_with_params("calendar.description", &[("url", &url)]);
+    if let Some(notes) = &recruitment.notes {
+        description = format!("{}\n{}", notes, description);
+    }
+
+    CalendarEvent {
+        uid: format!("recruitment-{}@gbf-discord-bot", recruitment.id),
+        summary: messages.get_with_params("calendar.summary", &[
+            ("quest_name", quest_name),
+            ("battle_type", battle_type.name()),
+        ]),
+        description,
+        url,
+        start: recruitment.expiry_date,
+        end: recruitment.expiry_date + Duration::minutes(EVENT_DURATION_MINUTES),
+        stamp: recruitment.updated_at,
+    }
+}
+
+/// 推測できない配信URLのトークン（OSの乱数による128ビットの16進数）
+fn new_token() -> Result<String, getrandom::Error> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes)?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_token() {
+        let token = new_token().unwrap();
+        assert_eq!(token.len(), 32);
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(token, new_token().unwrap());
+    }
+}
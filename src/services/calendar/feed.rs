@@ -0,0 +1,35 @@
+/// 配信URLのパス（`/calendar/<トークン>.ics`）
+pub fn feed_path(token: &str) -> String {
+    format!("/calendar/{}.ics", token)
+}
+
+/// HTTPリクエストの先頭行から配信URLのトークンを取り出す
+/// GET以外や、配信URLではないパスは None
+pub fn parse_request_line(line: &str) -> Option<&str> {
+    let mut parts = line.split_whitespace();
+    let (method, path) = (parts.next()?, parts.next()?);
+    if method != "GET" {
+        return None;
+    }
+    // 問い合わせ文字列は無視する
+    let path = path.split('?').next()?;
+    let token = path.strip_prefix("/calendar/")?.strip_suffix(".ics")?;
+    (!token.is_empty() && token.chars().all(|c| c.is_ascii_hexdigit())).then_some(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request_line() {
+        assert_eq!(parse_request_line("GET /calendar/0123abcd.ics HTTP/1.1"), Some("0123abcd"));
+        assert_eq!(parse_request_line("GET /calendar/0123abcd.ics?x=1 HTTP/1.1"), Some("0123abcd"));
+        assert_eq!(parse_request_line(&format!("GET {} HTTP/1.1", feed_path("ff"))), Some("ff"));
+        assert_eq!(parse_request_line("POST /calendar/0123abcd.ics HTTP/1.1"), None);
+        assert_eq!(parse_request_line("GET /calendar/../etc.ics HTTP/1.1"), None);
+        assert_eq!(parse_request_line("GET /calendar/.ics HTTP/1.1"), None);
+        assert_eq!(parse_request_line("GET / HTTP/1.1"), None);
+        assert_eq!(parse_request_line(""), None);
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+
+/// 1行の上限（RFC 5545 では改行を除いて75オクテット）
+const MAX_LINE_OCTETS: usize = 75;
+
+/// UTCの日時の書式（RFC 5545 の DATE-TIME）
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// カレンダーの予定1件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    /// 予定を更新しても変わらない識別子
+    pub uid: String,
+    pub summary: String,
+    pub description: String,
+    pub url: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// 予定を最後に変更した日時
+    pub stamp: DateTime<Utc>,
+}
+
+/// 予定の一覧を iCalendar（.ics）の文字列にする
+pub fn render(calendar_name: &str, events: &[CalendarEvent]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//gbf_discord_bot_rs//recruitments//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape(calendar_name)),
+    ];
+    for event in events {
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:{}", event.uid),
+            format!("DTSTAMP:{}", event.stamp.format(DATE_TIME_FORMAT)),
+            format!("DTSTART:{}", event.start.format(DATE_TIME_FORMAT)),
+            format!("DTEND:{}", event.end.format(DATE_TIME_FORMAT)),
+            format!("SUMMARY:{}", escape(&event.summary)),
+            format!("DESCRIPTION:{}", escape(&event.description)),
+            format!("URL:{}", event.url),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+/// テキストの値で特別な意味を持つ文字をエスケープする
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {},
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 長い行を折り返す（続きの行は空白で始める）
+/// マルチバイト文字の途中では折り返さない
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // 先頭の空白も1オクテットに数える
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+        assert_eq!(escape("フルオート可\r\n"), "フルオート可\\n");
+    }
+
+    #[test]
+    fn test_fold() {
+        let short = "SUMMARY:ルシファー HARD";
+        assert_eq!(fold(short), short);
+
+        let long = format!("DESCRIPTION:{}", "あ".repeat(40));
+        let folded = fold(&long);
+        assert!(folded.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert_eq!(folded.replace("\r\n ", ""), long);
+    }
+
+    #[test]
+    fn test_render() {
+        let start = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+        let events = vec![CalendarEvent {
+            uid: "recruitment-1@gbf-discord-bot".to_string(),
+            summary: "ルシファー HARD（全属性）".to_string(),
+            description: "https://discord.com/channels/1/2/3".to_string(),
+            url: "https://discord.com/channels/1/2/3".to_string(),
+            start,
+            end: start + chrono::Duration::hours(1),
+            stamp: start,
+        }];
+
+        let ics = render("募集", &events);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ics.contains("\r\nDTSTART:20261018T120000Z\r\nDTEND:20261018T130000Z\r\n"));
+        assert!(ics.contains("\r\nSUMMARY:ルシファー HARD（全属性）\r\n"));
+        assert_eq!(render("募集", &[]).matches("BEGIN:VEVENT").count(), 0);
+    }
+}
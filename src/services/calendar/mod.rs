@@ -0,0 +1,4 @@
+pub mod calendar_service;
+pub mod feed;
+pub mod ics;
+pub use calendar_service::CalendarService;
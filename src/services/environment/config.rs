@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// `environments` テーブルでの指定にかかわらず、値を表示しないキー
+pub const SECRET_KEYS: [&str; 2] = ["DISCORD_TOKEN", "DATABASE_URL"];
+
+/// 秘密の値を表示する際の文字列（長さも伏せる）
+pub const MASKED_VALUE: &str = "********";
+
+/// 設定値の検証エラー（不正・不足しているキーをすべて保持する）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub problems: Vec<String>,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines: Vec<String> = self.problems.iter().map(|problem| format!("- {}", problem)).collect();
+        write!(f, "Invalid configuration:\n{}", lines.join("\n"))
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// `.env` と `environments` テーブルから組み立てた型付きの設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// DISCORD_TOKEN
+    pub discord_token: String,
+    /// DATABASE_URL
+    pub database_url: String,
+    /// GUILD_ID: コマンドをサーバー単位で登録する場合の登録先
+    pub guild_id: Option<u64>,
+    /// REGISTER_COMMANDS_GLOBALLY: false の場合は GUILD_ID のサーバーにだけコマンドを登録する
+    pub register_commands_globally: bool,
+    /// BOT_OWNER_IDS: カンマ区切りのユーザーID
+    pub owner_ids: Vec<u64>,
+    /// DB_MAX_CONNECTIONS
+    pub db_max_connections: u32,
+    /// DB_MIN_CONNECTIONS
+    pub db_min_connections: u32,
+    /// DB_CONNECT_TIMEOUT: 接続・コネクション取得のタイムアウト
+    pub db_connect_timeout: Duration,
+    /// DB_IDLE_TIMEOUT
+    pub db_idle_timeout: Duration,
+    /// DB_MAX_LIFETIME
+    pub db_max_lifetime: Duration,
+    /// DB_SQL_LOGGING
+    pub db_sql_logging: bool,
+    /// CONFIG_HOT_RELOAD: environments / message_texts の変更を LISTEN/NOTIFY で検知して読み込み直す
+    pub config_hot_reload: bool,
+    /// CONFIG_RELOAD_DEBOUNCE: 通知が途切れてから読み込み直すまでの待ち時間
+    pub config_reload_debounce: Duration,
+    /// CALENDAR_FEED_ADDR: カレンダーの配信URLを提供するHTTPサーバーの待ち受けアドレス（未設定の場合は提供しない）
+    pub calendar_feed_addr: Option<SocketAddr>,
+    /// CALENDAR_FEED_URL: 配信URLとして案内するHTTPサーバーの公開URL（末尾の / は除く）
+    pub calendar_feed_url: Option<String>,
+}
+
+impl Config {
+    /// キーと値の一覧から設定を組み立てる
+    /// 途中で止めずにすべてのキーを検証し、問題をまとめて返す
+    pub fn from_map(values: &HashMap<String, String>) -> Result<Self, ConfigError> {
+        let mut reader = Reader { values, problems: Vec::new() };
+
+        let config = Config {
+            discord_token: reader.required("DISCORD_TOKEN"),
+            database_url: reader.required("DATABASE_URL"),
+            guild_id: reader.optional("GUILD_ID", parse_id),
+            register_commands_globally: reader.or_default("REGISTER_COMMANDS_GLOBALLY", parse_bool, true),
+            owner_ids: reader.or_default("BOT_OWNER_IDS", parse_id_list, Vec::new()),
+            db_max_connections: reader.or_default("DB_MAX_CONNECTIONS", parse_number, 20),
+            db_min_connections: reader.or_default("DB_MIN_CONNECTIONS", parse_number, 5),
+            db_connect_timeout: reader.or_default("DB_CONNECT_TIMEOUT", parse_duration, Duration::from_secs(8)),
+            db_idle_timeout: reader.or_default("DB_IDLE_TIMEOUT", parse_duration, Duration::from_secs(8)),
+            db_max_lifetime: reader.or_default("DB_MAX_LIFETIME", parse_duration, Duration::from_secs(8)),
+            db_sql_logging: reader.or_default("DB_SQL_LOGGING", parse_bool, true),
+            config_hot_reload: reader.or_default("CONFIG_HOT_RELOAD", parse_bool, true),
+            config_reload_debounce: reader.or_default("CONFIG_RELOAD_DEBOUNCE", parse_duration, Duration::from_secs(2)),
+            calendar_feed_addr: reader.optional("CALENDAR_FEED_ADDR", parse_socket_addr),
+            calendar_feed_url: reader.optional("CALENDAR_FEED_URL", parse_base_url),
+        };
+
+        // キーをまたいだ検証
+        if !config.register_commands_globally && config.guild_id.is_none() {
+            reader.problems.push("GUILD_ID: required when REGISTER_COMMANDS_GLOBALLY is false".to_string());
+        }
+        if config.db_max_connections == 0 {
+            reader.problems.push("DB_MAX_CONNECTIONS: must be at least 1".to_string());
+        }
+        if config.calendar_feed_addr.is_some() && config.calendar_feed_url.is_none() {
+            reader.problems.push("CALENDAR_FEED_URL: required when CALENDAR_FEED_ADDR is set".to_string());
+        }
+        if config.db_min_connections > config.db_max_connections {
+            reader.problems.push(format!(
+                "DB_MIN_CONNECTIONS: {} is greater than DB_MAX_CONNECTIONS ({})",
+                config.db_min_connections, config.db_max_connections
+            ));
+        }
+
+        if reader.problems.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError { problems: reader.problems })
+        }
+    }
+}
+
+struct Reader<'a> {
+    values: &'a HashMap<String, String>,
+    problems: Vec<String>,
+}
+
+impl Reader<'_> {
+    fn value(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|value| value.trim()).filter(|value| !value.is_empty())
+    }
+
+    fn required(&mut self, key: &str) -> String {
+        match self.value(key) {
+            Some(value) => value.to_string(),
+            None => {
+                self.problems.push(format!("{}: missing", key));
+                String::new()
+            }
+        }
+    }
+
+    fn optional<T>(&mut self, key: &str, parse: fn(&str) -> Result<T, String>) -> Option<T> {
+        let value = self.value(key)?;
+        match parse(value) {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                self.problems.push(format!("{}: {}", key, e));
+                None
+            }
+        }
+    }
+
+    fn or_default<T>(&mut self, key: &str, parse: fn(&str) -> Result<T, String>, default: T) -> T {
+        self.optional(key, parse).unwrap_or(default)
+    }
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Ok(true),
+        "false" | "0" | "no" | "off" => Ok(false),
+        _ => Err(format!("'{}' is not a boolean (true/false)", value)),
+    }
+}
+
+fn parse_number<T: FromStr>(value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("'{}' is not a valid number", value))
+}
+
+/// DiscordのID（Snowflake）
+fn parse_id(value: &str) -> Result<u64, String> {
+    value.parse::<u64>()
+        .ok()
+        .filter(|id| *id > 0)
+        .ok_or_else(|| format!("'{}' is not a valid Discord ID", value))
+}
+
+fn parse_id_list(value: &str) -> Result<Vec<u64>, String> {
+    value.split(',')
+        .map(|id| id.trim())
+        .filter(|id| !id.is_empty())
+        .map(parse_id)
+        .collect()
+}
+
+/// "0.0.0.0:8080" の形式
+fn parse_socket_addr(value: &str) -> Result<SocketAddr, String> {
+    value.parse().map_err(|_| format!("'{}' is not a valid address (e.g. 0.0.0.0:8080)", value))
+}
+
+/// http:// または https:// で始まるURL
+fn parse_base_url(value: &str) -> Result<String, String> {
+    if value.starts_with("http://") || value.starts_with("https://") {
+        Ok(value.trim_end_matches('/').to_string())
+    } else {
+        Err(format!("'{}' is not a valid URL (e.g. https://bot.example.com)", value))
+    }
+}
+
+/// "500ms", "8s", "5m", "1h" または秒数のみを受け付ける
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("'{}' is not a valid duration (e.g. 500ms, 8s, 5m, 1h)", value);
+    let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount.parse().map_err(|_| invalid())?;
+
+    match unit.trim() {
+        "ms" => Ok(Duration::from_millis(amount)),
+        "" | "s" => Ok(Duration::from_secs(amount)),
+        "m" => Ok(Duration::from_secs(amount * 60)),
+        "h" => Ok(Duration::from_secs(amount * 60 * 60)),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    fn required_values() -> HashMap<String, String> {
+        values(&[("DISCORD_TOKEN", "token"), ("DATABASE_URL", "postgres://localhost/gbf_bot")])
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::from_map(&required_values()).unwrap();
+        assert_eq!(config.discord_token, "token");
+        assert_eq!(config.guild_id, None);
+        assert!(config.register_commands_globally);
+        assert!(config.owner_ids.is_empty());
+        assert_eq!(config.db_max_connections, 20);
+        assert_eq!(config.db_min_connections, 5);
+        assert_eq!(config.db_connect_timeout, Duration::from_secs(8));
+        assert!(config.db_sql_logging);
+        assert!(config.config_hot_reload);
+        assert_eq!(config.config_reload_debounce, Duration::from_secs(2));
+        assert_eq!(config.calendar_feed_addr, None);
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let mut map = required_values();
+        map.extend(values(&[
+            ("GUILD_ID", "123456789012345678"),
+            ("REGISTER_COMMANDS_GLOBALLY", "no"),
+            ("BOT_OWNER_IDS", "1, 2,3"),
+            ("DB_MAX_CONNECTIONS", "10"),
+            ("DB_MIN_CONNECTIONS", "2"),
+            ("DB_CONNECT_TIMEOUT", "500ms"),
+            ("DB_IDLE_TIMEOUT", "5m"),
+            ("DB_MAX_LIFETIME", "1h"),
+            ("DB_SQL_LOGGING", "off"),
+        ]));
+
+        let config = Config::from_map(&map).unwrap();
+        assert_eq!(config.guild_id, Some(123456789012345678));
+        assert!(!config.register_commands_globally);
+        assert_eq!(config.owner_ids, vec![1, 2, 3]);
+        assert_eq!(config.db_max_connections, 10);
+        assert_eq!(config.db_connect_timeout, Duration::from_millis(500));
+        assert_eq!(config.db_idle_timeout, Duration::from_secs(300));
+        assert_eq!(config.db_max_lifetime, Duration::from_secs(3600));
+        assert!(!config.db_sql_logging);
+    }
+
+    #[test]
+    fn test_lists_every_problem() {
+        let map = values(&[
+            ("REGISTER_COMMANDS_GLOBALLY", "maybe"),
+            ("BOT_OWNER_IDS", "1,abc"),
+            ("DB_MAX_CONNECTIONS", "-1"),
+            ("DB_CONNECT_TIMEOUT", "8 days"),
+        ]);
+
+        let error = Config::from_map(&map).unwrap_err();
+        let keys: Vec<&str> = error.problems.iter().map(|p| p.split(':').next().unwrap()).collect();
+        assert_eq!(keys, vec![
+            "DISCORD_TOKEN",
+            "DATABASE_URL",
+            "REGISTER_COMMANDS_GLOBALLY",
+            "BOT_OWNER_IDS",
+            "DB_MAX_CONNECTIONS",
+            "DB_CONNECT_TIMEOUT",
+        ]);
+        assert!(error.to_string().contains("- DISCORD_TOKEN: missing"));
+    }
+
+    #[test]
+    fn test_cross_key_validation() {
+        let mut map = required_values();
+        map.extend(values(&[
+            ("REGISTER_COMMANDS_GLOBALLY", "false"),
+            ("DB_MAX_CONNECTIONS", "2"),
+            ("DB_MIN_CONNECTIONS", "3"),
+        ]));
+
+        let error = Config::from_map(&map).unwrap_err();
+        assert_eq!(error.problems.len(), 2);
+        assert!(error.problems[0].starts_with("GUILD_ID"));
+        assert!(error.problems[1].starts_with("DB_MIN_CONNECTIONS"));
+    }
+
+    #[test]
+    fn test_calendar_feed() {
+        let mut map = required_values();
+        map.extend(values(&[
+            ("CALENDAR_FEED_ADDR", "0.0.0.0:8080"),
+            ("CALENDAR_FEED_URL", "https://bot.example.com/"),
+        ]));
+        let config = Config::from_map(&map).unwrap();
+        assert_eq!(config.calendar_feed_addr, Some("0.0.0.0:8080".parse().unwrap()));
+        assert_eq!(config.calendar_feed_url.as_deref(), Some("https://bot.example.com"));
+
+        map.remove("CALENDAR_FEED_URL");
+        let error = Config::from_map(&map).unwrap_err();
+        assert!(error.problems[0].starts_with("CALENDAR_FEED_URL"));
+
+        map.insert("CALENDAR_FEED_URL".to_string(), "bot.example.com".to_string());
+        map.insert("CALENDAR_FEED_ADDR".to_string(), "8080".to_string());
+        assert_eq!(Config::from_map(&map).unwrap_err().problems.len(), 2);
+    }
+}
@@ -121,35 +121,56 @@ impl Environment {
         vars.clear();
     }
     
+    /// 変数をまとめて置き換える（検証済みの値を一度に反映するため）
+    pub async fn replace_all(&self, variables: HashMap<String, String>) {
+        let mut vars = self.variables.write().await;
+        *vars = variables;
+    }
+
     pub async fn get_all(&self) -> HashMap<String, String> {
         let vars = self.variables.read().await;
         vars.clone()
     }
     
     pub async fn replace_variables(&self, text: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let re = Regex::new(r"\$\{([A-Za-z0-9_\-\.]+)\}").unwrap();
         let vars = self.variables.read().await;
-        
-        let mut result = text.to_string();
-        let mut missing_keys = Vec::new();
-        
-        for cap in re.captures_iter(text) {
-            let full_match = cap.get(0).unwrap().as_str();
-            let key = cap.get(1).unwrap().as_str();
-            
-            if let Some(value) = vars.get(key) {
-                result = result.replace(full_match, value);
-            } else {
-                missing_keys.push(key.to_string());
-            }
-        }
-        
-        if !missing_keys.is_empty() {
-            return Err(Box::new(EnvironmentError { 
+        replace_variables(text, &vars).map_err(|missing_keys| {
+            Box::new(EnvironmentError { 
                 message: format!("Missing environment variables: {}", missing_keys.join(", ")) 
-            }));
+            }) as Box<dyn std::error::Error>
+        })
+    }
+}
+
+/// `${KEY}` を値に置き換える
+/// 値の無いキーがあれば、そのキーの一覧（重複なし、出現順）を返す
+pub fn replace_variables(text: &str, variables: &HashMap<String, String>) -> Result<String, Vec<String>> {
+    let re = Regex::new(r"\$\{([A-Za-z0-9_\-\.]+)\}").unwrap();
+    let mut missing_keys: Vec<String> = Vec::new();
+
+    let result = re.replace_all(text, |cap: &regex::Captures| {
+        let key = &cap[1];
+        match variables.get(key) {
+            Some(value) => value.clone(),
+            None => {
+                if !missing_keys.iter().any(|missing| missing == key) {
+                    missing_keys.push(key.to_string());
+                }
+                cap[0].to_string()
+            }
         }
-        
-        Ok(result)
+    });
+
+    if missing_keys.is_empty() {
+        Ok(result.into_owned())
+    } else {
+        Err(missing_keys)
     }
-}
\ No newline at end of file
+}
+
+/// `${KEY}` を値に置き換え、値の無いキーは空文字にする（表示用、`${...}` をそのまま残さない）
+pub fn replace_variables_or_empty(text: &str, variables: &HashMap<String, String>) -> String {
+    let re = Regex::new(r"\$\{([A-Za-z0-9_\-\.]+)\}").unwrap();
+    re.replace_all(text, |cap: &regex::Captures| variables.get(&cap[1]).cloned().unwrap_or_default())
+        .into_owned()
+}
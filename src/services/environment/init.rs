@@ -1,11 +1,21 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, RwLock};
+use tracing::{info, warn};
 
 use crate::repository::Database;
+use super::config::Config;
 use super::environment::Environment;
 
 // Create a singleton instance
 lazy_static::lazy_static! {
     pub static ref ENV: Environment = Environment::new();
+    static ref CONFIG: RwLock<Option<Arc<Config>>> = RwLock::new(None);
+}
+
+/// 現在有効な設定（起動時の検証前は None）
+pub fn current_config() -> Option<Arc<Config>> {
+    CONFIG.read().ok().and_then(|config| config.clone())
 }
 
 // Initialize the environment
@@ -21,20 +31,63 @@ pub async fn init_environment(db: Option<Arc<Database>>) -> Result<(), Box<dyn s
     Ok(())
 }
 
+/// 起動時に `.env`（プロセスの環境変数）と `environments` テーブルから設定を読み込み、検証する
+/// 不正・不足しているキーがあればすべて列挙したエラーを返す
+pub async fn init_config() -> Result<Arc<Config>, Box<dyn std::error::Error>> {
+    let mut variables: HashMap<String, String> = env::vars().collect();
+
+    // DATABASE_URL が無い場合はデータベースを読まずに検証し、不足を報告する
+    if variables.contains_key("DATABASE_URL") {
+        let db = Arc::new(Database::new().await?);
+        variables.extend(load_database_variables(db).await?);
+    }
+
+    apply(variables).await
+}
+
 // Load environment variables from database and update ENV singleton
+// 検証に失敗した場合は何も反映せず、直前の設定を維持する
 pub async fn load_from_database_and_update_env(db: Arc<Database>) -> Result<(), Box<dyn std::error::Error>> {
-    // We can't modify the ENV directly since it's a lazy_static,
-    // but we can load the database values
+    let mut variables: HashMap<String, String> = env::vars().collect();
+    variables.extend(load_database_variables(db).await?);
+
+    apply(variables).await.map(|_| ())
+}
+
+/// `environments` テーブルの値を変更（None は削除）した場合の設定を、反映する前に検証する
+pub async fn check_database_change(db: Arc<Database>, key: &str, value: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut database_variables = load_database_variables(db).await?;
+    match value {
+        Some(value) => database_variables.insert(key.to_string(), value.to_string()),
+        None => database_variables.remove(key),
+    };
+
+    let mut variables: HashMap<String, String> = env::vars().collect();
+    variables.extend(database_variables);
+    Config::from_map(&variables)?;
+    Ok(())
+}
+
+async fn load_database_variables(db: Arc<Database>) -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
     let env = Environment::with_database(db);
-    
-    // Load from database
     env.load_from_database().await?;
-    
-    // Copy values from the temporary environment to the singleton
-    let vars = env.get_all().await;
-    for (key, value) in vars.iter() {
-        ENV.set(key, value).await;
+    Ok(env.get_all().await)
+}
+
+/// 検証に成功した場合のみ ENV と設定を置き換える
+async fn apply(variables: HashMap<String, String>) -> Result<Arc<Config>, Box<dyn std::error::Error>> {
+    let config = match Config::from_map(&variables) {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            warn!("Rejected configuration: {:?}", e.problems);
+            return Err(Box::new(e));
+        }
+    };
+
+    ENV.replace_all(variables).await;
+    if let Ok(mut current) = CONFIG.write() {
+        *current = Some(config.clone());
     }
-    
-    Ok(())
-}
\ No newline at end of file
+    info!("Configuration loaded");
+    Ok(config)
+}
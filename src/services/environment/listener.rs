@@ -0,0 +1,155 @@
+//! Reloads the configuration when `environments` or `message_texts` change.
+//!
+//! The triggers in `migrations/20261018000400_config_notifications.sql` send the name of the
+//! changed table on [`NOTIFY_CHANNEL`]. Notifications are collected until none arrives for the
+//! debounce time, so that a bulk update is reloaded only once.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use sqlx::postgres::PgListener;
+use tracing::{error, info, warn};
+
+use crate::repository::Database;
+use crate::services::message::message_text_cache;
+use super::config::Config;
+use super::init::{load_from_database_and_update_env, ENV};
+
+/// 通知のチャンネル名
+pub const NOTIFY_CHANNEL: &str = "gbf_bot_config_changed";
+
+/// 接続が切れた場合に再接続するまでの待ち時間
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+const ENVIRONMENTS_TABLE: &str = "environments";
+const MESSAGE_TEXTS_TABLE: &str = "message_texts";
+
+/// 通知の購読をバックグラウンドで開始する
+pub fn spawn(config: &Config) -> tokio::task::JoinHandle<()> {
+    let database_url = config.database_url.clone();
+    let debounce = config.config_reload_debounce;
+
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = listen(&database_url, debounce).await {
+                error!("Config listener stopped: {}", e);
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    })
+}
+
+async fn listen(database_url: &str, debounce: Duration) -> Result<(), sqlx::Error> {
+    let mut listener = PgListener::connect(database_url).await?;
+    listener.listen(NOTIFY_CHANNEL).await?;
+    info!("Listening for config changes on '{}'", NOTIFY_CHANNEL);
+
+    loop {
+        let notification = listener.recv().await?;
+        let mut tables = BTreeSet::from([notification.payload().to_string()]);
+
+        // 通知が途切れるまでまとめる
+        while let Ok(notification) = tokio::time::timeout(debounce, listener.recv()).await {
+            tables.insert(notification?.payload().to_string());
+        }
+
+        reload(&tables).await;
+    }
+}
+
+async fn reload(tables: &BTreeSet<String>) {
+    if tables.contains(MESSAGE_TEXTS_TABLE) {
+        message_text_cache::invalidate();
+    }
+
+    if !tables.contains(ENVIRONMENTS_TABLE) {
+        return;
+    }
+
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            error!("Config reload skipped, database connection error: {}", e);
+            return;
+        }
+    };
+
+    let before = ENV.get_all().await;
+    let result = load_from_database_and_update_env(db).await.map_err(|e| e.to_string());
+    if let Err(e) = result {
+        warn!("Config reload rejected, keeping the previous configuration: {}", e);
+        return;
+    }
+
+    let diff = KeyDiff::between(&before, &ENV.get_all().await);
+    if diff.is_empty() {
+        info!("Config reloaded: no changes");
+    } else {
+        info!("Config reloaded: {}", diff);
+    }
+}
+
+/// 読み込み前後で追加・変更・削除されたキー（値は秘密を含むため記録しない）
+#[derive(Debug, Default, PartialEq, Eq)]
+struct KeyDiff {
+    added: Vec<String>,
+    changed: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl KeyDiff {
+    fn between(before: &HashMap<String, String>, after: &HashMap<String, String>) -> Self {
+        let mut diff = KeyDiff::default();
+        for (key, value) in after {
+            match before.get(key) {
+                None => diff.added.push(key.clone()),
+                Some(previous) if previous != value => diff.changed.push(key.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = before.keys().filter(|key| !after.contains_key(*key)).cloned().collect();
+
+        diff.added.sort();
+        diff.changed.sort();
+        diff.removed.sort();
+        diff
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl std::fmt::Display for KeyDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "added [{}], changed [{}], removed [{}]",
+            self.added.join(", "), self.changed.join(", "), self.removed.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn test_key_diff() {
+        let before = values(&[("A", "1"), ("B", "2"), ("C", "3")]);
+        let after = values(&[("A", "1"), ("B", "20"), ("D", "4")]);
+
+        let diff = KeyDiff::between(&before, &after);
+        assert_eq!(diff.added, vec!["D"]);
+        assert_eq!(diff.changed, vec!["B"]);
+        assert_eq!(diff.removed, vec!["C"]);
+        assert_eq!(diff.to_string(), "added [D], changed [B], removed [C]");
+    }
+
+    #[test]
+    fn test_key_diff_empty() {
+        let before = values(&[("A", "1")]);
+        assert!(KeyDiff::between(&before, &before.clone()).is_empty());
+    }
+}
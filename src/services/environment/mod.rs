@@ -1,3 +1,5 @@
+pub mod config;
 pub mod environment;
 pub mod init;
+pub mod listener;
 pub mod service;
@@ -1,9 +1,219 @@
 use std::sync::Arc;
+use regex::Regex;
+use tracing::{error, info};
 
 use crate::repository::Database;
-use super::init::load_from_database_and_update_env;
+use crate::models::environment::Environment;
+use crate::models::environment_audit_log::{EnvironmentAuditAction, EnvironmentAuditLog};
+use super::config::{MASKED_VALUE, SECRET_KEYS};
+use super::init::{check_database_change, load_from_database_and_update_env};
+
+/// `/environ get` で表示する変更履歴の件数
+const HISTORY_LIMIT: u64 = 5;
 
 /// Service function to load environment variables from database
 pub async fn load_environment_from_database(db: Arc<Database>) -> Result<(), Box<dyn std::error::Error>> {
     load_from_database_and_update_env(db).await
-}
\ No newline at end of file
+}
+
+/// 変更を行ったユーザー（監査ログに記録する）
+#[derive(Debug, Clone, Copy)]
+pub struct Actor {
+    pub guild_id: Option<i64>,
+    pub user_id: i64,
+}
+
+/// `environments` テーブルの値を管理するサービス
+/// 返す値は秘密のキーであればマスク済み
+pub struct EnvironmentService {
+    db: Arc<Database>,
+}
+
+impl EnvironmentService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// 登録されている値の一覧（キー順）
+    pub async fn list(&self) -> Result<Vec<Environment>, String> {
+        let mut environments = self.db.environment.get_all().await
+            .map_err(|e| {
+                error!("Error getting environments: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        environments.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(environments.into_iter().map(masked).collect())
+    }
+
+    /// 値と最近の変更履歴を取得する
+    pub async fn get(&self, key: &str) -> Result<Option<(Environment, Vec<EnvironmentAuditLog>)>, String> {
+        let Some(environment) = self.find(key).await? else {
+            return Ok(None);
+        };
+
+        let history = self.db.environment_audit.get_by_key(key, HISTORY_LIMIT).await
+            .map_err(|e| {
+                error!("Error getting environment audit logs: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        Ok(Some((masked(environment), history)))
+    }
+
+    /// 値を登録・変更する
+    /// 設定として不正になる変更は保存せずに拒否し、保存後は実行中の設定に反映する
+    pub async fn set(&self, key: &str, value: &str, secret: Option<bool>, actor: Actor) -> Result<Environment, String> {
+        validate_key(key)?;
+        validate_writable(key)?;
+        if value.is_empty() {
+            return Err("値を入力してください。".to_string());
+        }
+
+        let existing = self.find(key).await?;
+        let is_secret = secret
+            .or_else(|| existing.as_ref().map(|environment| environment.is_secret))
+            .unwrap_or(false);
+
+        self.check(key, Some(value)).await?;
+
+        let environment = self.db.environment.set(key, value, is_secret).await
+            .map_err(|e| {
+                error!("Error setting environment: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        let secret_change = is_secret_environment(&environment) || existing.as_ref().is_some_and(is_secret_environment);
+        let old_value = existing.as_ref().map(|environment| environment.value.as_str());
+        self.audit(key, EnvironmentAuditAction::Set, old_value, Some(value), secret_change, actor).await;
+        self.reload().await?;
+
+        info!("Environment updated: key={}, user_id={}", key, actor.user_id);
+        Ok(masked(environment))
+    }
+
+    /// 値を削除する（存在しない場合は false）
+    pub async fn delete(&self, key: &str, actor: Actor) -> Result<bool, String> {
+        validate_writable(key)?;
+        let Some(existing) = self.find(key).await? else {
+            return Ok(false);
+        };
+
+        self.check(key, None).await?;
+
+        self.db.environment.delete(key).await
+            .map_err(|e| {
+                error!("Error deleting environment: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        let secret_change = is_secret_environment(&existing);
+        self.audit(key, EnvironmentAuditAction::Delete, Some(&existing.value), None, secret_change, actor).await;
+        self.reload().await?;
+
+        info!("Environment deleted: key={}, user_id={}", key, actor.user_id);
+        Ok(true)
+    }
+
+    async fn find(&self, key: &str) -> Result<Option<Environment>, String> {
+        self.db.environment.get_by_key(key).await
+            .map_err(|e| {
+                error!("Error getting environment: {:?}", e);
+                format!("Database error: {}", e)
+            })
+    }
+
+    async fn check(&self, key: &str, value: Option<&str>) -> Result<(), String> {
+        check_database_change(self.db.clone(), key, value).await
+            .map_err(|e| format!("設定が不正になるため変更できません。\n{}", e))
+    }
+
+    async fn reload(&self) -> Result<(), String> {
+        load_from_database_and_update_env(self.db.clone()).await
+            .map_err(|e| format!("保存しましたが、設定への反映に失敗しました: {}", e))
+    }
+
+    /// 監査ログを記録する（失敗しても変更自体は取り消さない）
+    async fn audit(&self, key: &str, action: EnvironmentAuditAction, old_value: Option<&str>, new_value: Option<&str>, secret: bool, actor: Actor) {
+        let (old_value, new_value) = if secret {
+            (old_value.map(|_| MASKED_VALUE), new_value.map(|_| MASKED_VALUE))
+        } else {
+            (old_value, new_value)
+        };
+
+        if let Err(e) = self.db.environment_audit.record(key, action, old_value, new_value, actor.guild_id, actor.user_id).await {
+            error!("Error recording environment audit log: key={}, error={:?}", key, e);
+        }
+    }
+}
+
+/// `${KEY}` で参照できるキーだけを受け付ける
+fn validate_key(key: &str) -> Result<(), String> {
+    let re = Regex::new(r"^[A-Za-z0-9_\-\.]+$").unwrap();
+    if re.is_match(key) {
+        Ok(())
+    } else {
+        Err(format!("キーには英数字と _ - . のみ使用できます: {}", key))
+    }
+}
+
+/// 起動に使うキー（[`SECRET_KEYS`]）は環境変数でのみ設定し、データベースからは変更・削除させない
+fn validate_writable(key: &str) -> Result<(), String> {
+    if SECRET_KEYS.iter().any(|secret| secret.eq_ignore_ascii_case(key)) {
+        return Err(format!("{} は起動時の環境変数でのみ設定できるため、変更・削除できません。", key));
+    }
+    Ok(())
+}
+
+fn is_secret_environment(environment: &Environment) -> bool {
+    environment.is_secret || SECRET_KEYS.contains(&environment.key.as_str())
+}
+
+fn masked(mut environment: Environment) -> Environment {
+    if is_secret_environment(&environment) {
+        environment.is_secret = true;
+        environment.value = MASKED_VALUE.to_string();
+    }
+    environment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn environment(key: &str, value: &str, is_secret: bool) -> Environment {
+        Environment {
+            id: 1,
+            key: key.to_string(),
+            value: value.to_string(),
+            is_secret,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_masked() {
+        assert_eq!(masked(environment("GUILD_ID", "123", false)).value, "123");
+        assert_eq!(masked(environment("API_KEY", "abc", true)).value, MASKED_VALUE);
+
+        let token = masked(environment("DISCORD_TOKEN", "token", false));
+        assert_eq!(token.value, MASKED_VALUE);
+        assert!(token.is_secret);
+    }
+
+    #[test]
+    fn test_validate_key() {
+        assert!(validate_key("DB_MAX_CONNECTIONS").is_ok());
+        assert!(validate_key("quest.default-name").is_ok());
+        assert!(validate_key("BAD KEY").is_err());
+        assert!(validate_key("${KEY}").is_err());
+    }
+
+    #[test]
+    fn test_validate_writable() {
+        assert!(validate_writable("DB_MAX_CONNECTIONS").is_ok());
+        assert!(validate_writable("DISCORD_TOKEN").is_err());
+        assert!(validate_writable("database_url").is_err());
+    }
+}
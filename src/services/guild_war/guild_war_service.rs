@@ -0,0 +1,148 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude::all::{ChannelId, Context, Message};
+use tracing::{error, info};
+
+use crate::models::guild_war_schedule::{GuildWarNotice, GuildWarSchedule, NOTICE_GRACE_MINUTES};
+use crate::repository::Database;
+use crate::repository::guild_war_schedule_repository::NewGuildWarSchedule;
+use crate::services::message::Messages;
+use crate::services::settings::SettingsService;
+use crate::utils::date_parser::GuildWarDate;
+use super::{round_name, schedule};
+
+/// 取り込んだ日程の投稿先とメンション
+pub struct ImportTarget {
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub role_id: Option<i64>,
+    pub created_by: i64,
+}
+
+/// GuildWarService - 古戦場の日程の取り込みと、区切りの開始・終了のお知らせを行うサービス
+/// 日程はサーバーごとに1つで、取り込むたびに置き換える
+pub struct GuildWarService {
+    db: Arc<Database>,
+}
+
+impl GuildWarService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// サーバーの日程（開始日時の順）
+    pub async fn list(&self, messages: &Messages, guild_id: i64) -> Result<Vec<GuildWarSchedule>, String> {
+        self.db.guild_war_schedule.get_by_guild(guild_id).await
+            .map_err(|e| {
+                error!("Error getting guild war schedule: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })
+    }
+
+    /// JSONの日程を取り込み、サーバーの日程を置き換える
+    /// 日時はサーバーのタイムゾーンとして解釈する
+    pub async fn import(&self, messages: &Messages, target: ImportTarget, content: &str) -> Result<Vec<GuildWarSchedule>, String> {
+        let settings = SettingsService::new(self.db.clone()).get(target.guild_id).await?;
+        let rounds = schedule::parse(messages, content, settings.timezone())?
+            .into_iter()
+            .map(|input| NewGuildWarSchedule {
+                round: input.round.key().to_string(),
+                starts_at: input.starts_at,
+                ends_at: input.ends_at,
+            })
+            .collect();
+
+        let saved = self.db.guild_war_schedule.replace(target.guild_id, target.channel_id, target.role_id, target.created_by, rounds).await
+            .map_err(|e| {
+                error!("Error saving guild war schedule: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+        info!("Guild war schedule imported: guild_id={}, rounds={}", target.guild_id, saved.len());
+        Ok(saved)
+    }
+
+    /// サーバーの日程を削除し、削除した区切りの数を返す
+    pub async fn clear(&self, messages: &Messages, guild_id: i64) -> Result<u64, String> {
+        let deleted = self.db.guild_war_schedule.delete_by_guild(guild_id).await
+            .map_err(|e| {
+                error!("Error deleting guild war schedule: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+        if deleted > 0 {
+            info!("Guild war schedule cleared: guild_id={}", guild_id);
+        }
+        Ok(deleted)
+    }
+
+    /// 「本戦1日目 22時」などの日時を、サーバーの日程から求める
+    pub async fn event_date(&self, messages: &Messages, guild_id: i64, date: GuildWarDate) -> Result<DateTime<Utc>, String> {
+        let name = round_name(messages, date.round);
+        let rounds = self.list(messages, guild_id).await?;
+        let Some(scheduled) = rounds.iter().find(|scheduled| scheduled.round() == Some(date.round)) else {
+            return Err(messages.get_with_params("guild_war.round_not_scheduled", &[("round", &name)]));
+        };
+
+        let settings = SettingsService::new(self.db.clone()).get(guild_id).await?;
+        scheduled.event_date(date.time, settings.timezone())
+            .ok_or_else(|| messages.get_with_params("guild_war.outside_round", &[
+                ("round", &name),
+                ("start", &scheduled.starts_at.timestamp().to_string()),
+                ("end", &scheduled.ends_at.timestamp().to_string()),
+            ]))
+    }
+
+    /// 投稿するべき区切りのお知らせ
+    pub async fn due(&self, now: DateTime<Utc>) -> Result<Vec<(GuildWarSchedule, GuildWarNotice)>, String> {
+        let rounds = self.db.guild_war_schedule.get_pending(now - Duration::minutes(NOTICE_GRACE_MINUTES), now).await
+            .map_err(|e| {
+                error!("Error getting pending guild war rounds: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        Ok(rounds.into_iter()
+            .filter_map(|round| round.due_notice(now).map(|notice| (round, notice)))
+            .collect())
+    }
+
+    /// 区切りの開始・終了をサーバーの設定言語で投稿する（終了時は次の区切りも案内する）
+    /// 他のプロセスが先に投稿した場合は投稿せずに None
+    pub async fn announce(&self, ctx: &Context, scheduled: &GuildWarSchedule, notice: GuildWarNotice) -> Result<Option<Message>, String> {
+        let claimed = self.db.guild_war_schedule.mark_notified(scheduled.id, notice, Utc::now()).await
+            .map_err(|e| format!("Database error: {}", e))?;
+        if !claimed {
+            return Ok(None);
+        }
+        let Some(round) = scheduled.round() else {
+            return Ok(None);
+        };
+
+        let messages = Messages::for_guild(&self.db, scheduled.guild_id).await;
+        let name = round_name(&messages, round);
+        let mut content = match notice {
+            GuildWarNotice::Start => messages.get_with_params("guild_war.round_started", &[
+                ("round", &name),
+                ("end", &scheduled.ends_at.timestamp().to_string()),
+            ]),
+            GuildWarNotice::End => messages.get_with_params("guild_war.round_ended", &[("round", &name)]),
+        };
+        if notice == GuildWarNotice::End {
+            let rounds = self.list(&messages, scheduled.guild_id).await?;
+            if let Some(next) = rounds.iter().find(|next| next.starts_at >= scheduled.ends_at) {
+                let next_name = next.round().map(|round| round_name(&messages, round)).unwrap_or_default();
+                content.push('\n');
+                content.push_str(&messages.get_with_params("guild_war.next_round", &[
+                    ("round", &next_name),
+                    ("start", &next.starts_at.timestamp().to_string()),
+                ]));
+            }
+        }
+        if let Some(role_id) = scheduled.role_id {
+            content = format!("<@&{}> {}", role_id, content);
+        }
+
+        let message = ChannelId::new(scheduled.channel_id as u64).say(&ctx.http, content).await
+            .map_err(|e| format!("Failed to post guild war notice: {}", e))?;
+        info!("Guild war {:?} notice posted: guild_id={}, round={}", notice, scheduled.guild_id, scheduled.round);
+        Ok(Some(message))
+    }
+}
@@ -0,0 +1,11 @@
+pub mod guild_war_service;
+pub mod schedule;
+pub use guild_war_service::GuildWarService;
+
+use crate::services::message::Messages;
+use crate::types::GuildWarRound;
+
+/// 区切りの表示名（「本戦1日目」など）
+pub fn round_name(messages: &Messages, round: GuildWarRound) -> String {
+    messages.get(&format!("guild_war.rounds.{}", round.key()))
+}
@@ -0,0 +1,115 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+use serde::Deserialize;
+
+use crate::services::message::Messages;
+use crate::types::GuildWarRound;
+use super::round_name;
+
+/// 取り込むファイルで使える日時の書式（サーバーのタイムゾーン）
+const DATE_TIME_FORMATS: [&str; 3] = ["%Y-%m-%d %H:%M", "%Y/%m/%d %H:%M", "%Y-%m-%dT%H:%M"];
+
+/// 取り込むファイルの区切り1件分
+#[derive(Debug, Clone, Deserialize)]
+struct RoundRecord {
+    round: String,
+    start: String,
+    end: String,
+}
+
+/// 取り込む区切りと期間
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundInput {
+    pub round: GuildWarRound,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+/// JSONの日程を読み込み、区切りの順に並べる
+/// 同じ区切りの重複や、前の区切りの終了より前に始まる区切りはエラーにする
+pub fn parse(messages: &Messages, content: &str, timezone: FixedOffset) -> Result<Vec<RoundInput>, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let records: Vec<RoundRecord> = serde_json::from_str(content)
+        .map_err(|e| messages.get_with_params("guild_war.invalid_file", &[("error", &e.to_string())]))?;
+    if records.is_empty() {
+        return Err(messages.get("guild_war.empty_file"));
+    }
+
+    let mut rounds: Vec<RoundInput> = Vec::with_capacity(records.len());
+    for record in records {
+        let round = GuildWarRound::from_name(&record.round)
+            .ok_or_else(|| messages.get_with_params("guild_war.unknown_round", &[("round", record.round.trim())]))?;
+        let name = round_name(messages, round);
+        if rounds.iter().any(|input| input.round == round) {
+            return Err(messages.get_with_params("guild_war.duplicate_round", &[("round", &name)]));
+        }
+
+        let starts_at = parse_date_time(messages, &record.start, timezone)?;
+        let ends_at = parse_date_time(messages, &record.end, timezone)?;
+        if ends_at <= starts_at {
+            return Err(messages.get_with_params("guild_war.invalid_period", &[("round", &name)]));
+        }
+        rounds.push(RoundInput { round, starts_at, ends_at });
+    }
+
+    rounds.sort_by_key(|input| input.round);
+    for pair in rounds.windows(2) {
+        if pair[1].starts_at < pair[0].ends_at {
+            return Err(messages.get_with_params("guild_war.overlapping_rounds", &[("round", &round_name(messages, pair[1].round))]));
+        }
+    }
+    Ok(rounds)
+}
+
+fn parse_date_time(messages: &Messages, value: &str, timezone: FixedOffset) -> Result<DateTime<Utc>, String> {
+    let value = value.trim();
+    DATE_TIME_FORMATS.iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .and_then(|date_time| timezone.from_local_datetime(&date_time).single())
+        .map(|date_time| date_time.with_timezone(&Utc))
+        .ok_or_else(|| messages.get_with_params("guild_war.invalid_time", &[("value", value)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jst() -> FixedOffset {
+        FixedOffset::east_opt(9 * 3600).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let messages = Messages::bundled(Some("ja"));
+        let json = r#"[
+            {"round": "本戦1日目", "start": "2026-11-04 07:00", "end": "2026-11-04 23:59"},
+            {"round": "preliminaries", "start": "2026/11/01 19:00", "end": "2026/11/02 23:59"}
+        ]"#;
+
+        let rounds = parse(&messages, json, jst()).unwrap();
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].round, GuildWarRound::Preliminaries);
+        assert_eq!(rounds[0].starts_at, jst().with_ymd_and_hms(2026, 11, 1, 19, 0, 0).unwrap().with_timezone(&Utc));
+        assert_eq!(rounds[1].round, GuildWarRound::Finals1);
+        assert_eq!(rounds[1].ends_at, jst().with_ymd_and_hms(2026, 11, 4, 23, 59, 0).unwrap().with_timezone(&Utc));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let messages = Messages::bundled(Some("ja"));
+        let parse = |json: &str| parse(&messages, json, jst());
+
+        assert!(parse("not json").is_err());
+        assert!(parse("[]").is_err());
+        assert!(parse(r#"[{"round": "本戦5日目", "start": "2026-11-08 07:00", "end": "2026-11-08 23:59"}]"#).is_err());
+        assert!(parse(r#"[{"round": "予選", "start": "11/01 19:00", "end": "2026-11-02 23:59"}]"#).is_err());
+        assert!(parse(r#"[{"round": "予選", "start": "2026-11-02 23:59", "end": "2026-11-01 19:00"}]"#).is_err());
+        assert!(parse(r#"[
+            {"round": "予選", "start": "2026-11-01 19:00", "end": "2026-11-02 23:59"},
+            {"round": "prelims", "start": "2026-11-01 19:00", "end": "2026-11-02 23:59"}
+        ]"#).is_err());
+        assert!(parse(r#"[
+            {"round": "予選", "start": "2026-11-01 19:00", "end": "2026-11-02 23:59"},
+            {"round": "インターバル", "start": "2026-11-02 12:00", "end": "2026-11-03 23:59"}
+        ]"#).is_err());
+    }
+}
@@ -1,7 +1,5 @@
-use rust_i18n::{t, set_locale};
 use std::sync::{OnceLock, RwLock};
 use std::collections::HashMap;
-use serde_json::Value;
 use tokio::fs;
 
 /// Supported languages for the message service
@@ -12,14 +10,16 @@ pub enum Language {
 }
 
 impl Language {
-    fn to_locale(&self) -> &'static str {
+    pub fn to_locale(&self) -> &'static str {
         match self {
             Language::English => "en",
             Language::Japanese => "ja",
         }
     }
 
-    pub fn from_str(lang: &str) -> Option<Self> {
+    /// Accepts language names and locales with a region, such as Discord's "en-US"
+    pub fn from_locale(lang: &str) -> Option<Self> {
+        let lang = lang.split(['-', '_']).next().unwrap_or_default();
         match lang.to_lowercase().as_str() {
             "en" | "english" | "eng" => Some(Language::English),
             "ja" | "japanese" | "jpn" | "jp" => Some(Language::Japanese),
@@ -78,8 +78,8 @@ impl CustomMessageStore {
 }
 
 /// Enhanced message service with two-tier message management
+/// The language is passed on every lookup, since each user may use a different one
 pub struct MessageService {
-    current_language: RwLock<Language>,
     custom_store: CustomMessageStore,
 }
 
@@ -88,7 +88,6 @@ static MESSAGE_SERVICE: OnceLock<MessageService> = OnceLock::new();
 impl MessageService {
     fn new() -> Self {
         Self {
-            current_language: RwLock::new(Language::default()),
             custom_store: CustomMessageStore::new(),
         }
     }
@@ -97,16 +96,6 @@ impl MessageService {
         MESSAGE_SERVICE.get_or_init(|| MessageService::new())
     }
 
-    /// Set the current language for messages
-    pub fn set_language(&self, language: Language) {
-        *self.current_language.write().unwrap() = language;
-        set_locale(language.to_locale());
-    }
-
-    pub fn current_language(&self) -> Language {
-        *self.current_language.read().unwrap()
-    }
-
     /// Load custom messages from directory (async)
     pub async fn load_custom_messages(&self, dir_path: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         self.custom_store.load_from_directory(dir_path).await
@@ -117,44 +106,26 @@ impl MessageService {
         self.custom_store.clear();
     }
 
-    /// Get a localized message by key
-    /// First checks custom messages, then falls back to standard rust-i18n messages
-    pub fn get(&self, key: &str) -> String {
-        let current_lang = self.current_language();
-        self.get_with_language(key, current_lang)
-    }
-
     /// Get a localized message with specific language
+    /// First checks custom messages, then falls back to standard rust-i18n messages
     pub fn get_with_language(&self, key: &str, language: Language) -> String {
-        let locale = language.to_locale();
-        
         // First try custom messages
-        if let Some(custom_message) = self.custom_store.get(key, locale) {
+        if let Some(custom_message) = self.custom_message(key, language) {
             return custom_message;
         }
         
         // Fallback to standard rust-i18n messages
-        // t!(key, locale = locale).to_string()
-        "".to_string()
+        bundled_message(key, language).unwrap_or_default()
     }
 
-    /// Get a localized message with parameters
-    /// Supports both {{param}} and {param} parameter formats
-    pub fn get_with_params(&self, key: &str, params: &[(&str, &str)]) -> String {
-        self.get_with_params_and_language(key, params, self.current_language())
+    /// Get a custom (JSON) override of a message, if any
+    pub fn custom_message(&self, key: &str, language: Language) -> Option<String> {
+        self.custom_store.get(key, language.to_locale())
     }
 
     /// Get a localized message with parameters and specific language
     pub fn get_with_params_and_language(&self, key: &str, params: &[(&str, &str)], language: Language) -> String {
-        let mut result = self.get_with_language(key, language);
-        
-        for (param_key, param_value) in params {
-            // Support both {{param}} and {param} formats
-            result = result.replace(&format!("{{{{{}}}}}", param_key), param_value);
-            result = result.replace(&format!("{{{}}}", param_key), param_value);
-        }
-        
-        result
+        apply_params(self.get_with_language(key, language), params)
     }
 
     /// Check if a custom message override exists for the key
@@ -169,6 +140,22 @@ impl MessageService {
     }
 }
 
+/// Get a message from the bundled `locales/*.yml`
+pub fn bundled_message(key: &str, language: Language) -> Option<String> {
+    crate::_rust_i18n_try_translate(language.to_locale(), key).map(|message| message.to_string())
+}
+
+/// Replace parameters in a message
+/// Supports both {{param}} and {param} formats
+pub fn apply_params(message: String, params: &[(&str, &str)]) -> String {
+    let mut result = message;
+    for (param_key, param_value) in params {
+        result = result.replace(&format!("{{{{{}}}}}", param_key), param_value);
+        result = result.replace(&format!("{{{}}}", param_key), param_value);
+    }
+    result
+}
+
 impl Default for MessageService {
     fn default() -> Self {
         Self::new()
@@ -179,31 +166,16 @@ impl Default for MessageService {
 pub mod messages {
     use super::*;
 
-    /// Get a message using the global instance
-    pub fn get(key: &str) -> String {
-        MessageService::instance().get(key)
-    }
-
     /// Get a message with specific language
     pub fn get_with_language(key: &str, language: Language) -> String {
         MessageService::instance().get_with_language(key, language)
     }
 
-    /// Get a message with parameters
-    pub fn get_with_params(key: &str, params: &[(&str, &str)]) -> String {
-        MessageService::instance().get_with_params(key, params)
-    }
-
     /// Get a message with parameters and specific language
     pub fn get_with_params_and_language(key: &str, params: &[(&str, &str)], language: Language) -> String {
         MessageService::instance().get_with_params_and_language(key, params, language)
     }
 
-    /// Set the global language
-    pub fn set_global_language(language: Language) {
-        MessageService::instance().set_language(language);
-    }
-
     /// Load custom messages from directory
     pub async fn load_custom_messages(dir_path: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         MessageService::instance().load_custom_messages(dir_path).await
@@ -231,10 +203,18 @@ mod tests {
         assert_eq!(Language::Japanese.to_locale(), "ja");
     }
 
+    #[test]
+    fn test_language_from_discord_locale() {
+        assert_eq!(Language::from_locale("ja"), Some(Language::Japanese));
+        assert_eq!(Language::from_locale("en-US"), Some(Language::English));
+        assert_eq!(Language::from_locale("en-GB"), Some(Language::English));
+        assert_eq!(Language::from_locale("fr"), None);
+    }
+
     #[test]
     fn test_message_service_basic() {
         let service = MessageService::new();
-        let success_msg = service.get("common.success");
+        let success_msg = service.get_with_language("common.success", Language::English);
         assert!(!success_msg.is_empty());
     }
 
@@ -247,10 +227,9 @@ mod tests {
     #[test]
     fn test_parameter_substitution() {
         let service = MessageService::new();
-        let params = &[("quest_name", "ドラゴンクエスト"), ("battle_type", "全属性")];
+        let params = &[("count", "3"), ("capacity", "6")];
         
-        // Assuming we have a message key that uses parameters
-        let result = service.get_with_params("recruitment.message", params);
-        assert!(!result.is_empty());
+        let result = service.get_with_params_and_language("battle_recruitment.participant_count", params, Language::Japanese);
+        assert_eq!(result, "現在の参加者: 3/6");
     }
 }
\ No newline at end of file
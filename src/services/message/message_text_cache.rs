@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tracing::info;
+
+use crate::models::message_text::MessageText;
+use crate::repository::Database;
+
+lazy_static::lazy_static! {
+    /// guild_id -> message_id -> message_texts の行
+    static ref MESSAGE_TEXTS: RwLock<HashMap<i64, Arc<HashMap<String, MessageText>>>> = RwLock::new(HashMap::new());
+}
+
+/// サーバーの message_texts を取得する（初回のみデータベースから読み込む）
+pub async fn get_by_guild(db: &Database, guild_id: i64) -> Result<Arc<HashMap<String, MessageText>>, String> {
+    if let Some(cached) = MESSAGE_TEXTS.read().ok().and_then(|cache| cache.get(&guild_id).cloned()) {
+        return Ok(cached);
+    }
+
+    let message_texts = db.message_text.get_by_guild(guild_id).await
+        .map_err(|e| format!("Database error: {}", e))?;
+    let message_texts: Arc<HashMap<String, MessageText>> = Arc::new(message_texts.into_iter()
+        .map(|message_text| (message_text.message_id.clone(), message_text))
+        .collect());
+
+    if let Ok(mut cache) = MESSAGE_TEXTS.write() {
+        cache.insert(guild_id, message_texts.clone());
+    }
+    Ok(message_texts)
+}
+
+/// キャッシュを破棄し、次回の参照時に読み込み直す
+pub fn invalidate() {
+    if let Ok(mut cache) = MESSAGE_TEXTS.write() {
+        let guild_count = cache.len();
+        cache.clear();
+        info!("Message text cache cleared: {} guilds", guild_count);
+    }
+}
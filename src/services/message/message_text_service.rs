@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::{Duration, FixedOffset, Utc};
+use tracing::{error, info};
+
+use crate::models::guild_settings::DEFAULT_TIMEZONE;
+use crate::models::message_text::MessageText;
+use crate::repository::Database;
+use crate::services::battle_recruitment::template::{RecruitmentVariables, TEMPLATE_KEYS, VARIABLES};
+use crate::services::battle_recruitment::DEFAULT_CAPACITY;
+use crate::services::environment::environment::{replace_variables, replace_variables_or_empty};
+use crate::types::BattleType;
+use super::message_service::{bundled_message, Language};
+use super::messages::{key_for_legacy_id, legacy_message_id};
+use super::{message_text_cache, Messages};
+
+/// メッセージ本文の上限（Discordの制限は2000文字）
+const MESSAGE_LENGTH_LIMIT: usize = 2000;
+
+/// プレビューで使う参加者
+const SAMPLE_PARTICIPANTS: [&str; 3] = ["@user1", "@user2", "@user3"];
+
+/// プレビューで使うクエスト名
+const SAMPLE_QUEST_NAME: &str = "ルシファーHL";
+
+/// プレビューで使う募集テンプレートの補足
+const SAMPLE_NOTES: &str = "フルオート可";
+
+/// `/message_text` で変更するメッセージ
+pub struct MessageTextEntry {
+    pub key: String,
+    /// 以前のメッセージID（MSG00032 など）
+    pub legacy_id: Option<&'static str>,
+    /// サーバー独自の設定（無ければ同梱のメッセージを使う）
+    pub guild_text: Option<MessageText>,
+}
+
+impl MessageTextEntry {
+    /// `${name}` の変数を使えるテンプレートか
+    pub fn is_template(&self) -> bool {
+        TEMPLATE_KEYS.contains(&self.key.as_str())
+    }
+
+    /// 現在使われているメッセージ（サーバー独自の設定、同梱のメッセージの順）
+    pub fn current(&self, language: Language) -> Option<String> {
+        let guild_text = self.guild_text.as_ref().and_then(|text| match language {
+            Language::Japanese => Some(text.message_jp.clone()),
+            Language::English => text.message_en.clone(),
+        });
+        guild_text
+            .filter(|message| !message.is_empty())
+            .or_else(|| bundled_message(&self.key, language))
+    }
+}
+
+/// 保存前の変更内容
+pub struct MessageTextDraft {
+    pub key: String,
+    /// 保存先の message_id（以前のIDで登録済みの場合はその行を更新する）
+    message_id: String,
+    pub message_jp: String,
+    pub message_en: Option<String>,
+}
+
+impl MessageTextDraft {
+    /// 見本の変数で描画したメッセージ（英語が未設定の場合は同梱の英語メッセージ）
+    pub fn preview(&self, language: Language) -> String {
+        let message = match language {
+            Language::Japanese => Some(self.message_jp.clone()),
+            Language::English => self.message_en.clone(),
+        };
+        let message = message
+            .or_else(|| bundled_message(&self.key, language))
+            .unwrap_or_default();
+
+        if TEMPLATE_KEYS.contains(&self.key.as_str()) {
+            replace_variables_or_empty(&message, &sample_variables(language))
+        } else {
+            message
+        }
+    }
+}
+
+/// サーバー独自のメッセージ（message_texts）を管理するサービス
+pub struct MessageTextService {
+    db: Arc<Database>,
+}
+
+impl MessageTextService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// 募集のテンプレートと、サーバー独自に設定したメッセージの一覧
+    pub async fn list(&self, guild_id: i64) -> Result<Vec<MessageTextEntry>, String> {
+        let mut guild_texts: HashMap<String, MessageText> = self.db.message_text.get_by_guild(guild_id).await
+            .map_err(|e| {
+                error!("Error getting message texts: {:?}", e);
+                format!("Database error: {}", e)
+            })?
+            .into_iter()
+            .map(|text| (text.message_id.clone(), text))
+            .collect();
+
+        let mut entries: Vec<MessageTextEntry> = TEMPLATE_KEYS.iter()
+            .map(|key| {
+                let legacy_id = legacy_message_id(key);
+                let guild_text = guild_texts.remove(*key)
+                    .or_else(|| legacy_id.and_then(|legacy_id| guild_texts.remove(legacy_id)));
+                MessageTextEntry { key: key.to_string(), legacy_id, guild_text }
+            })
+            .collect();
+
+        let mut others: Vec<MessageTextEntry> = guild_texts.into_values()
+            .map(|text| {
+                let key = key_for_legacy_id(&text.message_id).unwrap_or(&text.message_id).to_string();
+                let legacy_id = legacy_message_id(&key);
+                MessageTextEntry { key, legacy_id, guild_text: Some(text) }
+            })
+            .collect();
+        others.sort_by(|a, b| a.key.cmp(&b.key));
+        entries.extend(others);
+
+        Ok(entries)
+    }
+
+    /// メッセージを取得する
+    /// キーは同梱のメッセージのキーか、以前のメッセージID（MSG00032 など）で指定する
+    pub async fn get(&self, guild_id: i64, message_id: &str) -> Result<MessageTextEntry, String> {
+        let key = key_for_legacy_id(message_id).unwrap_or(message_id);
+        let legacy_id = legacy_message_id(key);
+
+        let mut guild_text = self.find(guild_id, key).await?;
+        if guild_text.is_none() && let Some(legacy_id) = legacy_id {
+            guild_text = self.find(guild_id, legacy_id).await?;
+        }
+
+        if guild_text.is_none() && bundled_message(key, Language::Japanese).is_none() {
+            return Err(format!("`{}` は変更できるメッセージではありません。", message_id));
+        }
+        Ok(MessageTextEntry { key: key.to_string(), legacy_id, guild_text })
+    }
+
+    /// 保存前の変更内容を作り、検証する
+    /// 入力の `\n` は改行として扱い、指定しなかった言語は現在の設定のまま
+    pub async fn draft(&self, guild_id: i64, message_id: &str, message_jp: Option<&str>, message_en: Option<&str>) -> Result<MessageTextDraft, String> {
+        let message_jp = message_jp.map(unescape).filter(|message| !message.is_empty());
+        let message_en = message_en.map(unescape).filter(|message| !message.is_empty());
+        if message_jp.is_none() && message_en.is_none() {
+            return Err("日本語・英語のどちらかのメッセージを入力してください。".to_string());
+        }
+
+        let entry = self.get(guild_id, message_id).await?;
+        let existing = entry.guild_text.as_ref();
+
+        let draft = MessageTextDraft {
+            message_id: existing.map(|text| text.message_id.clone()).unwrap_or_else(|| entry.key.clone()),
+            message_jp: message_jp
+                .or_else(|| existing.map(|text| text.message_jp.clone()))
+                .or_else(|| bundled_message(&entry.key, Language::Japanese))
+                .unwrap_or_default(),
+            message_en: message_en.or_else(|| existing.and_then(|text| text.message_en.clone())),
+            key: entry.key,
+        };
+
+        for message in std::iter::once(&draft.message_jp).chain(draft.message_en.as_ref()) {
+            validate(&draft.key, message)?;
+        }
+        Ok(draft)
+    }
+
+    /// 変更内容を保存する
+    pub async fn save(&self, guild_id: i64, draft: &MessageTextDraft) -> Result<MessageText, String> {
+        let message_text = self.db.message_text.set(guild_id, &draft.message_id, &draft.message_jp, draft.message_en.as_deref()).await
+            .map_err(|e| {
+                error!("Error setting message text: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        message_text_cache::invalidate();
+        info!("Message text updated: guild_id={}, message_id={}", guild_id, draft.message_id);
+        Ok(message_text)
+    }
+
+    /// サーバー独自の設定を削除し、同梱のメッセージに戻す（設定が無かった場合は false）
+    /// 以前のメッセージIDで登録された行も削除する
+    pub async fn reset(&self, guild_id: i64, message_id: &str) -> Result<bool, String> {
+        let entry = self.get(guild_id, message_id).await?;
+
+        let mut deleted = false;
+        for message_id in std::iter::once(entry.key.as_str()).chain(entry.legacy_id) {
+            deleted |= self.db.message_text.delete(guild_id, message_id).await
+                .map_err(|e| {
+                    error!("Error deleting message text: {:?}", e);
+                    format!("Database error: {}", e)
+                })?;
+        }
+
+        if deleted {
+            message_text_cache::invalidate();
+            info!("Message text reset: guild_id={}, key={}", guild_id, entry.key);
+        }
+        Ok(deleted)
+    }
+
+    async fn find(&self, guild_id: i64, message_id: &str) -> Result<Option<MessageText>, String> {
+        self.db.message_text.get_by_guild_and_message(guild_id, message_id).await
+            .map_err(|e| {
+                error!("Error getting message text: {:?}", e);
+                format!("Database error: {}", e)
+            })
+    }
+}
+
+/// スラッシュコマンドでは改行を入力できないため、`\n` を改行に置き換える
+fn unescape(message: &str) -> String {
+    message.trim().replace("\\n", "\n")
+}
+
+fn validate(key: &str, message: &str) -> Result<(), String> {
+    if message.chars().count() > MESSAGE_LENGTH_LIMIT {
+        return Err(format!("メッセージは{}文字以内で入力してください。", MESSAGE_LENGTH_LIMIT));
+    }
+
+    if TEMPLATE_KEYS.contains(&key) {
+        replace_variables(message, &sample_variables(Language::Japanese))
+            .map_err(|unknown| format!(
+                "使用できない変数があります: {}\n使用できる変数: {}",
+                unknown.iter().map(|name| format!("${{{}}}", name)).collect::<Vec<_>>().join(", "),
+                VARIABLES.iter().map(|name| format!("${{{}}}", name)).collect::<Vec<_>>().join(", "),
+            ))?;
+    }
+    Ok(())
+}
+
+/// プレビュー用の変数（全属性募集、翌日開催、参加者3人、補足あり）
+fn sample_variables(language: Language) -> HashMap<String, String> {
+    let participants: Vec<String> = SAMPLE_PARTICIPANTS.iter().map(|name| name.to_string()).collect();
+    let timezone: FixedOffset = DEFAULT_TIMEZONE.parse().expect("default timezone is valid");
+    RecruitmentVariables {
+        quest_name: SAMPLE_QUEST_NAME,
+        battle_type: BattleType::AllElement,
+        event_time: Some(Utc::now().with_timezone(&timezone) + Duration::days(1)),
+        participants: &participants,
+        capacity: DEFAULT_CAPACITY,
+        notes: Some(SAMPLE_NOTES),
+    }.to_map(&Messages::bundled(Some(language.to_locale())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_draft(key: &str, message_jp: &str, message_en: Option<&str>) -> MessageTextDraft {
+        MessageTextDraft {
+            key: key.to_string(),
+            message_id: key.to_string(),
+            message_jp: message_jp.to_string(),
+            message_en: message_en.map(|message| message.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("battle_recruitment.template.completed", "${participants}\n全員そろいました").is_ok());
+        assert!(validate("battle_recruitment.template.completed", "${participants} ${unknown}").unwrap_err().contains("${unknown}"));
+        // テンプレート以外のメッセージは変数を検証しない
+        assert!(validate("battle_recruitment.participants_title", "${unknown}").is_ok());
+        assert!(validate("battle_recruitment.participants_title", &"あ".repeat(MESSAGE_LENGTH_LIMIT + 1)).is_err());
+    }
+
+    #[test]
+    fn test_preview() {
+        let draft = new_draft("battle_recruitment.template.completed", "${participants}\n${count}/${capacity}", None);
+        assert_eq!(draft.preview(Language::Japanese), "@user1 @user2 @user3\n3/6");
+        // 英語が未設定の場合は同梱の英語メッセージ
+        assert_eq!(draft.preview(Language::English), "@user1 @user2 @user3\nThe party is complete!");
+
+        // テンプレート以外のメッセージはそのまま表示する
+        let draft = new_draft("battle_recruitment.participants_title", "${participants}", Some("Members"));
+        assert_eq!(draft.preview(Language::Japanese), "${participants}");
+        assert_eq!(draft.preview(Language::English), "Members");
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape(" 1行目\\n2行目 "), "1行目\n2行目");
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, warn};
+
+use crate::models::message_text::MessageText;
+use crate::repository::Database;
+use crate::services::environment::environment::{replace_variables, replace_variables_or_empty};
+use crate::models::guild_settings::GuildSettings;
+use crate::types::PoiseContext;
+use super::message_service::{apply_params, bundled_message, Language, MessageService};
+use super::message_text_cache;
+
+/// message_texts に以前から登録されているメッセージIDと、対応するキー
+const LEGACY_MESSAGE_IDS: [(&str, &str); 1] = [
+    ("battle_recruitment.template.completed", "MSG00032"),
+];
+
+/// 翻訳が見つからない場合に使う言語
+const FALLBACK_LANGUAGE: Language = Language::Japanese;
+
+/// Botが送信するメッセージの取得窓口
+///
+/// 次の順に探し、最初に見つかったものを使う:
+/// 1. サーバー独自の `message_texts`（message_id がキー、言語に応じて message_jp / message_en）
+/// 2. `MessageService` の JSON による上書き
+/// 3. 同梱の `locales/*.yml`
+pub struct Messages {
+    guild_texts: Arc<HashMap<String, MessageText>>,
+    /// メッセージを受け取るユーザーの言語
+    language: Language,
+    /// サーバーの設定言語（チャンネルに投稿するメッセージ用）
+    guild_language: Language,
+}
+
+impl Messages {
+    pub fn new(guild_texts: Arc<HashMap<String, MessageText>>, language: Language) -> Self {
+        Self { guild_texts, language, guild_language: language }
+    }
+
+    /// サーバーの設定言語でメッセージを引く
+    /// 読み込みに失敗した場合は同梱のメッセージのみを使う
+    pub async fn for_guild(db: &Database, guild_id: i64) -> Self {
+        Self::for_user(db, Some(guild_id), None).await
+    }
+
+    /// ユーザーの言語（Discordのクライアントの言語）を優先し、サーバーの設定言語、既定の言語の順に決める
+    pub async fn for_user(db: &Database, guild_id: Option<i64>, user_locale: Option<&str>) -> Self {
+        let Some(guild_id) = guild_id else {
+            return Self::bundled(user_locale);
+        };
+
+        let settings = db.guild_settings.get_by_guild(guild_id).await
+            .unwrap_or_else(|e| {
+                error!("Error getting guild settings for messages: {:?}", e);
+                None
+            })
+            .unwrap_or_else(|| GuildSettings::empty(guild_id));
+        let guild_language = Language::from_locale(settings.locale()).unwrap_or(FALLBACK_LANGUAGE);
+
+        let guild_texts = message_text_cache::get_by_guild(db, guild_id).await
+            .unwrap_or_else(|e| {
+                error!("Error getting message texts: {}", e);
+                Arc::new(HashMap::new())
+            });
+
+        Self {
+            guild_texts,
+            language: user_locale.and_then(Language::from_locale).unwrap_or(guild_language),
+            guild_language,
+        }
+    }
+
+    /// コマンドを実行したユーザーの言語でメッセージを引く
+    pub async fn for_context(ctx: &PoiseContext<'_>) -> Self {
+        let guild_id = ctx.guild_id().map(|guild_id| guild_id.get() as i64);
+        match Database::new().await {
+            Ok(db) => Self::for_user(&db, guild_id, ctx.locale()).await,
+            Err(e) => {
+                error!("Error connecting to the database for messages: {}", e);
+                Self::bundled(ctx.locale())
+            }
+        }
+    }
+
+    /// サーバー独自のメッセージを使わず、ユーザーの言語（無ければ既定の言語）で引く
+    pub fn bundled(user_locale: Option<&str>) -> Self {
+        let language = user_locale.and_then(Language::from_locale).unwrap_or(FALLBACK_LANGUAGE);
+        Self::new(Arc::new(HashMap::new()), language)
+    }
+
+    /// 同じサーバーのメッセージを、サーバーの設定言語で引く
+    pub fn in_guild_language(&self) -> Self {
+        Self::new(self.guild_texts.clone(), self.guild_language)
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// メッセージを取得する（どこにも無い場合はキーをそのまま返す）
+    pub fn get(&self, key: &str) -> String {
+        self.guild_text(key)
+            .or_else(|| MessageService::instance().custom_message(key, self.language))
+            .or_else(|| bundled_message(key, self.language))
+            .or_else(|| bundled_message(key, FALLBACK_LANGUAGE))
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// `{name}` 形式のパラメータを埋め込んでメッセージを取得する
+    pub fn get_with_params(&self, key: &str, params: &[(&str, &str)]) -> String {
+        apply_params(self.get(key), params)
+    }
+
+    /// `${name}` 形式の変数を埋め込んでテンプレートを描画する
+    /// サーバー独自のテンプレートに未知の変数がある場合は、同梱のテンプレートを使う
+    /// 値を渡されなかった変数は空文字になる
+    pub fn render(&self, key: &str, variables: &HashMap<String, String>) -> String {
+        match replace_variables(&self.get(key), variables) {
+            Ok(message) => message,
+            Err(missing) => {
+                warn!("Unknown variables in message template {}: {}", key, missing.join(", "));
+                let template = bundled_message(key, self.language)
+                    .or_else(|| bundled_message(key, FALLBACK_LANGUAGE))
+                    .unwrap_or_else(|| key.to_string());
+                replace_variables_or_empty(&template, variables)
+            }
+        }
+    }
+
+    fn guild_text(&self, key: &str) -> Option<String> {
+        let message_text = self.guild_texts.get(key)
+            .or_else(|| legacy_message_id(key).and_then(|message_id| self.guild_texts.get(message_id)))?;
+
+        match self.language {
+            Language::Japanese => Some(message_text.message_jp.clone()),
+            Language::English => message_text.message_en.clone(),
+        }
+        .filter(|message| !message.is_empty())
+    }
+}
+
+/// キーに対応する以前のメッセージID（MSG00032 など）
+pub fn legacy_message_id(key: &str) -> Option<&'static str> {
+    LEGACY_MESSAGE_IDS.iter()
+        .find(|(legacy_key, _)| *legacy_key == key)
+        .map(|(_, message_id)| *message_id)
+}
+
+/// 以前のメッセージIDに対応するキー
+pub fn key_for_legacy_id(message_id: &str) -> Option<&'static str> {
+    LEGACY_MESSAGE_IDS.iter()
+        .find(|(_, legacy_id)| legacy_id.eq_ignore_ascii_case(message_id))
+        .map(|(key, _)| *key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message_text(message_id: &str, message_jp: &str, message_en: Option<&str>) -> MessageText {
+        MessageText {
+            id: 1,
+            guild_id: 1,
+            message_id: message_id.to_string(),
+            message_jp: message_jp.to_string(),
+            message_en: message_en.map(|message| message.to_string()),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    fn messages(texts: Vec<MessageText>, language: Language) -> Messages {
+        let texts = texts.into_iter().map(|text| (text.message_id.clone(), text)).collect();
+        Messages::new(Arc::new(texts), language)
+    }
+
+    #[test]
+    fn test_guild_text_overrides_bundled() {
+        let texts = vec![message_text("battle_recruitment.participants_title", "参加者", Some("Members"))];
+
+        assert_eq!(messages(texts.clone(), Language::Japanese).get("battle_recruitment.participants_title"), "参加者");
+        assert_eq!(messages(texts, Language::English).get("battle_recruitment.participants_title"), "Members");
+    }
+
+    #[test]
+    fn test_legacy_message_id() {
+        let texts = vec![message_text("MSG00032", "${participants}\n全員そろいました", None)];
+        let japanese = messages(texts.clone(), Language::Japanese);
+        assert_eq!(japanese.get("battle_recruitment.template.completed"), "${participants}\n全員そろいました");
+
+        // 英語が未登録の場合は同梱の英語メッセージを使う
+        let english = messages(texts, Language::English);
+        assert_eq!(english.get("battle_recruitment.template.completed"), "${participants}\nThe party is complete!");
+    }
+
+    #[test]
+    fn test_user_and_guild_language() {
+        let texts = vec![message_text("battle_recruitment.participants_title", "参加者", Some("Members"))];
+        let texts: HashMap<String, MessageText> = texts.into_iter().map(|text| (text.message_id.clone(), text)).collect();
+        let messages = Messages {
+            guild_texts: Arc::new(texts),
+            language: Language::English,
+            guild_language: Language::Japanese,
+        };
+
+        assert_eq!(messages.get("battle_recruitment.participants_title"), "Members");
+        assert_eq!(messages.in_guild_language().get("battle_recruitment.participants_title"), "参加者");
+    }
+
+    #[test]
+    fn test_bundled_language() {
+        assert_eq!(Messages::bundled(Some("en-US")).get("battle_recruitment.participants_title"), "Participants");
+        assert_eq!(Messages::bundled(Some("fr")).get("battle_recruitment.participants_title"), "参加者一覧");
+        assert_eq!(Messages::bundled(None).get("battle_recruitment.participants_title"), "参加者一覧");
+    }
+
+    #[test]
+    fn test_bundled_and_missing() {
+        let messages = messages(Vec::new(), Language::Japanese);
+        assert_eq!(messages.get("battle_recruitment.participants_title"), "参加者一覧");
+        assert_eq!(messages.get("unknown.key"), "unknown.key");
+        assert_eq!(
+            messages.get_with_params("battle_recruitment.quest_not_found", &[("alias", "ルシHL")]),
+            "「ルシHL」に一致するクエストが見つかりません。"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let variables = HashMap::from([("quest_name".to_string(), "ルシHL".to_string())]);
+        let texts = vec![message_text("battle_recruitment.template.body", "${quest_name} ${unknown}", None)];
+
+        assert_eq!(messages(Vec::new(), Language::Japanese).render("greeting", &variables), "greeting");
+        assert_eq!(messages(texts, Language::Japanese).render("battle_recruitment.template.body", &HashMap::from([
+            ("quest_name".to_string(), "ルシHL".to_string()),
+            ("element_prompt".to_string(), String::new()),
+            ("event_time".to_string(), "10/18 21:00".to_string()),
+            ("notes".to_string(), "\nフルオート可".to_string()),
+        ])), "ルシHLの参加者を募集します。\n開催日時：10/18 21:00\nフルオート可");
+    }
+
+    #[test]
+    fn test_render_missing_variable() {
+        // 値を渡されなかった変数は `${...}` のまま残さない
+        let texts = vec![message_text("battle_recruitment.template.body", "${quest_name} ${unknown}", None)];
+        let rendered = messages(texts, Language::Japanese).render("battle_recruitment.template.body", &HashMap::from([
+            ("quest_name".to_string(), "ルシHL".to_string()),
+        ]));
+
+        assert!(rendered.starts_with("ルシHLの参加者を募集します。"));
+        assert!(!rendered.contains("${"));
+    }
+}
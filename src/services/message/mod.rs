@@ -1 +1,7 @@
-mod message_service;
\ No newline at end of file
+pub mod message_service;
+pub mod message_text_cache;
+pub mod message_text_service;
+pub mod messages;
+
+pub use message_service::Language;
+pub use messages::Messages;
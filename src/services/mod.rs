@@ -1,4 +1,10 @@
 pub mod battle_recruitment;
+pub mod calendar;
 pub mod environment;
+pub mod guild_war;
 pub mod permission;
-mod message;
\ No newline at end of file
+pub mod quest;
+pub mod settings;
+pub mod message;
+pub mod recruitment_rule;
+pub mod recruitment_template;
\ No newline at end of file
@@ -0,0 +1,70 @@
+//! Command checks that declare the capability a command needs.
+//!
+//! Use them with `#[poise::command(check = "checks::manage_quests")]`. A check on a parent
+//! command also applies to its subcommands.
+
+use poise::CreateReply;
+use tracing::warn;
+use crate::services::message::{Language, Messages};
+use crate::types::{Capability, PoiseContext, PoiseError};
+use super::{has_bot_control_permission, has_capability};
+
+pub async fn manage_quests(ctx: PoiseContext<'_>) -> Result<bool, PoiseError> {
+    require(ctx, Capability::ManageQuests).await
+}
+
+/// Only the guild owner, administrators and holders of the bot control role
+pub async fn bot_control(ctx: PoiseContext<'_>) -> Result<bool, PoiseError> {
+    let Some(member) = ctx.author_member().await else {
+        return deny(ctx, "errors.guild_only", None).await;
+    };
+
+    match has_bot_control_permission(&ctx, &member).await {
+        Ok(()) => Ok(true),
+        Err(reason) => {
+            warn!("Bot control denied: {}", reason);
+            deny(ctx, "errors.bot_control_required", None).await
+        }
+    }
+}
+
+/// Only the bot owners (`BOT_OWNER_IDS` and the application owner), for commands that
+/// affect every guild
+pub async fn bot_owner(ctx: PoiseContext<'_>) -> Result<bool, PoiseError> {
+    if ctx.framework().options().owners.contains(&ctx.author().id) {
+        return Ok(true);
+    }
+    warn!("Owner only command denied: user_id={}", ctx.author().id);
+    deny(ctx, "errors.owner_only", None).await
+}
+
+async fn require(ctx: PoiseContext<'_>, capability: Capability) -> Result<bool, PoiseError> {
+    let Some(member) = ctx.author_member().await else {
+        return deny(ctx, "errors.guild_only", None).await;
+    };
+
+    match has_capability(&ctx, &member, capability).await {
+        Ok(()) => Ok(true),
+        Err(reason) => {
+            warn!("Capability {:?} denied: {}", capability, reason);
+            deny(ctx, "errors.capability_required", Some(capability)).await
+        }
+    }
+}
+
+/// Replies with an ephemeral message in the user's language, since poise only logs failed checks
+async fn deny(ctx: PoiseContext<'_>, key: &str, capability: Option<Capability>) -> Result<bool, PoiseError> {
+    let messages = Messages::for_context(&ctx).await;
+    let capability = capability
+        .map(|capability| match messages.language() {
+            Language::Japanese => capability.name(),
+            Language::English => capability.key(),
+        })
+        .unwrap_or_default();
+
+    ctx.send(CreateReply::default()
+        .content(messages.get_with_params(key, &[("capability", capability)]))
+        .ephemeral(true)
+    ).await?;
+    Ok(false)
+}
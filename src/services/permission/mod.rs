@@ -1,28 +1,112 @@
-use poise::serenity_prelude::all::{Context, Member};
-use crate::types::{PoiseContext, PoiseError};
+pub mod checks;
+pub mod permission_service;
+
+use std::sync::Arc;
+use poise::serenity_prelude::all::{Member, RoleId};
+use tracing::warn;
+use crate::models::battle_recruitment::BattleRecruitment;
+use crate::repository::Database;
+use crate::services::settings::SettingsService;
+use crate::types::{Capability, PoiseContext};
 use crate::utils::constants::ROLL_GBF_BOT_CONTROLS;
+pub use permission_service::{PermissionService, PermissionSubject};
 
 /// Checks if a member has the specified role name
 pub async fn has_role(ctx: &PoiseContext<'_>, member: &Member, role_name: &str) -> Result<(), String> {
-    let guild = ctx.guild().unwrap();
-    let role = guild.role_by_name(role_name);
-    if role.is_none() {
-        return Err(format!("role is not found: '{}'", role_name).to_string())
+    let role_id = {
+        let Some(guild) = ctx.guild() else {
+            return Err("このコマンドはサーバー内でのみ使用できます。".to_string());
+        };
+        match guild.role_by_name(role_name) {
+            Some(role) => role.id,
+            None => return Err(format!("role is not found: '{}'", role_name)),
+        }
+    };
+
+    if member.roles.contains(&role_id) {
+        Ok(())
+    } else {
+        Err(format!("'{}' is roll '{}' not found.", member.display_name(),  role_name))
+    }
+}
+
+/// Checks if a member can control the bot: the guild owner, members with the Administrator
+/// permission, or members with the bot control role configured for the guild (gbf_bot_control by default)
+pub async fn has_bot_control_permission(ctx: &PoiseContext<'_>, member: &Member) -> Result<(), String> {
+    if is_guild_administrator(ctx, member) {
+        return Ok(());
+    }
+
+    let role_name = bot_control_role_name(ctx).await;
+    has_role(ctx, member, &role_name).await
+}
+
+/// Checks if a member has a capability, either as a bot controller or through a role or user grant
+pub async fn has_capability(ctx: &PoiseContext<'_>, member: &Member, capability: Capability) -> Result<(), String> {
+    if has_bot_control_permission(ctx, member).await.is_ok() {
+        return Ok(());
     }
-    let role = role.unwrap();
 
-    let has_permission = member.roles.iter().any(|role_id| {
-        role_id.eq(&role.id)
-    });
-    if has_permission {
+    let db = Database::new().await
+        .map_err(|e| format!("データベース接続エラー: {}", e))?;
+    let role_ids: Vec<u64> = member.roles.iter().map(|role_id| role_id.get()).collect();
+    let granted = PermissionService::new(Arc::new(db))
+        .is_granted(member.guild_id.get() as i64, capability, member.user.id.get(), &role_ids)
+        .await?;
+
+    if granted {
         Ok(())
     } else {
-        Err(format!("'{}' is roll '{}' not found.", member.display_name(),  role_name).to_string())
+        Err(format!("この操作には「{}」の権限が必要です。", capability.name()))
     }
+}
 
+/// Checks if a member can manage a recruitment (edit, transfer, start or cancel it): its creator,
+/// or members with the cancel_others_recruitments capability
+pub async fn can_manage_recruitment(ctx: &PoiseContext<'_>, member: &Member, recruitment: &BattleRecruitment) -> Result<(), String> {
+    if recruitment.is_created_by(member.user.id.get()) {
+        return Ok(());
+    }
+    has_capability(ctx, member, Capability::CancelOthersRecruitments).await
 }
 
-/// Checks if a member has the gbf_bot_control role
-pub async fn has_bot_control_permission(ctx: &PoiseContext<'_>, member: &Member) -> Result<(), String> {
-    has_role(ctx, member, ROLL_GBF_BOT_CONTROLS).await
+/// The guild owner and members with the Administrator permission can always control the bot,
+/// so that renaming the bot control role does not lock everyone out
+fn is_guild_administrator(ctx: &PoiseContext<'_>, member: &Member) -> bool {
+    let Some(guild) = ctx.guild() else {
+        return false;
+    };
+    if guild.owner_id == member.user.id {
+        return true;
+    }
+
+    // @everyone ロールのIDはサーバーIDと同じ
+    let everyone = RoleId::new(guild.id.get());
+    member.roles.iter()
+        .chain(std::iter::once(&everyone))
+        .filter_map(|role_id| guild.roles.get(role_id))
+        .any(|role| role.permissions.administrator())
+}
+
+/// Resolves the bot control role name from the guild settings, falling back to the default role
+async fn bot_control_role_name(ctx: &PoiseContext<'_>) -> String {
+    let Some(guild_id) = ctx.guild_id() else {
+        return ROLL_GBF_BOT_CONTROLS.to_string();
+    };
+
+    let db = match Database::new().await {
+        Ok(database) => Arc::new(database),
+        Err(e) => {
+            warn!("Failed to load guild settings, using the default bot control role: {}", e);
+            return ROLL_GBF_BOT_CONTROLS.to_string();
+        }
+    };
+
+    match SettingsService::new(db).get(guild_id.get() as i64).await {
+        Ok(settings) => settings.admin_role_name().to_string(),
+        Err(e) => {
+            warn!("Failed to load guild settings, using the default bot control role: {}", e);
+            ROLL_GBF_BOT_CONTROLS.to_string()
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+use crate::repository::Database;
+use crate::models::guild_permission::GuildPermission;
+use crate::types::Capability;
+
+/// 権限を付与する対象
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionSubject {
+    Role(u64),
+    User(u64),
+}
+
+impl PermissionSubject {
+    /// Discordのメンション形式
+    pub fn mention(&self) -> String {
+        match self {
+            Self::Role(role_id) => format!("<@&{}>", role_id),
+            Self::User(user_id) => format!("<@{}>", user_id),
+        }
+    }
+
+    /// (role_id, user_id) の組に変換する
+    fn columns(&self) -> (Option<i64>, Option<i64>) {
+        match self {
+            Self::Role(role_id) => (Some(*role_id as i64), None),
+            Self::User(user_id) => (None, Some(*user_id as i64)),
+        }
+    }
+
+    fn from_permission(permission: &GuildPermission) -> Option<Self> {
+        match (permission.role_id, permission.user_id) {
+            (Some(role_id), None) => Some(Self::Role(role_id as u64)),
+            (None, Some(user_id)) => Some(Self::User(user_id as u64)),
+            _ => None,
+        }
+    }
+}
+
+/// サーバーごとの権限付与を管理するサービス
+/// ロール名ではなくIDで付与するため、ロール名を変更しても権限は失われない
+pub struct PermissionService {
+    db: Arc<Database>,
+}
+
+impl PermissionService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// サーバーで付与されている権限の一覧を取得する
+    pub async fn list(&self, guild_id: i64) -> Result<Vec<(Capability, PermissionSubject)>, String> {
+        let permissions = self.db.guild_permission.get_by_guild(guild_id).await
+            .map_err(|e| {
+                error!("Error getting guild permissions: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        Ok(permissions.iter().filter_map(|permission| {
+            let capability = Capability::from_key(&permission.capability);
+            let subject = PermissionSubject::from_permission(permission);
+            if capability.is_none() || subject.is_none() {
+                warn!("Ignoring invalid guild permission: id={}", permission.id);
+            }
+            Some((capability?, subject?))
+        }).collect())
+    }
+
+    /// 権限を付与する
+    pub async fn grant(&self, guild_id: i64, capability: Capability, subject: PermissionSubject) -> Result<(), String> {
+        if self.list(guild_id).await?.contains(&(capability, subject)) {
+            return Err(format!("{} には既に「{}」の権限が付与されています。", subject.mention(), capability.name()));
+        }
+
+        let (role_id, user_id) = subject.columns();
+        self.db.guild_permission.grant(guild_id, capability.key(), role_id, user_id).await
+            .map_err(|e| {
+                error!("Error granting guild permission: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        info!("Guild permission granted: guild_id={}, capability={:?}, subject={:?}", guild_id, capability, subject);
+        Ok(())
+    }
+
+    /// 権限を取り消す
+    pub async fn revoke(&self, guild_id: i64, capability: Capability, subject: PermissionSubject) -> Result<(), String> {
+        let (role_id, user_id) = subject.columns();
+        match self.db.guild_permission.revoke(guild_id, capability.key(), role_id, user_id).await {
+            Ok(true) => {
+                info!("Guild permission revoked: guild_id={}, capability={:?}, subject={:?}", guild_id, capability, subject);
+                Ok(())
+            },
+            Ok(false) => Err(format!("{} に「{}」の権限は付与されていません。", subject.mention(), capability.name())),
+            Err(e) => {
+                error!("Error revoking guild permission: {:?}", e);
+                Err(format!("Database error: {}", e))
+            }
+        }
+    }
+
+    /// ユーザー本人または所持しているロールに権限が付与されているか
+    pub async fn is_granted(&self, guild_id: i64, capability: Capability, user_id: u64, role_ids: &[u64]) -> Result<bool, String> {
+        let permissions = self.list(guild_id).await?;
+        Ok(permissions.iter().any(|(granted, subject)| *granted == capability && match subject {
+            PermissionSubject::Role(role_id) => role_ids.contains(role_id),
+            PermissionSubject::User(granted_user_id) => *granted_user_id == user_id,
+        }))
+    }
+}
@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+
+/// CSVのヘッダー行
+const CSV_HEADER: [&str; 4] = ["target_id", "quest_name", "default_battle_type", "aliases"];
+
+/// CSVで別名を1列に格納する際の区切り文字
+const ALIAS_SEPARATOR: char = '|';
+
+/// クエストマスタのインポート・エクスポート形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum CatalogFormat {
+    #[name = "JSON"]
+    Json,
+    #[name = "CSV"]
+    Csv,
+}
+
+impl CatalogFormat {
+    /// ファイル名の拡張子から形式を判定する
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        let extension = filename.rsplit_once('.')?.1.to_lowercase();
+        Self::from_name(&extension)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// クエスト1件分のレコード（quests と quests_alias をまとめたもの）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuestRecord {
+    pub target_id: i32,
+    pub quest_name: String,
+    pub default_battle_type: i32,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// レコードを指定形式の文字列に変換する
+pub fn serialize(records: &[QuestRecord], format: CatalogFormat) -> Result<String, String> {
+    match format {
+        CatalogFormat::Json => serde_json::to_string_pretty(records)
+            .map_err(|e| format!("JSONの生成に失敗しました: {}", e)),
+        CatalogFormat::Csv => Ok(to_csv(records)),
+    }
+}
+
+/// 指定形式の文字列からレコードを読み込む
+pub fn deserialize(content: &str, format: CatalogFormat) -> Result<Vec<QuestRecord>, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    match format {
+        CatalogFormat::Json => serde_json::from_str(content)
+            .map_err(|e| format!("JSONの読み込みに失敗しました: {}", e)),
+        CatalogFormat::Csv => from_csv(content),
+    }
+}
+
+fn to_csv(records: &[QuestRecord]) -> String {
+    let mut lines = vec![CSV_HEADER.join(",")];
+    for record in records {
+        let aliases = record.aliases.join(&ALIAS_SEPARATOR.to_string());
+        let fields = [
+            record.target_id.to_string(),
+            record.quest_name.clone(),
+            record.default_battle_type.to_string(),
+            aliases,
+        ];
+        lines.push(fields.iter().map(|f| escape_csv_field(f)).collect::<Vec<_>>().join(","));
+    }
+    lines.join("\n") + "\n"
+}
+
+fn from_csv(content: &str) -> Result<Vec<QuestRecord>, String> {
+    let rows = parse_csv_rows(content)?;
+    let mut rows = rows.into_iter().filter(|row| !(row.len() == 1 && row[0].trim().is_empty()));
+
+    let header = rows.next().ok_or_else(|| "CSVが空です。".to_string())?;
+    let header: Vec<String> = header.iter().map(|h| h.trim().to_lowercase()).collect();
+    let column = |name: &str| header.iter().position(|h| h == name);
+    let (Some(target_id_col), Some(quest_name_col), Some(battle_type_col)) =
+        (column("target_id"), column("quest_name"), column("default_battle_type")) else {
+        return Err(format!("CSVのヘッダーが不正です。必要な列: {}", CSV_HEADER.join(",")));
+    };
+    let aliases_col = column("aliases");
+
+    let mut records = Vec::new();
+    for (index, row) in rows.enumerate() {
+        // ヘッダーを1行目として数える
+        let line = index + 2;
+        let field = |col: usize| row.get(col).map(|s| s.trim()).unwrap_or("");
+
+        let target_id = field(target_id_col).parse::<i32>()
+            .map_err(|_| format!("{}行目: target_id が数値ではありません: '{}'", line, field(target_id_col)))?;
+        let default_battle_type = field(battle_type_col).parse::<i32>()
+            .map_err(|_| format!("{}行目: default_battle_type が数値ではありません: '{}'", line, field(battle_type_col)))?;
+        let aliases = aliases_col
+            .map(|col| field(col).split(ALIAS_SEPARATOR)
+                .map(|a| a.trim().to_string())
+                .filter(|a| !a.is_empty())
+                .collect())
+            .unwrap_or_default();
+
+        records.push(QuestRecord {
+            target_id,
+            quest_name: field(quest_name_col).to_string(),
+            default_battle_type,
+            aliases,
+        });
+    }
+    Ok(records)
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// RFC 4180 形式のCSVを行・列に分解する
+fn parse_csv_rows(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                },
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {},
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            },
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err("CSVの引用符が閉じられていません。".to_string());
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_records() -> Vec<QuestRecord> {
+        vec![
+            QuestRecord {
+                target_id: 301061,
+                quest_name: "ルシファーHL".to_string(),
+                default_battle_type: 1,
+                aliases: vec!["ルシHL".to_string(), "ダークラプチャーHL".to_string()],
+            },
+            QuestRecord {
+                target_id: 305241,
+                quest_name: "Beelzebub, \"Impossible\"".to_string(),
+                default_battle_type: 7,
+                aliases: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_catalog_format_from_filename() {
+        assert_eq!(CatalogFormat::from_filename("quests.json"), Some(CatalogFormat::Json));
+        assert_eq!(CatalogFormat::from_filename("Quests.CSV"), Some(CatalogFormat::Csv));
+        assert_eq!(CatalogFormat::from_filename("quests.txt"), None);
+        assert_eq!(CatalogFormat::from_filename("quests"), None);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let records = sample_records();
+        let json = serialize(&records, CatalogFormat::Json).unwrap();
+        assert_eq!(deserialize(&json, CatalogFormat::Json).unwrap(), records);
+    }
+
+    #[test]
+    fn test_json_without_aliases() {
+        let json = r#"[{"target_id": 1, "quest_name": "テスト", "default_battle_type": 0}]"#;
+        let records = deserialize(json, CatalogFormat::Json).unwrap();
+        assert_eq!(records.len(), 1);
+        assert!(records[0].aliases.is_empty());
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let records = sample_records();
+        let csv = serialize(&records, CatalogFormat::Csv).unwrap();
+        assert!(csv.starts_with("target_id,quest_name,default_battle_type,aliases\n"));
+        assert!(csv.contains("\"Beelzebub, \"\"Impossible\"\"\""));
+        assert_eq!(deserialize(&csv, CatalogFormat::Csv).unwrap(), records);
+    }
+
+    #[test]
+    fn test_csv_with_bom_crlf_and_reordered_columns() {
+        let csv = "\u{feff}quest_name,target_id,default_battle_type\r\nルシファーHL,301061,1\r\n\r\n";
+        let records = deserialize(csv, CatalogFormat::Csv).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].target_id, 301061);
+        assert_eq!(records[0].quest_name, "ルシファーHL");
+        assert!(records[0].aliases.is_empty());
+    }
+
+    #[test]
+    fn test_csv_invalid_rows() {
+        assert!(deserialize("", CatalogFormat::Csv).is_err());
+        assert!(deserialize("name,id\nfoo,1\n", CatalogFormat::Csv).is_err());
+
+        let err = deserialize("target_id,quest_name,default_battle_type\nabc,foo,1\n", CatalogFormat::Csv).unwrap_err();
+        assert!(err.contains("2行目"));
+
+        assert!(deserialize("target_id,quest_name,default_battle_type\n1,\"foo,1\n", CatalogFormat::Csv).is_err());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod catalog;
+pub mod quest_service;
+
+pub use quest_service::QuestService;
@@ -0,0 +1,318 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{error, info};
+
+use crate::repository::Database;
+use crate::repository::quest_repository::CatalogWrite;
+use crate::models::quest::{Quest, QuestAlias};
+use crate::types::BattleType;
+use super::catalog::QuestRecord;
+
+/// クエストマスタ取り込み結果
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub aliases_added: usize,
+}
+
+impl std::fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "追加 {}件 / 更新 {}件 / 変更なし {}件 / 別名追加 {}件",
+            self.created, self.updated, self.unchanged, self.aliases_added
+        )
+    }
+}
+
+/// クエストとクエスト別名のマスタを管理するサービス
+pub struct QuestService {
+    db: Arc<Database>,
+}
+
+impl QuestService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// クエストを追加する
+    /// guild_id が None の場合は全サーバー共通のクエストとして追加する
+    pub async fn add_quest(&self, guild_id: Option<i64>, target_id: i32, quest_name: &str, default_battle_type: i32) -> Result<Quest, String> {
+        let quest_name = Self::validate_quest_name(quest_name)?;
+        Self::validate_target_id(target_id)?;
+        Self::validate_battle_type(default_battle_type)?;
+
+        if self.find_quest(guild_id, target_id).await?.is_some_and(|quest| quest.guild_id == guild_id) {
+            return Err(format!("target_id {} のクエストは既に登録されています。", target_id));
+        }
+
+        let quest = self.db.quest.create(guild_id, target_id, quest_name, default_battle_type).await
+            .map_err(|e| {
+                error!("Error creating quest: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        info!("Quest added: guild_id={:?}, target_id={}, name={}", guild_id, quest.target_id, quest.quest_name);
+        Ok(quest)
+    }
+
+    /// クエストを編集する
+    pub async fn edit_quest(&self, guild_id: Option<i64>, target_id: i32, quest_name: Option<&str>, default_battle_type: Option<i32>) -> Result<Quest, String> {
+        let quest_name = quest_name.map(Self::validate_quest_name).transpose()?;
+        if let Some(default_battle_type) = default_battle_type {
+            Self::validate_battle_type(default_battle_type)?;
+        }
+        if quest_name.is_none() && default_battle_type.is_none() {
+            return Err("変更する項目を指定してください。".to_string());
+        }
+
+        match self.db.quest.update(guild_id, target_id, quest_name, default_battle_type).await {
+            Ok(Some(quest)) => {
+                info!("Quest updated: guild_id={:?}, target_id={}", guild_id, quest.target_id);
+                Ok(quest)
+            },
+            Ok(None) => Err(Self::not_found_message(guild_id, target_id)),
+            Err(e) => {
+                error!("Error updating quest: {:?}", e);
+                Err(format!("Database error: {}", e))
+            }
+        }
+    }
+
+    /// クエストを別名ごと削除する
+    pub async fn remove_quest(&self, guild_id: Option<i64>, target_id: i32) -> Result<(), String> {
+        match self.db.quest.delete(guild_id, target_id).await {
+            Ok(true) => {
+                info!("Quest removed: guild_id={:?}, target_id={}", guild_id, target_id);
+                Ok(())
+            },
+            Ok(false) => Err(Self::not_found_message(guild_id, target_id)),
+            Err(e) => {
+                error!("Error removing quest: {:?}", e);
+                Err(format!("Database error: {}", e))
+            }
+        }
+    }
+
+    /// サーバーから見えるクエスト一覧を別名付きで取得する（サーバー独自の登録が共通の登録より優先される）
+    pub async fn list_quests(&self, guild_id: Option<i64>) -> Result<Vec<(Quest, Vec<QuestAlias>)>, String> {
+        let mut quests = self.db.quest.get_all(guild_id).await
+            .map_err(|e| format!("Database error: {}", e))?;
+        let mut aliases = self.db.quest.get_aliases(guild_id).await
+            .map_err(|e| format!("Database error: {}", e))?;
+
+        quests.sort_by_key(|quest| quest.target_id);
+        aliases.sort_by(|a, b| a.alias.cmp(&b.alias));
+        Ok(quests.into_iter().map(|quest| {
+            let quest_aliases = aliases.iter()
+                .filter(|alias| alias.target_id == quest.target_id)
+                .cloned()
+                .collect();
+            (quest, quest_aliases)
+        }).collect())
+    }
+
+    /// クエストに別名を追加する
+    /// サーバー独自の別名は、同じ文字列の共通の別名より優先される
+    pub async fn add_alias(&self, guild_id: Option<i64>, target_id: i32, alias: &str) -> Result<QuestAlias, String> {
+        let alias = alias.trim();
+        if alias.is_empty() {
+            return Err("別名を入力してください。".to_string());
+        }
+
+        if self.find_quest(guild_id, target_id).await?.is_none() {
+            return Err(format!("target_id {} のクエストが見つかりません。", target_id));
+        }
+
+        let aliases = self.db.quest.get_aliases(guild_id).await
+            .map_err(|e| format!("Database error: {}", e))?;
+        if let Some(existing) = aliases.iter().find(|a| a.alias == alias && a.guild_id == guild_id) {
+            return Err(format!("別名「{}」は既に target_id {} で使用されています。", alias, existing.target_id));
+        }
+
+        let quest_alias = self.db.quest.add_alias(guild_id, target_id, alias).await
+            .map_err(|e| {
+                error!("Error adding quest alias: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        info!("Quest alias added: guild_id={:?}, target_id={}, alias={}", guild_id, target_id, alias);
+        Ok(quest_alias)
+    }
+
+    /// 別名を削除する
+    pub async fn remove_alias(&self, guild_id: Option<i64>, alias: &str) -> Result<(), String> {
+        match self.db.quest.remove_alias(guild_id, alias.trim()).await {
+            Ok(true) => {
+                info!("Quest alias removed: guild_id={:?}, alias={}", guild_id, alias);
+                Ok(())
+            },
+            Ok(false) => Err(format!("別名「{}」が見つかりません。", alias)),
+            Err(e) => {
+                error!("Error removing quest alias: {:?}", e);
+                Err(format!("Database error: {}", e))
+            }
+        }
+    }
+
+    /// 別名一覧を取得する（target_id指定時はそのクエストの別名のみ）
+    pub async fn list_aliases(&self, guild_id: Option<i64>, target_id: Option<i32>) -> Result<Vec<QuestAlias>, String> {
+        let result = match target_id {
+            Some(target_id) => self.db.quest.get_aliases_by_target_id(guild_id, target_id).await,
+            None => self.db.quest.get_aliases(guild_id).await,
+        };
+
+        let mut aliases = result.map_err(|e| format!("Database error: {}", e))?;
+        aliases.sort_by(|a, b| a.target_id.cmp(&b.target_id).then_with(|| a.alias.cmp(&b.alias)));
+        Ok(aliases)
+    }
+
+    /// サーバーから見えるクエストマスタをエクスポート用のレコードに変換する
+    pub async fn export_catalog(&self, guild_id: Option<i64>) -> Result<Vec<QuestRecord>, String> {
+        let quests = self.list_quests(guild_id).await?;
+        Ok(quests.into_iter().map(|(quest, aliases)| QuestRecord {
+            target_id: quest.target_id,
+            quest_name: quest.quest_name,
+            default_battle_type: quest.default_battle_type,
+            aliases: aliases.into_iter().map(|a| a.alias).collect(),
+        }).collect())
+    }
+
+    /// レコードをtarget_idをキーにしてクエストマスタへ取り込む
+    /// 全レコードを検証してから反映し、不正な行があれば何も反映せずにすべてのエラーを返す
+    /// サーバーへの取り込みでは、共通のクエストと内容が異なるものだけをサーバー独自の登録として追加する
+    pub async fn import_catalog(&self, guild_id: Option<i64>, records: &[QuestRecord]) -> Result<ImportSummary, String> {
+        let existing_aliases: HashMap<String, QuestAlias> = self.db.quest.get_aliases(guild_id).await
+            .map_err(|e| format!("Database error: {}", e))?
+            .into_iter()
+            .map(|a| (a.alias.clone(), a))
+            .collect();
+
+        Self::validate_records(guild_id, records, &existing_aliases)?;
+
+        // 変更内容をまとめてから1つのトランザクションで書き込み、途中で失敗した場合は何も反映しない
+        let mut summary = ImportSummary::default();
+        let mut writes = Vec::new();
+        let mut added_aliases = HashSet::new();
+        for record in records {
+            let quest_name = record.quest_name.trim();
+            match self.find_quest(guild_id, record.target_id).await? {
+                Some(quest) if quest.quest_name == quest_name && quest.default_battle_type == record.default_battle_type => {
+                    summary.unchanged += 1;
+                },
+                Some(quest) if quest.guild_id == guild_id => {
+                    writes.push(CatalogWrite::UpdateQuest {
+                        target_id: record.target_id,
+                        quest_name: quest_name.to_string(),
+                        default_battle_type: record.default_battle_type,
+                    });
+                    summary.updated += 1;
+                },
+                _ => {
+                    writes.push(CatalogWrite::CreateQuest {
+                        target_id: record.target_id,
+                        quest_name: quest_name.to_string(),
+                        default_battle_type: record.default_battle_type,
+                    });
+                    summary.created += 1;
+                },
+            }
+
+            for alias in &record.aliases {
+                let alias = alias.trim();
+                if alias.is_empty()
+                    || existing_aliases.get(alias).is_some_and(|a| a.target_id == record.target_id)
+                    || !added_aliases.insert(alias.to_string()) {
+                    continue;
+                }
+                writes.push(CatalogWrite::AddAlias { target_id: record.target_id, alias: alias.to_string() });
+                summary.aliases_added += 1;
+            }
+        }
+
+        self.db.quest.import_catalog(guild_id, &writes).await
+            .map_err(|e| format!("取り込みに失敗したため、変更は保存されていません: {}", e))?;
+
+        info!("Quest catalog imported: guild_id={:?}, {:?}", guild_id, summary);
+        Ok(summary)
+    }
+
+    fn validate_records(guild_id: Option<i64>, records: &[QuestRecord], existing_aliases: &HashMap<String, QuestAlias>) -> Result<(), String> {
+        let mut errors = Vec::new();
+        let mut target_ids = HashSet::new();
+        let mut file_aliases: HashMap<&str, i32> = HashMap::new();
+
+        for record in records {
+            let label = format!("target_id {}", record.target_id);
+            if let Err(e) = Self::validate_target_id(record.target_id) {
+                errors.push(format!("{}: {}", label, e));
+            }
+            if let Err(e) = Self::validate_quest_name(&record.quest_name) {
+                errors.push(format!("{}: {}", label, e));
+            }
+            if let Err(e) = Self::validate_battle_type(record.default_battle_type) {
+                errors.push(format!("{}: {}", label, e));
+            }
+            if !target_ids.insert(record.target_id) {
+                errors.push(format!("{}: ファイル内で重複しています。", label));
+            }
+
+            for alias in record.aliases.iter().map(|a| a.trim()).filter(|a| !a.is_empty()) {
+                if let Some(other) = file_aliases.insert(alias, record.target_id)
+                    && other != record.target_id {
+                    errors.push(format!("別名「{}」が target_id {} と {} で重複しています。", alias, other, record.target_id));
+                }
+                // 共通の別名はサーバー独自の別名で上書きできるため、同じ範囲の別名とだけ比較する
+                if let Some(other) = existing_aliases.get(alias)
+                    && other.guild_id == guild_id
+                    && other.target_id != record.target_id {
+                    errors.push(format!("別名「{}」は既に target_id {} で使用されています。", alias, other.target_id));
+                }
+            }
+        }
+
+        if records.is_empty() {
+            errors.push("取り込むレコードがありません。".to_string());
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+
+    async fn find_quest(&self, guild_id: Option<i64>, target_id: i32) -> Result<Option<Quest>, String> {
+        self.db.quest.get_by_target_id(guild_id, target_id).await
+            .map_err(|e| format!("Database error: {}", e))
+    }
+
+    fn not_found_message(guild_id: Option<i64>, target_id: i32) -> String {
+        match guild_id {
+            Some(_) => format!("target_id {} のクエストはこのサーバーに登録されていません。", target_id),
+            None => format!("target_id {} のクエストが見つかりません。", target_id),
+        }
+    }
+
+    fn validate_quest_name(quest_name: &str) -> Result<&str, String> {
+        let quest_name = quest_name.trim();
+        if quest_name.is_empty() {
+            return Err("クエスト名を入力してください。".to_string());
+        }
+        Ok(quest_name)
+    }
+
+    fn validate_target_id(target_id: i32) -> Result<(), String> {
+        if target_id <= 0 {
+            return Err("target_id は正の整数で指定してください。".to_string());
+        }
+        Ok(())
+    }
+
+    fn validate_battle_type(value: i32) -> Result<BattleType, String> {
+        BattleType::from_value(value)
+            .ok_or_else(|| format!("default_battle_type {} は無効な値です。(0〜7で指定してください)", value))
+    }
+}
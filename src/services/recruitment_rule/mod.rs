@@ -0,0 +1,2 @@
+pub mod rule_service;
+pub use rule_service::{RecruitmentRuleService, RuleInput};
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::{DateTime, FixedOffset, NaiveTime, Utc, Weekday};
+use tracing::{error, info};
+
+use crate::models::recruitment_rule::RecruitmentRule;
+use crate::repository::Database;
+use crate::repository::recruitment_rule_repository::NewRecruitmentRule;
+use crate::services::settings::SettingsService;
+use crate::types::BattleType;
+
+/// 投稿する時刻（開始時刻の何分前）の既定値
+pub const DEFAULT_LEAD_TIME_MINUTES: i64 = 24 * 60;
+
+/// 投稿する時刻の下限
+/// スケジューラーは1分ごとに確認するため、それより長くして開始前に必ず投稿されるようにする
+pub const MIN_LEAD_TIME_MINUTES: i64 = 5;
+
+/// 投稿する時刻の上限（翌週の回と重ならないように6日まで）
+pub const MAX_LEAD_TIME_MINUTES: i64 = 6 * 24 * 60;
+
+/// 1サーバーあたりのルールの上限
+pub const MAX_RULES_PER_GUILD: usize = 25;
+
+/// 追加するルールの入力値
+pub struct RuleInput<'a> {
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub quest_alias: &'a str,
+    pub battle_type: BattleType,
+    pub weekday: Weekday,
+    /// "HH:MM"（省略時はサーバー設定の開始時刻）
+    pub start_time: Option<&'a str>,
+    /// 開始時刻の何分前に投稿するか（省略時は1日前）
+    pub lead_time_minutes: Option<i64>,
+    pub created_by: i64,
+}
+
+/// 定期募集のルールを管理するサービス
+pub struct RecruitmentRuleService {
+    db: Arc<Database>,
+}
+
+impl RecruitmentRuleService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// サーバーのルールと、それぞれの次回の開始時刻を取得する
+    pub async fn list(&self, guild_id: i64) -> Result<Vec<(RecruitmentRule, DateTime<Utc>)>, String> {
+        let rules = self.db.recruitment_rule.get_by_guild(guild_id).await
+            .map_err(|e| {
+                error!("Error getting recruitment rules: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        let timezone = self.timezone(guild_id).await?;
+        let now = Utc::now();
+        Ok(rules.into_iter()
+            .map(|rule| {
+                let event = rule.next_event(now, timezone);
+                (rule, event)
+            })
+            .collect())
+    }
+
+    /// ルールを追加する
+    pub async fn add(&self, input: RuleInput<'_>) -> Result<RecruitmentRule, String> {
+        let lead_time_minutes = validate_lead_time(input.lead_time_minutes)?;
+        let quest_alias = input.quest_alias.trim();
+
+        let settings = SettingsService::new(self.db.clone()).get(input.guild_id).await?;
+        let start_time = match input.start_time.map(str::trim).filter(|time| !time.is_empty()) {
+            Some(start_time) => parse_start_time(start_time)?,
+            None => settings.default_start_time(),
+        };
+
+        match self.db.quest.get_by_alias(Some(input.guild_id), quest_alias).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Err(format!("クエスト「{}」が見つかりません。", quest_alias)),
+            Err(e) => {
+                error!("Error getting quest for recruitment rule: {:?}", e);
+                return Err(format!("Database error: {}", e));
+            }
+        }
+
+        let existing = self.db.recruitment_rule.get_by_guild(input.guild_id).await
+            .map_err(|e| {
+                error!("Error getting recruitment rules: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+        if existing.len() >= MAX_RULES_PER_GUILD {
+            return Err(format!("定期募集は1サーバーあたり{}件まで登録できます。", MAX_RULES_PER_GUILD));
+        }
+
+        let rule = self.db.recruitment_rule.create(NewRecruitmentRule {
+            guild_id: input.guild_id,
+            channel_id: input.channel_id,
+            quest_alias: quest_alias.to_string(),
+            battle_type_id: input.battle_type as i32,
+            weekday: input.weekday.num_days_from_monday() as i16,
+            start_time: start_time.format("%H:%M").to_string(),
+            lead_time_minutes: lead_time_minutes as i32,
+            created_by: input.created_by,
+        }).await
+            .map_err(|e| {
+                error!("Error creating recruitment rule: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        info!("Recruitment rule added: guild_id={}, id={}", rule.guild_id, rule.id);
+        Ok(rule)
+    }
+
+    /// ルールを停止・再開する。ルールが存在しない場合は false
+    pub async fn set_paused(&self, guild_id: i64, id: i32, paused: bool) -> Result<bool, String> {
+        let updated = self.db.recruitment_rule.set_paused(guild_id, id, paused).await
+            .map_err(|e| {
+                error!("Error updating recruitment rule: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        if updated {
+            info!("Recruitment rule {}: guild_id={}, id={}", if paused { "paused" } else { "resumed" }, guild_id, id);
+        }
+        Ok(updated)
+    }
+
+    /// ルールを削除する。ルールが存在しない場合は false
+    pub async fn delete(&self, guild_id: i64, id: i32) -> Result<bool, String> {
+        let deleted = self.db.recruitment_rule.delete(guild_id, id).await
+            .map_err(|e| {
+                error!("Error deleting recruitment rule: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        if deleted {
+            info!("Recruitment rule deleted: guild_id={}, id={}", guild_id, id);
+        }
+        Ok(deleted)
+    }
+
+    /// 投稿時刻を過ぎたルールと、その回の開始時刻を取得する
+    pub async fn due(&self, now: DateTime<Utc>) -> Result<Vec<(RecruitmentRule, DateTime<Utc>)>, String> {
+        let rules = self.db.recruitment_rule.get_active().await
+            .map_err(|e| {
+                error!("Error getting active recruitment rules: {:?}", e);
+                format!("Database error: {}", e)
+            })?;
+
+        let mut timezones: HashMap<i64, FixedOffset> = HashMap::new();
+        let mut due = Vec::new();
+        for rule in rules {
+            let timezone = match timezones.get(&rule.guild_id) {
+                Some(timezone) => *timezone,
+                None => {
+                    let timezone = self.timezone(rule.guild_id).await?;
+                    timezones.insert(rule.guild_id, timezone);
+                    timezone
+                }
+            };
+            if let Some(event) = rule.due_event(now, timezone) {
+                due.push((rule, event));
+            }
+        }
+        Ok(due)
+    }
+
+    /// その回を投稿済みとして記録する
+    /// 既に記録されていた場合（他のプロセスが投稿した場合）は false
+    pub async fn claim(&self, rule: &RecruitmentRule, event: DateTime<Utc>) -> Result<bool, String> {
+        self.db.recruitment_rule.mark_posted(rule.id, event).await
+            .map_err(|e| {
+                error!("Error marking recruitment rule as posted: {:?}", e);
+                format!("Database error: {}", e)
+            })
+    }
+
+    async fn timezone(&self, guild_id: i64) -> Result<FixedOffset, String> {
+        let settings = SettingsService::new(self.db.clone()).get(guild_id).await?;
+        Ok(settings.timezone())
+    }
+}
+
+fn parse_start_time(value: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .map_err(|_| format!("開始時刻は HH:MM 形式で指定してください: {}", value))
+}
+
+fn validate_lead_time(minutes: Option<i64>) -> Result<i64, String> {
+    let minutes = minutes.unwrap_or(DEFAULT_LEAD_TIME_MINUTES);
+    if !(MIN_LEAD_TIME_MINUTES..=MAX_LEAD_TIME_MINUTES).contains(&minutes) {
+        return Err(format!("投稿する時刻は開始時刻の{}〜{}分前で指定してください。", MIN_LEAD_TIME_MINUTES, MAX_LEAD_TIME_MINUTES));
+    }
+    Ok(minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_start_time() {
+        assert_eq!(parse_start_time("21:00").unwrap(), NaiveTime::from_hms_opt(21, 0, 0).unwrap());
+        assert_eq!(parse_start_time("7:30").unwrap(), NaiveTime::from_hms_opt(7, 30, 0).unwrap());
+        assert!(parse_start_time("25:00").is_err());
+        assert!(parse_start_time("21時").is_err());
+    }
+
+    #[test]
+    fn test_validate_lead_time() {
+        assert_eq!(validate_lead_time(None).unwrap(), DEFAULT_LEAD_TIME_MINUTES);
+        assert_eq!(validate_lead_time(Some(MIN_LEAD_TIME_MINUTES)).unwrap(), MIN_LEAD_TIME_MINUTES);
+        assert_eq!(validate_lead_time(Some(MAX_LEAD_TIME_MINUTES)).unwrap(), MAX_LEAD_TIME_MINUTES);
+        // 開始時刻ちょうどでは、確認の間隔のために投稿される前に開始時刻を過ぎてしまう
+        assert!(validate_lead_time(Some(0)).is_err());
+        assert!(validate_lead_time(Some(-1)).is_err());
+        assert!(validate_lead_time(Some(MAX_LEAD_TIME_MINUTES + 1)).is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod template_service;
+pub use template_service::{RecruitmentTemplateService, TemplateInput};
@@ -0,0 +1,241 @@
+use std::sync::Arc;
+use tracing::{error, info};
+
+use crate::models::recruitment_template::RecruitmentTemplate;
+use crate::repository::Database;
+use crate::repository::recruitment_template_repository::NewRecruitmentTemplate;
+use crate::services::battle_recruitment::{DEFAULT_CAPACITY, MAX_CAPACITY, MAX_NOTES_LENGTH};
+use crate::services::message::Messages;
+use crate::types::BattleType;
+use crate::utils::text_normalizer::normalize_alias;
+
+/// 1人あたりのテンプレートの上限（オートコンプリートで表示できる件数）
+pub const MAX_TEMPLATES_PER_USER: usize = 25;
+
+/// テンプレート名の上限
+pub const MAX_NAME_LENGTH: usize = 50;
+
+/// 保存するテンプレートの入力値
+pub struct TemplateInput<'a> {
+    pub guild_id: i64,
+    pub owner_id: i64,
+    pub name: &'a str,
+    pub quest_alias: &'a str,
+    pub battle_type: BattleType,
+    /// 省略時は6人
+    pub capacity: Option<i64>,
+    pub notes: Option<&'a str>,
+    /// 省略時はサーバー設定の募集チャンネル
+    pub channel_id: Option<i64>,
+}
+
+/// 募集テンプレートを管理するサービス
+/// エラーは `messages`（実行したユーザーの言語）で返す
+pub struct RecruitmentTemplateService {
+    db: Arc<Database>,
+}
+
+impl RecruitmentTemplateService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// メンバーが使えるテンプレート（自分のものと公開されたもの）を取得する
+    pub async fn list(&self, messages: &Messages, guild_id: i64, user_id: i64) -> Result<Vec<RecruitmentTemplate>, String> {
+        self.db.recruitment_template.get_visible(guild_id, user_id).await
+            .map_err(|e| {
+                error!("Error getting recruitment templates: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })
+    }
+
+    /// 名前からテンプレートを取得する（自分のものを公開されたものより優先する）
+    pub async fn find(&self, messages: &Messages, guild_id: i64, user_id: i64, name: &str) -> Result<RecruitmentTemplate, String> {
+        let templates = self.list(messages, guild_id, user_id).await?;
+        pick(&templates, user_id, name)
+            .cloned()
+            .ok_or_else(|| messages.get_with_params("recruitment_template.not_found", &[("name", name)]))
+    }
+
+    /// テンプレートを保存する（同じ名前の自分のテンプレートは上書きする）
+    pub async fn save(&self, messages: &Messages, input: TemplateInput<'_>) -> Result<RecruitmentTemplate, String> {
+        let name = input.name.trim();
+        let notes = input.notes.map(str::trim).filter(|notes| !notes.is_empty());
+        let capacity = validate(messages, name, input.capacity, notes)?;
+        let quest_alias = input.quest_alias.trim();
+
+        match self.db.quest.get_by_alias(Some(input.guild_id), quest_alias).await {
+            Ok(Some(_)) => {},
+            Ok(None) => return Err(messages.get_with_params("battle_recruitment.quest_not_found", &[("alias", quest_alias)])),
+            Err(e) => {
+                error!("Error getting quest for recruitment template: {:?}", e);
+                return Err(messages.get("battle_recruitment.database_error"));
+            }
+        }
+
+        let templates = self.list(messages, input.guild_id, input.owner_id).await?;
+        let own: Vec<&RecruitmentTemplate> = templates.iter().filter(|t| t.owner_id == input.owner_id).collect();
+        let replaces = own.iter().any(|t| t.name == name);
+        if !replaces && own.len() >= MAX_TEMPLATES_PER_USER {
+            return Err(messages.get_with_params("recruitment_template.limit_reached", &[("max", &MAX_TEMPLATES_PER_USER.to_string())]));
+        }
+
+        let template = self.db.recruitment_template.save(NewRecruitmentTemplate {
+            guild_id: input.guild_id,
+            owner_id: input.owner_id,
+            name: name.to_string(),
+            quest_alias: quest_alias.to_string(),
+            battle_type_id: input.battle_type as i32,
+            capacity,
+            notes: notes.map(str::to_string),
+            channel_id: input.channel_id,
+        }).await
+            .map_err(|e| {
+                error!("Error saving recruitment template: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+
+        info!("Recruitment template saved: guild_id={}, id={}", template.guild_id, template.id);
+        Ok(template)
+    }
+
+    /// 自分のテンプレートを削除する。テンプレートが存在しない場合は false
+    pub async fn delete(&self, messages: &Messages, guild_id: i64, user_id: i64, name: &str) -> Result<bool, String> {
+        let templates = self.list(messages, guild_id, user_id).await?;
+        let Some(template) = pick(&templates, user_id, name).filter(|t| t.owner_id == user_id) else {
+            return Ok(false);
+        };
+
+        let deleted = self.db.recruitment_template.delete(guild_id, user_id, &template.name).await
+            .map_err(|e| {
+                error!("Error deleting recruitment template: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+
+        if deleted {
+            info!("Recruitment template deleted: guild_id={}, id={}", guild_id, template.id);
+        }
+        Ok(deleted)
+    }
+
+    /// 自分のテンプレートをサーバー全体に公開する
+    /// 同じ名前の公開テンプレートが既にある場合はエラー
+    pub async fn publish(&self, messages: &Messages, guild_id: i64, user_id: i64, name: &str) -> Result<RecruitmentTemplate, String> {
+        let templates = self.list(messages, guild_id, user_id).await?;
+        let template = pick(&templates, user_id, name)
+            .filter(|t| t.owner_id == user_id)
+            .ok_or_else(|| messages.get_with_params("recruitment_template.not_found", &[("name", name)]))?;
+
+        let conflict = templates.iter()
+            .any(|t| t.is_public && t.id != template.id && t.name == template.name);
+        if conflict {
+            return Err(messages.get_with_params("recruitment_template.name_conflict", &[("name", &template.name)]));
+        }
+
+        self.set_public(messages, template, true).await?;
+        Ok(template.clone())
+    }
+
+    /// 公開されたテンプレートを非公開に戻す（保存したメンバーのテンプレートとして残る）
+    pub async fn unpublish(&self, messages: &Messages, guild_id: i64, user_id: i64, name: &str) -> Result<RecruitmentTemplate, String> {
+        let templates = self.list(messages, guild_id, user_id).await?;
+        let public: Vec<RecruitmentTemplate> = templates.into_iter().filter(|t| t.is_public).collect();
+        let template = pick(&public, user_id, name)
+            .ok_or_else(|| messages.get_with_params("recruitment_template.not_found", &[("name", name)]))?;
+
+        self.set_public(messages, template, false).await?;
+        Ok(template.clone())
+    }
+
+    async fn set_public(&self, messages: &Messages, template: &RecruitmentTemplate, is_public: bool) -> Result<(), String> {
+        self.db.recruitment_template.set_public(template.id, is_public).await
+            .map_err(|e| {
+                error!("Error updating recruitment template: {:?}", e);
+                messages.get("battle_recruitment.database_error")
+            })?;
+
+        info!("Recruitment template {}: guild_id={}, id={}", if is_public { "published" } else { "unpublished" }, template.guild_id, template.id);
+        Ok(())
+    }
+}
+
+/// 名前が一致するテンプレート（ひらがな・カタカナ、全角・半角を区別しない）
+/// 自分のテンプレートを公開されたものより優先する
+fn pick<'a>(templates: &'a [RecruitmentTemplate], user_id: i64, name: &str) -> Option<&'a RecruitmentTemplate> {
+    let name = normalize_alias(name);
+    let mut matches = templates.iter().filter(|t| normalize_alias(&t.name) == name);
+    let first = matches.next()?;
+    if first.owner_id == user_id {
+        return Some(first);
+    }
+    matches.find(|t| t.owner_id == user_id).or(Some(first))
+}
+
+/// 入力値を確認し、募集人数を返す
+fn validate(messages: &Messages, name: &str, capacity: Option<i64>, notes: Option<&str>) -> Result<i32, String> {
+    let name_length = name.chars().count();
+    if name_length == 0 || name_length > MAX_NAME_LENGTH {
+        return Err(messages.get_with_params("recruitment_template.invalid_name", &[("max", &MAX_NAME_LENGTH.to_string())]));
+    }
+
+    let capacity = capacity.unwrap_or(DEFAULT_CAPACITY as i64);
+    if !(1..=MAX_CAPACITY as i64).contains(&capacity) {
+        return Err(messages.get_with_params("recruitment_template.invalid_capacity", &[("max", &MAX_CAPACITY.to_string())]));
+    }
+
+    if notes.is_some_and(|notes| notes.chars().count() > MAX_NOTES_LENGTH) {
+        return Err(messages.get_with_params("recruitment_template.notes_too_long", &[("max", &MAX_NOTES_LENGTH.to_string())]));
+    }
+
+    Ok(capacity as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(id: i32, owner_id: i64, name: &str, is_public: bool) -> RecruitmentTemplate {
+        RecruitmentTemplate {
+            id,
+            guild_id: 1,
+            owner_id,
+            name: name.to_string(),
+            quest_alias: "ルシHL".to_string(),
+            battle_type_id: 0,
+            capacity: 6,
+            notes: None,
+            channel_id: None,
+            is_public,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_pick_prefers_own_template() {
+        let templates = vec![
+            template(1, 2, "土曜ルシ", true),
+            template(2, 1, "土曜ルシ", false),
+            template(3, 2, "ベルゼ", true),
+        ];
+
+        assert_eq!(pick(&templates, 1, "土曜ルシ").unwrap().id, 2);
+        assert_eq!(pick(&templates, 3, "土曜ルシ").unwrap().id, 1);
+        // ひらがな・全角半角の違いは区別しない
+        assert_eq!(pick(&templates, 1, "べるぜ").unwrap().id, 3);
+        assert!(pick(&templates, 1, "アルバハ").is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        let messages = Messages::bundled(Some("ja"));
+
+        assert_eq!(validate(&messages, "土曜ルシ", None, None).unwrap(), 6);
+        assert_eq!(validate(&messages, "土曜ルシ", Some(30), Some("フルオート可")).unwrap(), 30);
+        assert!(validate(&messages, "", None, None).is_err());
+        assert!(validate(&messages, &"あ".repeat(MAX_NAME_LENGTH + 1), None, None).is_err());
+        assert!(validate(&messages, "土曜ルシ", Some(0), None).is_err());
+        assert!(validate(&messages, "土曜ルシ", Some(MAX_CAPACITY as i64 + 1), None).is_err());
+        assert!(validate(&messages, "土曜ルシ", None, Some(&"あ".repeat(MAX_NOTES_LENGTH + 1))).is_err());
+    }
+}
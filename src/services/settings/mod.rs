@@ -0,0 +1,4 @@
+pub mod setting_key;
+pub mod settings_service;
+pub use setting_key::SettingKey;
+pub use settings_service::SettingsService;
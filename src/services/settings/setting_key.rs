@@ -0,0 +1,253 @@
+use chrono::{FixedOffset, NaiveTime};
+use crate::models::guild_settings::{GuildSettings, DEFAULT_LOCALE, DEFAULT_START_TIME, DEFAULT_TIMEZONE};
+use crate::utils::constants::ROLL_GBF_BOT_CONTROLS;
+
+/// 対応している言語
+pub const SUPPORTED_LOCALES: [&str; 2] = ["ja", "en"];
+
+/// リマインドできる最大の時間（分）
+const MAX_REMINDER_OFFSET_MINUTES: i64 = 7 * 24 * 60;
+
+/// サーバー設定の項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum SettingKey {
+    #[name = "recruitment_channel"]
+    #[name_localized("ja", "募集チャンネル")]
+    RecruitmentChannel,
+    #[name = "default_start_time"]
+    #[name_localized("ja", "既定の開始時刻")]
+    DefaultStartTime,
+    #[name = "admin_role"]
+    #[name_localized("ja", "管理ロール名")]
+    AdminRoleName,
+    #[name = "locale"]
+    #[name_localized("ja", "言語")]
+    Locale,
+    #[name = "timezone"]
+    #[name_localized("ja", "タイムゾーン")]
+    Timezone,
+    #[name = "reminder_offsets"]
+    #[name_localized("ja", "リマインド")]
+    ReminderOffsets,
+    #[name = "recruitment_threads"]
+    #[name_localized("ja", "募集スレッド")]
+    RecruitmentThreads,
+    #[name = "scheduled_events"]
+    #[name_localized("ja", "イベント登録")]
+    ScheduledEvents,
+}
+
+impl SettingKey {
+    pub const ALL: [SettingKey; 8] = [
+        SettingKey::RecruitmentChannel,
+        SettingKey::DefaultStartTime,
+        SettingKey::AdminRoleName,
+        SettingKey::Locale,
+        SettingKey::Timezone,
+        SettingKey::ReminderOffsets,
+        SettingKey::RecruitmentThreads,
+        SettingKey::ScheduledEvents,
+    ];
+
+    /// 表示用の項目名
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingKey::RecruitmentChannel => "募集チャンネル",
+            SettingKey::DefaultStartTime => "既定の開始時刻",
+            SettingKey::AdminRoleName => "管理ロール名",
+            SettingKey::Locale => "言語",
+            SettingKey::Timezone => "タイムゾーン",
+            SettingKey::ReminderOffsets => "リマインド（開始前の分）",
+            SettingKey::RecruitmentThreads => "募集スレッド",
+            SettingKey::ScheduledEvents => "Discordのイベントに登録",
+        }
+    }
+
+    /// 未設定の場合の説明
+    pub fn default_description(&self) -> String {
+        match self {
+            SettingKey::RecruitmentChannel => "コマンドを実行したチャンネル".to_string(),
+            SettingKey::DefaultStartTime => DEFAULT_START_TIME.to_string(),
+            SettingKey::AdminRoleName => ROLL_GBF_BOT_CONTROLS.to_string(),
+            SettingKey::Locale => DEFAULT_LOCALE.to_string(),
+            SettingKey::Timezone => DEFAULT_TIMEZONE.to_string(),
+            SettingKey::ReminderOffsets => "なし".to_string(),
+            SettingKey::RecruitmentThreads => "作成しない".to_string(),
+            SettingKey::ScheduledEvents => "登録しない".to_string(),
+        }
+    }
+
+    /// 設定値を表示用の文字列にする（未設定の場合は None）
+    pub fn display_value(&self, settings: &GuildSettings) -> Option<String> {
+        match self {
+            SettingKey::RecruitmentChannel => settings.recruitment_channel_id.map(|id| format!("<#{}>", id)),
+            SettingKey::DefaultStartTime => settings.default_start_time.clone(),
+            SettingKey::AdminRoleName => settings.admin_role_name.clone(),
+            SettingKey::Locale => settings.locale.clone(),
+            SettingKey::Timezone => settings.timezone.clone(),
+            SettingKey::ReminderOffsets => settings.reminder_offsets.clone(),
+            SettingKey::RecruitmentThreads => settings.recruitment_threads.map(switch_value),
+            SettingKey::ScheduledEvents => settings.scheduled_events.map(switch_value),
+        }
+    }
+
+    /// 入力値を検証して設定に反映する
+    pub fn apply(&self, settings: &mut GuildSettings, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("値を入力してください。".to_string());
+        }
+
+        match self {
+            SettingKey::RecruitmentChannel => settings.recruitment_channel_id = Some(parse_channel_id(value)?),
+            SettingKey::DefaultStartTime => settings.default_start_time = Some(parse_start_time(value)?),
+            SettingKey::AdminRoleName => settings.admin_role_name = Some(value.trim_start_matches('@').to_string()),
+            SettingKey::Locale => settings.locale = Some(parse_locale(value)?),
+            SettingKey::Timezone => settings.timezone = Some(parse_timezone(value)?),
+            SettingKey::ReminderOffsets => settings.reminder_offsets = Some(parse_reminder_offsets(value)?),
+            SettingKey::RecruitmentThreads => settings.recruitment_threads = Some(parse_switch(value)?),
+            SettingKey::ScheduledEvents => settings.scheduled_events = Some(parse_switch(value)?),
+        }
+        Ok(())
+    }
+
+    /// 設定を未設定（既定値）に戻す
+    pub fn clear(&self, settings: &mut GuildSettings) {
+        match self {
+            SettingKey::RecruitmentChannel => settings.recruitment_channel_id = None,
+            SettingKey::DefaultStartTime => settings.default_start_time = None,
+            SettingKey::AdminRoleName => settings.admin_role_name = None,
+            SettingKey::Locale => settings.locale = None,
+            SettingKey::Timezone => settings.timezone = None,
+            SettingKey::ReminderOffsets => settings.reminder_offsets = None,
+            SettingKey::RecruitmentThreads => settings.recruitment_threads = None,
+            SettingKey::ScheduledEvents => settings.scheduled_events = None,
+        }
+    }
+}
+
+/// "<#123>" 形式のメンションまたはIDを受け付ける
+fn parse_channel_id(value: &str) -> Result<i64, String> {
+    value.trim_start_matches("<#").trim_end_matches('>')
+        .parse::<i64>()
+        .ok()
+        .filter(|id| *id > 0)
+        .ok_or_else(|| format!("チャンネルはメンション（#チャンネル）またはIDで指定してください: {}", value))
+}
+
+fn parse_start_time(value: &str) -> Result<String, String> {
+    NaiveTime::parse_from_str(value, "%H:%M")
+        .map(|time| time.format("%H:%M").to_string())
+        .map_err(|_| format!("開始時刻は HH:MM 形式で指定してください: {}", value))
+}
+
+fn parse_locale(value: &str) -> Result<String, String> {
+    let locale = value.to_lowercase();
+    if SUPPORTED_LOCALES.contains(&locale.as_str()) {
+        Ok(locale)
+    } else {
+        Err(format!("言語は {} のいずれかで指定してください: {}", SUPPORTED_LOCALES.join(", "), value))
+    }
+}
+
+/// "+09:00" 形式の時差のほか、UTC と JST を受け付ける
+fn parse_timezone(value: &str) -> Result<String, String> {
+    let offset = match value.to_uppercase().as_str() {
+        "UTC" | "GMT" | "Z" => Ok(FixedOffset::east_opt(0).expect("zero offset is valid")),
+        "JST" => Ok(FixedOffset::east_opt(9 * 3600).expect("JST offset is valid")),
+        _ => value.parse::<FixedOffset>(),
+    };
+    offset
+        .map(|offset| offset.to_string())
+        .map_err(|_| format!("タイムゾーンは +09:00 のような時差で指定してください: {}", value))
+}
+
+/// "60, 15" のようなカンマ区切りの分を、重複を除いて長い順に並べる
+fn parse_reminder_offsets(value: &str) -> Result<String, String> {
+    let mut offsets = value.split(',')
+        .map(|minutes| minutes.trim())
+        .filter(|minutes| !minutes.is_empty())
+        .map(|minutes| minutes.parse::<i64>()
+            .ok()
+            .filter(|minutes| (1..=MAX_REMINDER_OFFSET_MINUTES).contains(minutes))
+            .ok_or_else(|| format!("リマインドは1〜{}の分をカンマ区切りで指定してください: {}", MAX_REMINDER_OFFSET_MINUTES, minutes)))
+        .collect::<Result<Vec<i64>, String>>()?;
+
+    offsets.sort_unstable_by(|a, b| b.cmp(a));
+    offsets.dedup();
+    Ok(offsets.iter().map(|minutes| minutes.to_string()).collect::<Vec<_>>().join(","))
+}
+
+/// on/off の設定の表示
+fn switch_value(enabled: bool) -> String {
+    if enabled { "on" } else { "off" }.to_string()
+}
+
+/// on/off のほか、true/false と する/しない を受け付ける
+fn parse_switch(value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "on" | "true" | "yes" | "する" => Ok(true),
+        "off" | "false" | "no" | "しない" => Ok(false),
+        _ => Err(format!("on または off で指定してください: {}", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(key: SettingKey, value: &str) -> Result<GuildSettings, String> {
+        let mut settings = GuildSettings::empty(1);
+        key.apply(&mut settings, value).map(|_| settings)
+    }
+
+    #[test]
+    fn test_recruitment_channel() {
+        let settings = apply(SettingKey::RecruitmentChannel, "<#123456789>").unwrap();
+        assert_eq!(settings.recruitment_channel_id, Some(123456789));
+        assert_eq!(apply(SettingKey::RecruitmentChannel, "123").unwrap().recruitment_channel_id, Some(123));
+        assert!(apply(SettingKey::RecruitmentChannel, "raids").is_err());
+    }
+
+    #[test]
+    fn test_default_start_time() {
+        assert_eq!(apply(SettingKey::DefaultStartTime, "9:05").unwrap().default_start_time.as_deref(), Some("09:05"));
+        assert!(apply(SettingKey::DefaultStartTime, "25:00").is_err());
+    }
+
+    #[test]
+    fn test_locale_and_timezone() {
+        assert_eq!(apply(SettingKey::Locale, "EN").unwrap().locale.as_deref(), Some("en"));
+        assert!(apply(SettingKey::Locale, "fr").is_err());
+
+        assert_eq!(apply(SettingKey::Timezone, "JST").unwrap().timezone.as_deref(), Some("+09:00"));
+        assert_eq!(apply(SettingKey::Timezone, "-05:00").unwrap().timezone.as_deref(), Some("-05:00"));
+        assert!(apply(SettingKey::Timezone, "Asia/Tokyo").is_err());
+    }
+
+    #[test]
+    fn test_reminder_offsets() {
+        let settings = apply(SettingKey::ReminderOffsets, "15, 60,15").unwrap();
+        assert_eq!(settings.reminder_offsets.as_deref(), Some("60,15"));
+        assert!(apply(SettingKey::ReminderOffsets, "0").is_err());
+        assert!(apply(SettingKey::ReminderOffsets, "abc").is_err());
+    }
+
+    #[test]
+    fn test_switches() {
+        assert_eq!(apply(SettingKey::RecruitmentThreads, "ON").unwrap().recruitment_threads, Some(true));
+        assert_eq!(apply(SettingKey::RecruitmentThreads, "しない").unwrap().recruitment_threads, Some(false));
+        assert!(apply(SettingKey::RecruitmentThreads, "maybe").is_err());
+        assert_eq!(apply(SettingKey::ScheduledEvents, "on").unwrap().scheduled_events, Some(true));
+    }
+
+    #[test]
+    fn test_empty_value_and_clear() {
+        assert!(apply(SettingKey::AdminRoleName, "  ").is_err());
+
+        let mut settings = apply(SettingKey::AdminRoleName, "@raid_admin").unwrap();
+        assert_eq!(settings.admin_role_name(), "raid_admin");
+        SettingKey::AdminRoleName.clear(&mut settings);
+        assert_eq!(settings.admin_role_name(), ROLL_GBF_BOT_CONTROLS);
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use chrono::FixedOffset;
+use tracing::{error, info};
+
+use crate::repository::Database;
+use crate::models::guild_settings::GuildSettings;
+use super::setting_key::SettingKey;
+
+/// サーバーごとの設定を管理するサービス
+pub struct SettingsService {
+    db: Arc<Database>,
+}
+
+impl SettingsService {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// サーバーの設定を取得する（未登録の場合はすべて既定値）
+    pub async fn get(&self, guild_id: i64) -> Result<GuildSettings, String> {
+        match self.db.guild_settings.get_by_guild(guild_id).await {
+            Ok(settings) => Ok(settings.unwrap_or_else(|| GuildSettings::empty(guild_id))),
+            Err(e) => {
+                error!("Error getting guild settings: {:?}", e);
+                Err(format!("Database error: {}", e))
+            }
+        }
+    }
+
+    /// サーバーのタイムゾーン（取得できない場合は既定のタイムゾーン）
+    pub async fn timezone(&self, guild_id: i64) -> FixedOffset {
+        self.get(guild_id).await
+            .unwrap_or_else(|_| GuildSettings::empty(guild_id))
+            .timezone()
+    }
+
+    /// 設定項目を変更する
+    pub async fn set(&self, guild_id: i64, key: SettingKey, value: &str) -> Result<GuildSettings, String> {
+        let mut settings = self.get(guild_id).await?;
+        key.apply(&mut settings, value)?;
+
+        let settings = self.save(&settings).await?;
+        info!("Guild setting updated: guild_id={}, key={:?}", guild_id, key);
+        Ok(settings)
+    }
+
+    /// 設定項目を既定値に戻す（項目未指定時はすべて）
+    pub async fn reset(&self, guild_id: i64, key: Option<SettingKey>) -> Result<GuildSettings, String> {
+        let Some(key) = key else {
+            self.db.guild_settings.delete(guild_id).await
+                .map_err(|e| {
+                    error!("Error deleting guild settings: {:?}", e);
+                    format!("Database error: {}", e)
+                })?;
+            info!("Guild settings reset: guild_id={}", guild_id);
+            return Ok(GuildSettings::empty(guild_id));
+        };
+
+        let mut settings = self.get(guild_id).await?;
+        key.clear(&mut settings);
+
+        let settings = self.save(&settings).await?;
+        info!("Guild setting reset: guild_id={}, key={:?}", guild_id, key);
+        Ok(settings)
+    }
+
+    async fn save(&self, settings: &GuildSettings) -> Result<GuildSettings, String> {
+        self.db.guild_settings.save(settings).await
+            .map_err(|e| {
+                error!("Error saving guild settings: {:?}", e);
+                format!("Database error: {}", e)
+            })
+    }
+}
@@ -1,15 +1,31 @@
 use poise::serenity_prelude::all::ReactionType;
 
 // Battle types enum
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
 pub enum BattleType {
+    #[name = "Default"]
+    #[name_localized("ja", "デフォルト")]
     Default = 0,
+    #[name = "All elements"]
+    #[name_localized("ja", "全属性")]
     AllElement = 1,
+    #[name = "Fire"]
+    #[name_localized("ja", "火属性")]
     Fire = 2,
+    #[name = "Water"]
+    #[name_localized("ja", "水属性")]
     Water = 3,
+    #[name = "Earth"]
+    #[name_localized("ja", "土属性")]
     Earth = 4,
+    #[name = "Wind"]
+    #[name_localized("ja", "風属性")]
     Wind = 5,
+    #[name = "Light"]
+    #[name_localized("ja", "光属性")]
     Light = 6,
+    #[name = "Dark"]
+    #[name_localized("ja", "闇属性")]
     Dark = 7,
 }
 
@@ -41,6 +57,17 @@ impl BattleType {
         }
     }
     
+    /// リアクションの絵文字に対応する属性（異体字セレクタの有無は区別しない）
+    pub fn from_reaction(reaction: &ReactionType) -> Option<Self> {
+        let ReactionType::Unicode(emoji) = reaction else {
+            return None;
+        };
+        let emoji = emoji.trim_end_matches('\u{fe0f}');
+        [Self::Fire, Self::Water, Self::Earth, Self::Wind, Self::Light, Self::Dark]
+            .into_iter()
+            .find(|element| element.reactions().iter().any(|r| matches!(r, ReactionType::Unicode(e) if e.trim_end_matches('\u{fe0f}') == emoji)))
+    }
+
     pub fn reactions(&self) -> Vec<ReactionType> {
         match self {
             Self::Default | Self::AllElement => vec![
@@ -137,6 +164,15 @@ mod tests {
         assert!(dark_reactions[0].to_string().contains("🌑"));
     }
 
+    #[test]
+    fn test_battle_type_from_reaction() {
+        for element in [BattleType::Fire, BattleType::Water, BattleType::Earth, BattleType::Wind, BattleType::Light, BattleType::Dark] {
+            assert_eq!(BattleType::from_reaction(&element.reactions()[0]), Some(element));
+        }
+        assert_eq!(BattleType::from_reaction(&ReactionType::Unicode("🌪".to_string())), Some(BattleType::Wind));
+        assert_eq!(BattleType::from_reaction(&ReactionType::Unicode("👍".to_string())), None);
+    }
+
     #[test]
     fn test_battle_type_equality() {
         assert_eq!(BattleType::Default, BattleType::Default);
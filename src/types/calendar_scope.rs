@@ -0,0 +1,39 @@
+/// カレンダーに含める募集の範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum CalendarScope {
+    #[name = "guild"]
+    #[name_localized("ja", "サーバー全体")]
+    Guild,
+    #[name = "joined"]
+    #[name_localized("ja", "参加した募集")]
+    Joined,
+}
+
+impl CalendarScope {
+    pub const ALL: [CalendarScope; 2] = [CalendarScope::Guild, CalendarScope::Joined];
+
+    /// データベースに保存する値
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Guild => "guild",
+            Self::Joined => "joined",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|scope| scope.key() == key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_round_trip() {
+        for scope in CalendarScope::ALL {
+            assert_eq!(CalendarScope::from_key(scope.key()), Some(scope));
+        }
+        assert_eq!(CalendarScope::from_key("unknown"), None);
+    }
+}
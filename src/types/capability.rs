@@ -0,0 +1,49 @@
+/// サーバーごとにロールやユーザーへ付与できる権限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, poise::ChoiceParameter)]
+pub enum Capability {
+    #[name = "manage_quests"]
+    #[name_localized("ja", "クエスト管理")]
+    ManageQuests,
+    #[name = "cancel_others_recruitments"]
+    #[name_localized("ja", "他人の募集のキャンセル")]
+    CancelOthersRecruitments,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 2] = [
+        Capability::ManageQuests,
+        Capability::CancelOthersRecruitments,
+    ];
+
+    /// データベースに保存する値
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::ManageQuests => "manage_quests",
+            Self::CancelOthersRecruitments => "cancel_others_recruitments",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|capability| capability.key() == key)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ManageQuests => "クエスト管理",
+            Self::CancelOthersRecruitments => "他人の募集のキャンセル",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_round_trip() {
+        for capability in Capability::ALL {
+            assert_eq!(Capability::from_key(capability.key()), Some(capability));
+        }
+        assert_eq!(Capability::from_key("unknown"), None);
+    }
+}
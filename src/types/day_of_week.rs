@@ -0,0 +1,54 @@
+use chrono::Weekday;
+
+/// 定期募集の曜日
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum DayOfWeek {
+    #[name = "Monday"]
+    #[name_localized("ja", "月曜日")]
+    Monday,
+    #[name = "Tuesday"]
+    #[name_localized("ja", "火曜日")]
+    Tuesday,
+    #[name = "Wednesday"]
+    #[name_localized("ja", "水曜日")]
+    Wednesday,
+    #[name = "Thursday"]
+    #[name_localized("ja", "木曜日")]
+    Thursday,
+    #[name = "Friday"]
+    #[name_localized("ja", "金曜日")]
+    Friday,
+    #[name = "Saturday"]
+    #[name_localized("ja", "土曜日")]
+    Saturday,
+    #[name = "Sunday"]
+    #[name_localized("ja", "日曜日")]
+    Sunday,
+}
+
+impl DayOfWeek {
+    pub fn weekday(&self) -> Weekday {
+        match self {
+            Self::Monday => Weekday::Mon,
+            Self::Tuesday => Weekday::Tue,
+            Self::Wednesday => Weekday::Wed,
+            Self::Thursday => Weekday::Thu,
+            Self::Friday => Weekday::Fri,
+            Self::Saturday => Weekday::Sat,
+            Self::Sunday => Weekday::Sun,
+        }
+    }
+
+    /// 表示用の曜日名
+    pub fn label(weekday: Weekday) -> &'static str {
+        match weekday {
+            Weekday::Mon => "月曜日",
+            Weekday::Tue => "火曜日",
+            Weekday::Wed => "水曜日",
+            Weekday::Thu => "木曜日",
+            Weekday::Fri => "金曜日",
+            Weekday::Sat => "土曜日",
+            Weekday::Sun => "日曜日",
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::utils::text_normalizer::normalize_alias;
+
+/// 古戦場（Unite and Fight）の日程の区切り
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GuildWarRound {
+    Preliminaries,
+    Interlude,
+    Finals1,
+    Finals2,
+    Finals3,
+    Finals4,
+}
+
+impl GuildWarRound {
+    pub const ALL: [GuildWarRound; 6] = [
+        GuildWarRound::Preliminaries,
+        GuildWarRound::Interlude,
+        GuildWarRound::Finals1,
+        GuildWarRound::Finals2,
+        GuildWarRound::Finals3,
+        GuildWarRound::Finals4,
+    ];
+
+    /// データベースに保存する値（メッセージのキーにも使う）
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Preliminaries => "preliminaries",
+            Self::Interlude => "interlude",
+            Self::Finals1 => "finals_1",
+            Self::Finals2 => "finals_2",
+            Self::Finals3 => "finals_3",
+            Self::Finals4 => "finals_4",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|round| round.key() == key)
+    }
+
+    /// 日時の入力や取り込むファイルで使える呼び方（日本語・英語）
+    fn names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Preliminaries => &["予選", "preliminaries", "prelims"],
+            Self::Interlude => &["インターバル", "interlude"],
+            Self::Finals1 => &["本戦1日目", "finals day 1", "finals_1"],
+            Self::Finals2 => &["本戦2日目", "finals day 2", "finals_2"],
+            Self::Finals3 => &["本戦3日目", "finals day 3", "finals_3"],
+            Self::Finals4 => &["本戦4日目", "finals day 4", "finals_4"],
+        }
+    }
+
+    /// 呼び方から判定する（全角・半角、大文字・小文字、空白を区別しない）
+    pub fn from_name(name: &str) -> Option<Self> {
+        match Self::split_prefix(name) {
+            Some((round, rest)) if rest.is_empty() => Some(round),
+            _ => None,
+        }
+    }
+
+    /// 先頭の呼び方と、正規化した残りの文字列に分ける（「本戦1日目 22時」→ Finals1 と "22時"）
+    pub fn split_prefix(input: &str) -> Option<(Self, String)> {
+        let input = normalize_alias(input);
+        Self::ALL.into_iter()
+            .flat_map(|round| round.names().iter().map(move |name| (round, normalize_alias(name))))
+            .filter(|(_, name)| input.starts_with(name.as_str()))
+            .max_by_key(|(_, name)| name.len())
+            .map(|(round, name)| (round, input[name.len()..].to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_round_trip() {
+        for round in GuildWarRound::ALL {
+            assert_eq!(GuildWarRound::from_key(round.key()), Some(round));
+        }
+        assert_eq!(GuildWarRound::from_key("unknown"), None);
+    }
+
+    #[test]
+    fn test_split_prefix() {
+        assert_eq!(GuildWarRound::split_prefix("本戦1日目 22時"), Some((GuildWarRound::Finals1, "22時".to_string())));
+        assert_eq!(GuildWarRound::split_prefix("本戦４日目　２１：３０"), Some((GuildWarRound::Finals4, "21:30".to_string())));
+        assert_eq!(GuildWarRound::split_prefix("予選"), Some((GuildWarRound::Preliminaries, String::new())));
+        assert_eq!(GuildWarRound::split_prefix("Finals Day 2 7:00"), Some((GuildWarRound::Finals2, "7:00".to_string())));
+        assert_eq!(GuildWarRound::split_prefix("明日 22時"), None);
+        assert_eq!(GuildWarRound::from_name("interlude"), Some(GuildWarRound::Interlude));
+        assert_eq!(GuildWarRound::from_name("本戦1日目 22時"), None);
+    }
+}
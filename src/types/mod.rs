@@ -1,5 +1,15 @@
 pub mod battle_type;
+pub mod calendar_scope;
+pub mod capability;
+pub mod day_of_week;
+pub mod guild_war_round;
+pub mod recruitment_status;
 pub use battle_type::BattleType;
+pub use calendar_scope::CalendarScope;
+pub use capability::Capability;
+pub use day_of_week::DayOfWeek;
+pub use guild_war_round::GuildWarRound;
+pub use recruitment_status::RecruitmentStatus;
 use std::sync::Arc;
 use crate::utils::database::DatabaseService;
 
@@ -0,0 +1,28 @@
+/// 募集の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum RecruitmentStatus {
+    #[name = "Open"]
+    #[name_localized("ja", "募集中")]
+    Open,
+    #[name = "Full"]
+    #[name_localized("ja", "満員")]
+    Full,
+    #[name = "Started"]
+    #[name_localized("ja", "開始済み")]
+    Started,
+    #[name = "Cancelled"]
+    #[name_localized("ja", "キャンセル済み")]
+    Cancelled,
+}
+
+impl RecruitmentStatus {
+    /// 表示用のメッセージのキー
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            Self::Open => "recruitment_list.status_open",
+            Self::Full => "recruitment_list.status_full",
+            Self::Started => "recruitment_list.status_started",
+            Self::Cancelled => "recruitment_list.status_cancelled",
+        }
+    }
+}
@@ -1,6 +1,21 @@
-use chrono::{DateTime, Local, Datelike, Timelike, Duration};
+use chrono::{DateTime, Local, Datelike, NaiveTime, TimeZone, Timelike, Duration};
 use dateparser;
+use regex::Regex;
 use tracing::error;
+use crate::types::GuildWarRound;
+
+lazy_static::lazy_static! {
+    /// 古戦場の区切りに続く時刻（「22時」「22時30分」「22時半」「22:30」）
+    static ref TIME_OF_DAY_PATTERN: Regex = Regex::new(r"^(\d{1,2})(?::(\d{2})|時(?:(\d{1,2})分|(半))?)$").unwrap();
+}
+
+/// 古戦場の日程を使った日時（「本戦1日目 22時」「予選 19:30」「インターバル」など）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuildWarDate {
+    pub round: GuildWarRound,
+    /// 省略時は区切りの開始日時
+    pub time: Option<NaiveTime>,
+}
 
 /// Parses a date string using the dateparser crate
 /// 
@@ -8,16 +23,18 @@ use tracing::error;
 /// - Use dateparser crate for parsing
 /// - For empty input, treat it as "今日21:00" (today 21:00) and continue parsing
 /// - Return error if parsing fails
-pub async fn parse_event_date(date_str: &str) -> Result<DateTime<Local>, String> {
+///
+/// Dates without an explicit offset are interpreted in `timezone` (the guild's timezone)
+pub async fn parse_event_date<Tz: TimeZone>(date_str: &str, timezone: &Tz) -> Result<DateTime<Tz>, String> {
     let trimmed_input = date_str.trim();
     if trimmed_input.is_empty() {
-        return Ok(default_expiry_date().await);
+        return Ok(today_at_default_time(timezone));
     }
     
     // Use dateparser crate to parse the input
-    match dateparser::parse(trimmed_input) {
+    match dateparser::parse_with_timezone(trimmed_input, timezone) {
         Ok(parsed_datetime) => {
-            Ok(parsed_datetime.with_timezone(&Local))
+            Ok(parsed_datetime.with_timezone(timezone))
         }
         Err(e) => {
             error!("Failed to parse date string '{}': {}", trimmed_input, e);
@@ -26,16 +43,46 @@ pub async fn parse_event_date(date_str: &str) -> Result<DateTime<Local>, String>
     }
 }
 
+/// Parses a date relative to the Guild War schedule, e.g. "本戦1日目 22時"
+///
+/// Returns None when the input does not start with a Guild War round, so that it can be
+/// parsed with `parse_event_date` instead. The date itself comes from the guild's schedule.
+pub fn parse_guild_war_date(date_str: &str) -> Option<Result<GuildWarDate, String>> {
+    let (round, rest) = GuildWarRound::split_prefix(date_str)?;
+    if rest.is_empty() {
+        return Some(Ok(GuildWarDate { round, time: None }));
+    }
+
+    let result = parse_time_of_day(&rest)
+        .map(|time| GuildWarDate { round, time: Some(time) })
+        .ok_or_else(|| format!("Failed to parse time '{}' in '{}'", rest, date_str.trim()));
+    Some(result)
+}
+
+/// 区切りに続く時刻（全角・半角を揃えた文字列）
+fn parse_time_of_day(text: &str) -> Option<NaiveTime> {
+    let captures = TIME_OF_DAY_PATTERN.captures(text)?;
+    let hour = captures[1].parse().ok()?;
+    let minute = match (captures.get(2).or(captures.get(3)), captures.get(4)) {
+        (Some(minute), _) => minute.as_str().parse().ok()?,
+        (None, Some(_)) => 30,
+        (None, None) => 0,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
 /// Returns the default expiry date (today at 21:00)
 pub async fn default_expiry_date() -> chrono::DateTime<Local> {
-    let now = Local::now();
+    today_at_default_time(&Local)
+}
+
+/// Today at 21:00 in `timezone`
+fn today_at_default_time<Tz: TimeZone>(timezone: &Tz) -> DateTime<Tz> {
+    let now = chrono::Utc::now().with_timezone(timezone);
     now.with_hour(21)
-        .unwrap_or(now)
-        .with_minute(0)
-        .unwrap_or(now)
-        .with_second(0)
-        .unwrap_or(now)
-        .with_nanosecond(0)
+        .and_then(|date| date.with_minute(0))
+        .and_then(|date| date.with_second(0))
+        .and_then(|date| date.with_nanosecond(0))
         .unwrap_or(now)
 }
 
@@ -62,7 +109,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_default_case() {
         // Test the "今日 21:00" case
-        let result = parse_event_date("今日 21:00").await.unwrap();
+        let result = parse_event_date("今日 21:00", &Local).await.unwrap();
         let default = default_expiry_date().await;
         
         assert_eq!(result.year(), default.year());
@@ -75,7 +122,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_valid_format() {
         // Test a valid date format "MM/DD HH:MM"
-        let result = parse_event_date("12/25 15:30").await.unwrap();
+        let result = parse_event_date("12/25 15:30", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -85,10 +132,20 @@ mod tests {
         assert_eq!(result.minute(), 30);
     }
 
+    #[tokio::test]
+    async fn test_parse_event_date_guild_timezone() {
+        // Dates without an offset are interpreted in the guild's timezone
+        let jst = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        let result = parse_event_date("2026-12-25 21:00", &jst).await.unwrap();
+
+        assert_eq!(result, jst.with_ymd_and_hms(2026, 12, 25, 21, 0, 0).unwrap());
+        assert_eq!(result.with_timezone(&chrono::Utc).hour(), 12);
+    }
+
     #[tokio::test]
     async fn test_parse_event_date_invalid_date() {
         // Test an invalid date (e.g., February 30)
-        let result = parse_event_date("2/30 12:00").await.unwrap();
+        let result = parse_event_date("2/30 12:00", &Local).await.unwrap();
         let default = default_expiry_date().await;
         
         // Should return the default date
@@ -102,7 +159,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_invalid_time() {
         // Test an invalid time (e.g., 25:70)
-        let result = parse_event_date("5/5 25:70").await.unwrap();
+        let result = parse_event_date("5/5 25:70", &Local).await.unwrap();
         let default = default_expiry_date().await;
         
         // Should return the default date
@@ -116,7 +173,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_invalid_format() {
         // Test completely invalid format - should return error
-        let result = parse_event_date("invalid date format").await;
+        let result = parse_event_date("invalid date format", &Local).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Unable to parse date string"));
     }
@@ -124,7 +181,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_empty_string() {
         // Test empty string - should return default value
-        let result = parse_event_date("").await.unwrap();
+        let result = parse_event_date("", &Local).await.unwrap();
         let default = default_expiry_date().await;
         
         assert_eq!(result.year(), default.year());
@@ -137,7 +194,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_whitespace_only() {
         // Test whitespace-only input - should return default value
-        let result = parse_event_date("   \t\n  ").await.unwrap();
+        let result = parse_event_date("   \t\n  ", &Local).await.unwrap();
         let default = default_expiry_date().await;
         
         assert_eq!(result.year(), default.year());
@@ -150,7 +207,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_unparseable_content() {
         // Test unparseable content with actual text - should return error
-        let result = parse_event_date("random text 123").await;
+        let result = parse_event_date("random text 123", &Local).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Unable to parse date string"));
     }
@@ -158,7 +215,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_only_slash_format() {
         // Test date-only format "MM/DD"
-        let result = parse_event_date("12/25").await.unwrap();
+        let result = parse_event_date("12/25", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -171,7 +228,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_event_date_only_japanese_format() {
         // Test date-only Japanese format "MM月DD日"
-        let result = parse_event_date("12月25日").await.unwrap();
+        let result = parse_event_date("12月25日", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -185,7 +242,7 @@ mod tests {
     async fn test_parse_time_only_colon_format() {
         // Test time-only format "HH:MM"
         let now = Local::now();
-        let result = parse_event_date("15:30").await.unwrap();
+        let result = parse_event_date("15:30", &Local).await.unwrap();
         
         // Should be today at 15:30 if it hasn't passed, or tomorrow if it has
         if now.hour() < 15 || (now.hour() == 15 && now.minute() < 30) {
@@ -204,7 +261,7 @@ mod tests {
     async fn test_parse_time_only_japanese_format() {
         // Test time-only Japanese format "HH時MM分"
         let now = Local::now();
-        let result = parse_event_date("15時30分").await.unwrap();
+        let result = parse_event_date("15時30分", &Local).await.unwrap();
         
         // Should be today at 15:30 if it hasn't passed, or tomorrow if it has
         if now.hour() < 15 || (now.hour() == 15 && now.minute() < 30) {
@@ -223,7 +280,7 @@ mod tests {
     async fn test_parse_time_only_half_hour_format() {
         // Test time-only Japanese format "HH時半"
         let now = Local::now();
-        let result = parse_event_date("15時半").await.unwrap();
+        let result = parse_event_date("15時半", &Local).await.unwrap();
         
         // Should be today at 15:30 if it hasn't passed, or tomorrow if it has
         if now.hour() < 15 || (now.hour() == 15 && now.minute() < 30) {
@@ -241,7 +298,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_relative_date_today() {
         // Test relative date "今日"
-        let result = parse_event_date("今日").await.unwrap();
+        let result = parse_event_date("今日", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -254,7 +311,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_relative_date_tomorrow() {
         // Test relative date "明日"
-        let result = parse_event_date("明日").await.unwrap();
+        let result = parse_event_date("明日", &Local).await.unwrap();
         let tomorrow = Local::now() + Duration::days(1);
         
         assert_eq!(result.year(), tomorrow.year());
@@ -267,7 +324,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_relative_date_days_after() {
         // Test relative date "3日後"
-        let result = parse_event_date("3日後").await.unwrap();
+        let result = parse_event_date("3日後", &Local).await.unwrap();
         let target_date = Local::now() + Duration::days(3);
         
         assert_eq!(result.year(), target_date.year());
@@ -280,7 +337,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_relative_date_with_time_today() {
         // Test relative date with time "今日 15:30"
-        let result = parse_event_date("今日 15:30").await.unwrap();
+        let result = parse_event_date("今日 15:30", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -293,7 +350,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_relative_date_with_time_tomorrow() {
         // Test relative date with time "明日22時30分"
-        let result = parse_event_date("明日22時30分").await.unwrap();
+        let result = parse_event_date("明日22時30分", &Local).await.unwrap();
         let tomorrow = Local::now() + Duration::days(1);
         
         assert_eq!(result.year(), tomorrow.year());
@@ -306,7 +363,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_relative_date_with_time_days_after() {
         // Test relative date with time "2日後 15時半"
-        let result = parse_event_date("2日後 15時半").await.unwrap();
+        let result = parse_event_date("2日後 15時半", &Local).await.unwrap();
         let target_date = Local::now() + Duration::days(2);
         
         assert_eq!(result.year(), target_date.year());
@@ -319,7 +376,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_date_time_japanese_format() {
         // Test Japanese date and time format "12月25日 15時30分"
-        let result = parse_event_date("12月25日 15時30分").await.unwrap();
+        let result = parse_event_date("12月25日 15時30分", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -332,7 +389,7 @@ mod tests {
     #[tokio::test]
     async fn test_parse_date_time_mixed_format() {
         // Test mixed format "12月25日 15:30"
-        let result = parse_event_date("12月25日 15:30").await.unwrap();
+        let result = parse_event_date("12月25日 15:30", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -348,7 +405,7 @@ mod tests {
     async fn test_english_time_am_pm() {
         // Test English time with AM/PM
         let now = Local::now();
-        let result = parse_event_date("2:30 PM").await.unwrap();
+        let result = parse_event_date("2:30 PM", &Local).await.unwrap();
         
         // Should be today at 14:30 if it hasn't passed, or tomorrow if it has
         if now.hour() < 14 || (now.hour() == 14 && now.minute() < 30) {
@@ -365,7 +422,7 @@ mod tests {
     async fn test_english_time_half_past() {
         // Test English "half past" format
         let now = Local::now();
-        let result = parse_event_date("half past 3").await.unwrap();
+        let result = parse_event_date("half past 3", &Local).await.unwrap();
         
         // Should be today at 3:30 if it hasn't passed, or tomorrow if it has
         if now.hour() < 3 || (now.hour() == 3 && now.minute() < 30) {
@@ -382,7 +439,7 @@ mod tests {
     async fn test_english_time_quarter_past() {
         // Test English "quarter past" format
         let now = Local::now();
-        let result = parse_event_date("quarter past 5").await.unwrap();
+        let result = parse_event_date("quarter past 5", &Local).await.unwrap();
         
         // Should be today at 5:15 if it hasn't passed, or tomorrow if it has
         if now.hour() < 5 || (now.hour() == 5 && now.minute() < 15) {
@@ -399,7 +456,7 @@ mod tests {
     async fn test_english_time_quarter_to() {
         // Test English "quarter to" format
         let now = Local::now();
-        let result = parse_event_date("quarter to 6").await.unwrap();
+        let result = parse_event_date("quarter to 6", &Local).await.unwrap();
         
         // Should be today at 5:45 if it hasn't passed, or tomorrow if it has
         if now.hour() < 5 || (now.hour() == 5 && now.minute() < 45) {
@@ -415,7 +472,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_date_month_name() {
         // Test English date with month name "Dec 31"
-        let result = parse_event_date("Dec 31").await.unwrap();
+        let result = parse_event_date("Dec 31", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -428,7 +485,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_date_full_month_name() {
         // Test English date with full month name "January 15th"
-        let result = parse_event_date("January 15th").await.unwrap();
+        let result = parse_event_date("January 15th", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -441,7 +498,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_date_day_month_format() {
         // Test English date "31st Dec" format
-        let result = parse_event_date("31st Dec").await.unwrap();
+        let result = parse_event_date("31st Dec", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -454,7 +511,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_relative_today() {
         // Test English relative date "today"
-        let result = parse_event_date("today").await.unwrap();
+        let result = parse_event_date("today", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -467,7 +524,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_relative_tomorrow() {
         // Test English relative date "tomorrow"
-        let result = parse_event_date("tomorrow").await.unwrap();
+        let result = parse_event_date("tomorrow", &Local).await.unwrap();
         let tomorrow = Local::now() + Duration::days(1);
         
         assert_eq!(result.year(), tomorrow.year());
@@ -480,7 +537,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_relative_days_later() {
         // Test English relative date "3 days later"
-        let result = parse_event_date("3 days later").await.unwrap();
+        let result = parse_event_date("3 days later", &Local).await.unwrap();
         let target_date = Local::now() + Duration::days(3);
         
         assert_eq!(result.year(), target_date.year());
@@ -493,7 +550,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_relative_in_days() {
         // Test English relative date "in 5 days"
-        let result = parse_event_date("in 5 days").await.unwrap();
+        let result = parse_event_date("in 5 days", &Local).await.unwrap();
         let target_date = Local::now() + Duration::days(5);
         
         assert_eq!(result.year(), target_date.year());
@@ -506,7 +563,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_relative_with_time_am_pm() {
         // Test English relative date with time "today 3:30 PM"
-        let result = parse_event_date("today 3:30 PM").await.unwrap();
+        let result = parse_event_date("today 3:30 PM", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -519,7 +576,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_relative_with_time_quarter_past() {
         // Test English relative date with time "tomorrow quarter past 2"
-        let result = parse_event_date("tomorrow quarter past 2").await.unwrap();
+        let result = parse_event_date("tomorrow quarter past 2", &Local).await.unwrap();
         let tomorrow = Local::now() + Duration::days(1);
         
         assert_eq!(result.year(), tomorrow.year());
@@ -532,7 +589,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_date_time_am_pm() {
         // Test English date and time "Dec 25 2:30 PM"
-        let result = parse_event_date("Dec 25 2:30 PM").await.unwrap();
+        let result = parse_event_date("Dec 25 2:30 PM", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -545,7 +602,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_date_time_day_month_format() {
         // Test English date and time "25th Dec 10:15 AM"
-        let result = parse_event_date("25th Dec 10:15 AM").await.unwrap();
+        let result = parse_event_date("25th Dec 10:15 AM", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -558,7 +615,7 @@ mod tests {
     #[tokio::test]
     async fn test_english_date_time_full_month() {
         // Test English date and time "January 1st 11:45 PM"
-        let result = parse_event_date("January 1st 11:45 PM").await.unwrap();
+        let result = parse_event_date("January 1st 11:45 PM", &Local).await.unwrap();
         let now = Local::now();
         
         assert_eq!(result.year(), now.year());
@@ -568,4 +625,20 @@ mod tests {
         assert_eq!(result.minute(), 45);
     }
 
-}
\ No newline at end of file
+    // ============ Guild War Tests ============
+
+    #[test]
+    fn test_parse_guild_war_date() {
+        let date = |round, hour, minute| Some(Ok(GuildWarDate { round, time: NaiveTime::from_hms_opt(hour, minute, 0) }));
+
+        assert_eq!(parse_guild_war_date("本戦1日目 22時"), date(GuildWarRound::Finals1, 22, 0));
+        assert_eq!(parse_guild_war_date("本戦２日目２１時半"), date(GuildWarRound::Finals2, 21, 30));
+        assert_eq!(parse_guild_war_date("予選 19:30"), date(GuildWarRound::Preliminaries, 19, 30));
+        assert_eq!(parse_guild_war_date("finals day 4 7時15分"), date(GuildWarRound::Finals4, 7, 15));
+        assert_eq!(parse_guild_war_date("インターバル"), Some(Ok(GuildWarDate { round: GuildWarRound::Interlude, time: None })));
+
+        assert!(matches!(parse_guild_war_date("本戦1日目 25時"), Some(Err(_))));
+        assert!(matches!(parse_guild_war_date("本戦1日目 夜"), Some(Err(_))));
+        assert_eq!(parse_guild_war_date("明日 22時"), None);
+    }
+}
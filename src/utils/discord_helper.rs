@@ -130,6 +130,25 @@ mod tests {
         assert_eq!(parts[6], "555666777"); // message_id
     }
 
+    #[test]
+    fn test_parse_message_link() {
+        let current = ChannelId::new(1);
+
+        assert_eq!(
+            parse_message_link("https://discord.com/channels/123456789/987654321/555666777", current),
+            Some((ChannelId::new(987654321), MessageId::new(555666777)))
+        );
+        assert_eq!(
+            parse_message_link(" https://ptb.discord.com/channels/123456789/987654321/555666777 ", current),
+            Some((ChannelId::new(987654321), MessageId::new(555666777)))
+        );
+        // IDだけの場合は実行したチャンネルのメッセージ
+        assert_eq!(parse_message_link("555666777", current), Some((current, MessageId::new(555666777))));
+        assert_eq!(parse_message_link("https://example.com/channels/1/2/3", current), None);
+        assert_eq!(parse_message_link("募集", current), None);
+        assert_eq!(parse_message_link("0", current), None);
+    }
+
     #[test]
     fn test_dm_url_components_extraction() {
         // Test DM URL format
@@ -272,4 +291,66 @@ pub async fn update_embed_with_participants(
     }
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Parses a message link (or a bare message ID in `current_channel`) into its channel and message
+pub fn parse_message_link(value: &str, current_channel: ChannelId) -> Option<(ChannelId, MessageId)> {
+    let value = value.trim();
+    let parse_id = |id: &str| id.parse::<u64>().ok().filter(|id| *id != 0);
+
+    let Some(path) = value.split_once("discord.com/channels/")
+        .filter(|(host, _)| ["https://", "https://ptb.", "https://canary."].contains(host))
+        .map(|(_, path)| path) else {
+        return parse_id(value).map(|message_id| (current_channel, MessageId::new(message_id)));
+    };
+
+    let mut parts = path.split('/');
+    let (_guild, channel_id, message_id) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((ChannelId::new(parse_id(channel_id)?), MessageId::new(parse_id(message_id)?)))
+}
+
+/// Gets the mentions of the users who reacted to a message, sorted by user ID
+pub async fn get_participant_mentions(
+    ctx: &poise::serenity_prelude::all::Context,
+    channel_id: ChannelId,
+    message_id: MessageId,
+) -> Result<Vec<String>, String> {
+    let message = channel_id.message(&ctx.http, message_id).await
+        .map_err(|e| format!("Failed to fetch the message: {}", e))?;
+
+    let mut users: Vec<UserId> = get_unique_reaction_users(ctx, &message).await?.into_iter().collect();
+    users.sort();
+    Ok(users.into_iter().map(|id| format!("<@{}>", id)).collect())
+}
+
+/// Sends a message as a reply to another message
+pub async fn reply_to_message(
+    ctx: &poise::serenity_prelude::all::Context,
+    channel_id: ChannelId,
+    message_id: MessageId,
+    content: String,
+) -> Result<Message, String> {
+    let builder = poise::serenity_prelude::all::CreateMessage::new()
+        .content(content)
+        .reference_message((channel_id, message_id));
+
+    channel_id.send_message(&ctx.http, builder).await
+        .map_err(|e| format!("Failed to send a reply: {}", e))
+}
+
+/// Checks whether a user is still a member of a guild
+/// A member or guild that Discord no longer knows counts as not a member
+pub async fn is_guild_member(
+    http: &poise::serenity_prelude::all::Http,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> Result<bool, String> {
+    match http.get_member(guild_id, user_id).await {
+        Ok(_) => Ok(true),
+        Err(poise::serenity_prelude::all::Error::Http(e)) if e.status_code() == Some(poise::serenity_prelude::all::StatusCode::NOT_FOUND) => Ok(false),
+        Err(e) => Err(format!("Failed to fetch the member: {}", e)),
+    }
+}
@@ -1,4 +1,5 @@
 pub mod discord_helper;
 pub mod date_parser;
 pub mod constants;
+pub mod text_normalizer;
 pub(crate) mod database;
\ No newline at end of file
@@ -0,0 +1,135 @@
+/// 半角カタカナ（U+FF61〜U+FF9D）に対応する全角文字
+const HALF_WIDTH_KATAKANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+
+/// 半角濁点・半濁点
+const HALF_WIDTH_VOICED_MARK: char = '\u{FF9E}';
+const HALF_WIDTH_SEMI_VOICED_MARK: char = '\u{FF9F}';
+
+/// クエスト別名の比較用に文字列を正規化する
+///
+/// - 全角英数記号を半角に、半角カタカナを全角に揃える
+/// - ひらがなをカタカナに揃える
+/// - 英字を小文字に揃え、空白を取り除く
+pub fn normalize_alias(text: &str) -> String {
+    let mut result: Vec<char> = Vec::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            HALF_WIDTH_VOICED_MARK => {
+                if let Some(last) = result.last_mut() {
+                    *last = add_voiced_mark(*last);
+                }
+            },
+            HALF_WIDTH_SEMI_VOICED_MARK => {
+                if let Some(last) = result.last_mut() {
+                    *last = add_semi_voiced_mark(*last);
+                }
+            },
+            _ => {
+                let c = hiragana_to_katakana(to_narrow_ascii(to_wide_katakana(c)));
+                if !c.is_whitespace() {
+                    result.extend(c.to_lowercase());
+                }
+            },
+        }
+    }
+
+    result.into_iter().collect()
+}
+
+/// 2つの文字列の編集距離（レーベンシュタイン距離）を文字単位で求める
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+fn to_wide_katakana(c: char) -> char {
+    match c as u32 {
+        0xFF61..=0xFF9D => HALF_WIDTH_KATAKANA.chars().nth((c as u32 - 0xFF61) as usize).unwrap_or(c),
+        _ => c,
+    }
+}
+
+fn to_narrow_ascii(c: char) -> char {
+    match c as u32 {
+        0xFF01..=0xFF5E => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        0x3000 => ' ',
+        _ => c,
+    }
+}
+
+fn hiragana_to_katakana(c: char) -> char {
+    match c as u32 {
+        0x3041..=0x3096 | 0x309D..=0x309E => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+fn add_voiced_mark(c: char) -> char {
+    match c {
+        'ウ' => 'ヴ',
+        'カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ'
+        | 'タ' | 'チ' | 'ツ' | 'テ' | 'ト' | 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => {
+            char::from_u32(c as u32 + 1).unwrap_or(c)
+        },
+        _ => c,
+    }
+}
+
+fn add_semi_voiced_mark(c: char) -> char {
+    match c {
+        'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => char::from_u32(c as u32 + 2).unwrap_or(c),
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_alias_kana_and_width() {
+        let expected = normalize_alias("ルシファー");
+        assert_eq!(expected, "ルシファー");
+        assert_eq!(normalize_alias("るしふぁー"), expected);
+        assert_eq!(normalize_alias("ﾙｼﾌｧｰ"), expected);
+    }
+
+    #[test]
+    fn test_normalize_alias_voiced_marks() {
+        assert_eq!(normalize_alias("ﾍﾞﾙｾﾞﾊﾞﾌﾞ"), "ベルゼバブ");
+        assert_eq!(normalize_alias("ﾎﾟｲﾝﾄ"), "ポイント");
+        assert_eq!(normalize_alias("ｳﾞｪｲﾝ"), "ヴェイン");
+        assert_eq!(normalize_alias("べるぜばぶ"), "ベルゼバブ");
+    }
+
+    #[test]
+    fn test_normalize_alias_ascii_and_spaces() {
+        assert_eq!(normalize_alias("ルシ ＨＬ"), "ルシhl");
+        assert_eq!(normalize_alias("るし　hl"), "ルシhl");
+        assert_eq!(normalize_alias("Lucilius HL"), "luciliushl");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("ルシファー", "ルシファ"), 1);
+        assert_eq!(edit_distance("ベルゼバブ", "ベルセバブ"), 1);
+    }
+}
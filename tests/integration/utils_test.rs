@@ -1,5 +1,5 @@
 use gbf_discord_bot_rs::utils::date_parser;
-use chrono::{Local, Datelike, Timelike};
+use chrono::{Local, Utc, FixedOffset, Datelike, Timelike};
 
 #[tokio::test]
 async fn test_date_parser_integration() {
@@ -17,11 +17,13 @@ async fn test_date_parser_integration() {
     assert_eq!(default_date.minute(), 0);
     assert_eq!(default_date.second(), 0);
     
-    // Test parsing a date string
-    let parsed_date = date_parser::parse_event_date("12/25 15:30").await.unwrap();
+    // Test parsing a date string in the guild's timezone
+    let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+    let parsed_date = date_parser::parse_event_date("12/25 15:30", &jst).await.unwrap();
     
     // Check that the parsed date is correct
-    assert_eq!(parsed_date.year(), now.year());
+    assert_eq!(parsed_date.offset(), &jst);
+    assert_eq!(parsed_date.year(), Utc::now().with_timezone(&jst).year());
     assert_eq!(parsed_date.month(), 12);
     assert_eq!(parsed_date.day(), 25);
     assert_eq!(parsed_date.hour(), 15);